@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use super::amount::Amount;
 
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StakeConfiguration {
     pub token: String,
     pub unlock_duration: u64,
@@ -12,7 +12,7 @@ pub struct StakeConfiguration {
     pub authority_stake: Amount,
 }
 
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RewardConfiguration {
     pub token: String,
     pub address_confirmation_reward: Amount,
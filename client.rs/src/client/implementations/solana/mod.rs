@@ -12,6 +12,10 @@ pub use test_helpers::create_test_tx;
 pub use client::HapiCoreSolana;
 pub use token::TokenContractSolana;
 
-pub use instruction_data::{DecodedInstructionData, InstructionData};
+pub use instruction_data::{
+    CreateAddressData, CreateAssetData, CreateCaseData, CreateNetworkData, CreateReporterData,
+    DecodedInstructionData, InstructionData, UpdateAddressData, UpdateAssetData, UpdateCaseData,
+    UpdateReporterData,
+};
 pub use instruction_decoder::DecodedInstruction;
 pub use utils::{byte_array_from_str, get_network_address};
@@ -49,7 +49,8 @@ fn create_test_instruction(
 
     UiCompiledInstruction {
         program_id_index: 0,
-        accounts: (0..accounts).map(|x| x as u8).collect(),
+        // The first account key is the program itself
+        accounts: (1..accounts).map(|x| x as u8).collect(),
         data: bs58::encode(instruction_data).into_string(),
         stack_height: None,
     }
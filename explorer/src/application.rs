@@ -1,12 +1,14 @@
 use {
     anyhow::{anyhow, bail, Result},
     jsonwebtoken::{encode, EncodingKey, Header},
-    sea_orm::{Database, DatabaseConnection},
+    sea_orm::{Database, DatabaseConnection, EntityTrait, QueryOrder},
     sea_orm_cli::MigrateSubcommands,
     sea_orm_migration::MigratorTrait,
     secrecy::{ExposeSecret, SecretString},
-    std::net::SocketAddr,
-    tokio::{sync::oneshot, task::JoinHandle},
+    serde::Serialize,
+    std::{net::SocketAddr, path::PathBuf, time::Duration},
+    tokio::{fs::File, io::AsyncWriteExt, sync::oneshot, task::JoinHandle},
+    tokio_stream::StreamExt,
     tracing::info,
     tracing::instrument,
     uuid::Uuid,
@@ -14,24 +16,36 @@ use {
 
 use crate::{
     configuration::Configuration,
-    entity::{network, types::NetworkBackend},
+    entity::{
+        indexer, network,
+        subscription::{create_entity_updates, EntityUpdates},
+        types::NetworkBackend,
+        watchlist::AlertAllowedHosts,
+    },
     migrations::Migrator,
     observability::{update_network_metrics, MetricOp},
-    server::handlers::TokenClaims,
-    service::{EntityMutation, EntityQuery},
+    server::handlers::{StatsCache, TokenClaims},
+    service::{
+        export_entities, AlertSender, EntityMutation, EntityQuery, ExportFormat, Exportable,
+    },
 };
 
-const JWT_VALIDITY_DAYS: i64 = 365;
-
 #[derive(Clone)]
 pub struct AppState {
     pub database_conn: DatabaseConnection,
     pub jwt_secret: SecretString,
+    pub webhook_secret: Option<SecretString>,
+    pub webhook_signature_tolerance: Duration,
+    pub entity_updates: EntityUpdates,
+    pub stats_cache: StatsCache,
+    pub alerts: AlertSender,
+    pub alert_allowed_hosts: AlertAllowedHosts,
 }
 
 pub struct Application {
     pub socket: Option<SocketAddr>,
     pub enable_metrics: bool,
+    pub jwt_validity_days: u32,
     pub state: AppState,
     pub shutdown_sender: Option<oneshot::Sender<()>>,
     pub server_handle: Option<JoinHandle<Result<()>>>,
@@ -41,9 +55,22 @@ impl Application {
     pub async fn from_configuration(configuration: Configuration) -> Result<Self> {
         let database_conn = Database::connect(configuration.database_url.as_str()).await?;
 
+        let alert_allowed_hosts = AlertAllowedHosts(configuration.alert_allowed_hosts.to_owned());
+
         let state = AppState {
             database_conn,
             jwt_secret: configuration.jwt_secret.to_owned(),
+            webhook_secret: configuration.webhook_secret.to_owned(),
+            webhook_signature_tolerance: configuration.webhook_signature_tolerance,
+            entity_updates: create_entity_updates(),
+            stats_cache: StatsCache::new(configuration.stats_cache_ttl),
+            alerts: AlertSender::start(
+                configuration.alert_retry_delay,
+                configuration.alert_max_attempts,
+                configuration.alert_queue_capacity,
+                alert_allowed_hosts.clone(),
+            ),
+            alert_allowed_hosts,
         };
 
         info!("Application initialized");
@@ -51,6 +78,7 @@ impl Application {
         Ok(Self {
             socket: None,
             enable_metrics: configuration.enable_metrics,
+            jwt_validity_days: configuration.jwt_validity_days,
             state,
             shutdown_sender: None,
             server_handle: None,
@@ -153,25 +181,108 @@ impl Application {
         EntityMutation::create_indexer(&self.state.database_conn, backend, chain_id, id, now)
             .await?;
 
+        let token = self.create_token(id, now, 0)?;
+
+        tracing::info!("IndexerId: {}. Token: {}", id, token);
+
+        Ok(token)
+    }
+
+    fn create_token(
+        &self,
+        id: Uuid,
+        now: chrono::DateTime<chrono::Utc>,
+        generation: i32,
+    ) -> Result<String> {
         let iat = now.timestamp() as usize;
-        let exp = (now + chrono::Duration::days(JWT_VALIDITY_DAYS)).timestamp() as usize;
+        let exp =
+            (now + chrono::Duration::days(self.jwt_validity_days.into())).timestamp() as usize;
         let claims: TokenClaims = TokenClaims {
             id: id.to_string(),
             exp,
             iat,
+            generation,
         };
 
-        let token = encode(
+        Ok(encode(
             &Header::default(),
             &claims,
             &EncodingKey::from_secret(self.state.jwt_secret.expose_secret().as_ref()),
-        )?;
+        )?)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn list_indexers(&self) -> Result<Vec<indexer::Model>> {
+        let indexers = indexer::Entity::find()
+            .order_by_asc(indexer::Column::CreatedAt)
+            .all(&self.state.database_conn)
+            .await?;
+
+        for indexer in &indexers {
+            tracing::info!(
+                id = %indexer.id,
+                network = indexer.network_id,
+                created_at = %indexer.created_at,
+                last_heartbeat = %indexer.last_heartbeat,
+                cursor = indexer.cursor,
+                token_generation = indexer.token_generation,
+                "Indexer"
+            );
+        }
+
+        Ok(indexers)
+    }
+
+    /// Rejects all tokens of the indexer issued so far
+    #[instrument(level = "info", skip(self))]
+    pub async fn revoke_indexer(&self, id: Uuid) -> Result<()> {
+        EntityMutation::increment_token_generation(&self.state.database_conn, id).await?;
+
+        tracing::info!("Indexer {} tokens are revoked", id);
+
+        Ok(())
+    }
+
+    /// Rejects previous tokens of the indexer and issues a new one
+    #[instrument(level = "info", skip(self))]
+    pub async fn rotate_indexer_token(&self, id: Uuid) -> Result<String> {
+        let indexer =
+            EntityMutation::increment_token_generation(&self.state.database_conn, id).await?;
+
+        let token = self.create_token(id, chrono::Utc::now(), indexer.token_generation)?;
 
         tracing::info!("IndexerId: {}. Token: {}", id, token);
 
         Ok(token)
     }
 
+    /// Writes all the filtered entities to the output file
+    #[instrument(level = "info", skip(self, filter), fields(entity = E::NAME))]
+    pub async fn export<E>(
+        &self,
+        filter: E::Filter,
+        format: ExportFormat,
+        output: PathBuf,
+    ) -> Result<()>
+    where
+        E: Exportable,
+        E::Filter: Send + Sync + 'static,
+        E::Model: Serialize,
+    {
+        let mut file = File::create(&output).await?;
+        let mut chunks = export_entities::<E>(self.state.database_conn.clone(), filter, format);
+
+        while let Some(chunk) = chunks.next().await {
+            file.write_all(&chunk?).await?;
+        }
+
+        file.flush().await?;
+
+        tracing::info!("{} are exported to {}", E::NAME, output.display());
+
+        Ok(())
+    }
+
     pub async fn shutdown(&mut self) -> Result<()> {
         // Close database connection
         self.state.database_conn.clone().close().await?;
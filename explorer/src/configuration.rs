@@ -2,8 +2,8 @@ use {
     config::{Config, ConfigError, File, FileFormat},
     secrecy::SecretString,
     serde::{Deserialize, Deserializer},
-    serde_with::serde_as,
-    std::env,
+    serde_with::{serde_as, DurationSeconds},
+    std::{env, time::Duration},
 };
 
 const CONFIG_PATH: &str = "configuration.toml";
@@ -34,6 +34,41 @@ pub struct Configuration {
     /// Secret for JWT
     #[serde(deserialize_with = "deserialize_secret_string")]
     pub jwt_secret: SecretString,
+
+    /// The number of days the indexer tokens are valid for
+    #[serde(default = "default_jwt_validity_days")]
+    pub jwt_validity_days: u32,
+
+    /// Shared secret to verify HMAC signatures of webhook payloads (optional)
+    #[serde(default, deserialize_with = "deserialize_optional_secret_string")]
+    pub webhook_secret: Option<SecretString>,
+
+    /// Maximum age of the webhook signature timestamp in seconds
+    #[serde_as(as = "DurationSeconds<u64>")]
+    #[serde(default = "default_webhook_signature_tolerance")]
+    pub webhook_signature_tolerance: Duration,
+
+    /// How long the `/stats` responses are cached in seconds
+    #[serde_as(as = "DurationSeconds<u64>")]
+    #[serde(default = "default_stats_cache_ttl")]
+    pub stats_cache_ttl: Duration,
+
+    /// Delay before the first retry of a watchlist alert in seconds, doubled after each failed attempt
+    #[serde_as(as = "DurationSeconds<u64>")]
+    #[serde(default = "default_alert_retry_delay")]
+    pub alert_retry_delay: Duration,
+
+    /// The number of attempts to deliver a watchlist alert
+    #[serde(default = "default_alert_max_attempts")]
+    pub alert_max_attempts: u32,
+
+    /// Loopback, link-local and private webhook hosts of the watchlists allowed to be alerted
+    #[serde(default)]
+    pub alert_allowed_hosts: Vec<String>,
+
+    /// The number of watchlist alerts waiting for the delivery, further alerts are dropped
+    #[serde(default = "default_alert_queue_capacity")]
+    pub alert_queue_capacity: usize,
 }
 
 impl Default for Configuration {
@@ -45,6 +80,14 @@ impl Default for Configuration {
             listener: default_listener(),
             database_url: String::new(),
             jwt_secret: default_jwt_secret(),
+            jwt_validity_days: default_jwt_validity_days(),
+            webhook_secret: None,
+            webhook_signature_tolerance: default_webhook_signature_tolerance(),
+            stats_cache_ttl: default_stats_cache_ttl(),
+            alert_retry_delay: default_alert_retry_delay(),
+            alert_max_attempts: default_alert_max_attempts(),
+            alert_allowed_hosts: vec![],
+            alert_queue_capacity: default_alert_queue_capacity(),
         }
     }
 }
@@ -89,6 +132,30 @@ fn default_jwt_secret() -> SecretString {
     SecretString::new("my_ultra_secure_secret".to_string())
 }
 
+fn default_jwt_validity_days() -> u32 {
+    365
+}
+
+fn default_webhook_signature_tolerance() -> Duration {
+    Duration::from_secs(300)
+}
+
+fn default_stats_cache_ttl() -> Duration {
+    Duration::from_secs(30)
+}
+
+fn default_alert_retry_delay() -> Duration {
+    Duration::from_secs(1)
+}
+
+fn default_alert_max_attempts() -> u32 {
+    5
+}
+
+fn default_alert_queue_capacity() -> usize {
+    1000
+}
+
 fn deserialize_secret_string<'de, D>(deserializer: D) -> Result<SecretString, D::Error>
 where
     D: Deserializer<'de>,
@@ -96,3 +163,13 @@ where
     let s = String::deserialize(deserializer)?;
     Ok(SecretString::new(s))
 }
+
+fn deserialize_optional_secret_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<SecretString>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = Option::<String>::deserialize(deserializer)?;
+    Ok(s.map(SecretString::new))
+}
@@ -2,5 +2,6 @@ pub(super) mod model;
 pub(super) mod query_utils;
 pub(super) mod resolver;
 
-pub use model::{ActiveModel, Entity, Model};
+pub use model::{ActiveModel, Column, Entity, Model};
+pub use query_utils::AddressFilter;
 pub(crate) use resolver::AddressQuery;
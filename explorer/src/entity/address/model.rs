@@ -2,14 +2,15 @@ use {
     async_graphql::SimpleObject,
     hapi_core::client::entities::address::Address as AddressPayload,
     sea_orm::{entity::prelude::*, NotSet, Set},
+    serde::Serialize,
 };
 
-use super::query_utils::{AddressCondition, AddressFilter};
+use super::query_utils::{AddressCondition, AddressFilter, AddressSearchField};
 use crate::entity::{case, reporter, types::Category, EntityFilter, FromPayload};
 
 // Note: risk and confirmations do not correspond to the types of contracts (due to Postgresql restrictions)
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
-#[graphql(name = "Address")]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject, Serialize)]
+#[graphql(name = "Address", complex)]
 #[sea_orm(table_name = "address")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -28,6 +29,7 @@ pub struct Model {
 impl EntityFilter for Entity {
     type Filter = AddressFilter;
     type Condition = AddressCondition;
+    type SearchField = AddressSearchField;
 
     // Filtering query
     fn filter(selected: Select<Entity>, filter_options: &AddressFilter) -> Select<Entity> {
@@ -59,19 +61,6 @@ impl EntityFilter for Entity {
 
         query
     }
-
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("network_id"),
-            String::from("address"),
-            String::from("case_id::text"),
-            String::from("reporter_id::text"),
-            String::from("risk::text"),
-            String::from("category::text"),
-            String::from("confirmations::text"),
-        ]
-    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
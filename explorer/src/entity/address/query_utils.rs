@@ -1,19 +1,31 @@
 use {
     async_graphql::{Enum, InputObject},
+    clap::Args,
+    sea_orm::EnumIter,
+    serde::Deserialize,
     uuid::Uuid,
 };
 
 use super::model::Column;
-use crate::entity::types::Category;
+use crate::entity::{
+    search::{SearchField, SearchMode},
+    types::Category,
+};
 
 /// Conditions to filter address listings by
-#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default, Deserialize, Args)]
 pub struct AddressFilter {
+    #[arg(long, help = "Network string identifier")]
     pub network_id: Option<String>,
+    #[arg(long, help = "Case id")]
     pub case_id: Option<Uuid>,
+    #[arg(long, help = "Reporter id")]
     pub reporter_id: Option<Uuid>,
+    #[arg(long, value_enum, help = "Address category")]
     pub category: Option<Category>,
+    #[arg(long, help = "Address risk score")]
     pub risk: Option<u8>,
+    #[arg(long, help = "Confirmation count")]
     pub confirmations: Option<String>,
 }
 
@@ -56,3 +68,36 @@ impl From<AddressCondition> for Column {
         }
     }
 }
+
+/// Available fields to search addresses by
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, EnumIter)]
+pub enum AddressSearchField {
+    /// Search by network id
+    NetworkId,
+    /// Search by address
+    Address,
+    /// Search by case id
+    CaseId,
+    /// Search by reporter id
+    ReporterId,
+}
+
+impl SearchField for AddressSearchField {
+    type Column = Column;
+
+    fn column(&self) -> Column {
+        match self {
+            AddressSearchField::NetworkId => Column::NetworkId,
+            AddressSearchField::Address => Column::Address,
+            AddressSearchField::CaseId => Column::CaseId,
+            AddressSearchField::ReporterId => Column::ReporterId,
+        }
+    }
+
+    fn mode(&self) -> SearchMode {
+        match self {
+            AddressSearchField::NetworkId | AddressSearchField::Address => SearchMode::Prefix,
+            AddressSearchField::CaseId | AddressSearchField::ReporterId => SearchMode::Uuid,
+        }
+    }
+}
@@ -1,16 +1,22 @@
 use {
-    async_graphql::{Context, Object, Result},
+    async_graphql::{dataloader::DataLoader, ComplexObject, Context, Object, Result},
     sea_orm::DatabaseConnection,
     tracing::instrument,
 };
 
 use super::{
     model::Model,
-    query_utils::{AddressCondition, AddressFilter},
+    query_utils::{AddressCondition, AddressFilter, AddressSearchField},
 };
 
 use crate::{
-    entity::pagination::{EntityInput, EntityPage},
+    entity::{
+        case,
+        loader::{CaseKey, EntityLoader, ReporterKey},
+        normalization::normalize_network_address,
+        pagination::{EntityCursorInput, EntityCursorPage, EntityInput, EntityPage},
+        reporter,
+    },
     service::EntityQuery,
 };
 
@@ -30,6 +36,10 @@ impl AddressQuery {
         #[graphql(desc = "Address network")] network_id: String,
     ) -> Result<Option<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
+        // Addresses invalid on the network are never stored
+        let Some(address) = normalize_network_address(db, &network_id, &address).await? else {
+            return Ok(None);
+        };
         let address =
             EntityQuery::find_entity_by_id::<super::model::Entity, _>(db, (network_id, address))
                 .await?;
@@ -45,6 +55,7 @@ impl AddressQuery {
         #[graphql(desc = "Address input parameters")] input: EntityInput<
             AddressFilter,
             AddressCondition,
+            AddressSearchField,
         >,
     ) -> Result<EntityPage<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
@@ -52,4 +63,45 @@ impl AddressQuery {
 
         Ok(page)
     }
+
+    /// Get multiple addresses page by page after the cursor
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn get_many_addresses_by_cursor(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address cursor input parameters")] input: EntityCursorInput<
+            AddressFilter,
+            AddressCondition,
+            AddressSearchField,
+        >,
+    ) -> Result<EntityCursorPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_many_by_cursor::<super::model::Entity>(db, input).await?;
+
+        Ok(page)
+    }
+}
+
+/// Relations of the `Address` model
+#[ComplexObject]
+impl Model {
+    /// Case the address belongs to
+    async fn case(&self, ctx: &Context<'_>) -> Result<Option<case::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let case = loader
+            .load_one(CaseKey(self.network_id.clone(), self.case_id))
+            .await?;
+
+        Ok(case)
+    }
+
+    /// Reporter of the address
+    async fn reporter(&self, ctx: &Context<'_>) -> Result<Option<reporter::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let reporter = loader
+            .load_one(ReporterKey(self.network_id.clone(), self.reporter_id))
+            .await?;
+
+        Ok(reporter)
+    }
 }
@@ -2,14 +2,17 @@ use {
     async_graphql::SimpleObject,
     hapi_core::client::entities::asset::Asset as AssetPayload,
     sea_orm::{entity::prelude::*, NotSet, Set},
+    serde::Serialize,
 };
 
-use super::query_utils::{AssetCondition, AssetFilter};
-use crate::entity::{case, reporter, types::Category, EntityFilter, FromPayload};
+use super::query_utils::{AssetCondition, AssetFilter, AssetSearchField};
+use crate::entity::{
+    case, normalization::address_candidates, reporter, types::Category, EntityFilter, FromPayload,
+};
 
 // Note: risk and confirmations types do not correspond to the types of contracts (due to Postgresql restrictions)
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
-#[graphql(name = "Asset")]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject, Serialize)]
+#[graphql(name = "Asset", complex)]
 #[sea_orm(table_name = "asset")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -30,6 +33,7 @@ pub struct Model {
 impl EntityFilter for Entity {
     type Filter = AssetFilter;
     type Condition = AssetCondition;
+    type SearchField = AssetSearchField;
 
     // Filtering query
     fn filter(selected: Select<Entity>, filter_options: &AssetFilter) -> Select<Entity> {
@@ -39,8 +43,9 @@ impl EntityFilter for Entity {
             query = query.filter(Column::NetworkId.eq(network));
         }
 
+        // The network backend is not known here, so any canonical form of the address matches
         if let Some(address) = &filter_options.address {
-            query = query.filter(Column::Address.eq(address));
+            query = query.filter(Column::Address.is_in(address_candidates(address)));
         }
 
         if let Some(case_id) = filter_options.case_id {
@@ -65,20 +70,6 @@ impl EntityFilter for Entity {
 
         query
     }
-
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("network_id"),
-            String::from("address"),
-            String::from("id"),
-            String::from("case_id::text"),
-            String::from("reporter_id::text"),
-            String::from("risk::text"),
-            String::from("category::text"),
-            String::from("confirmations::text"),
-        ]
-    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,20 +1,33 @@
 use {
     async_graphql::{Enum, InputObject},
+    clap::Args,
+    sea_orm::EnumIter,
+    serde::Deserialize,
     uuid::Uuid,
 };
 
 use super::model::Column;
-use crate::entity::types::Category;
+use crate::entity::{
+    search::{SearchField, SearchMode},
+    types::Category,
+};
 
 /// Conditions to filter address listings by
-#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default, Deserialize, Args)]
 pub struct AssetFilter {
+    #[arg(long, help = "Network string identifier")]
     pub network_id: Option<String>,
+    #[arg(long, help = "Asset contract address")]
     pub address: Option<String>,
+    #[arg(long, help = "Case id")]
     pub case_id: Option<Uuid>,
+    #[arg(long, help = "Reporter id")]
     pub reporter_id: Option<Uuid>,
+    #[arg(long, value_enum, help = "Asset category")]
     pub category: Option<Category>,
+    #[arg(long, help = "Asset risk score")]
     pub risk: Option<u8>,
+    #[arg(long, help = "Confirmation count")]
     pub confirmations: Option<String>,
 }
 
@@ -57,3 +70,41 @@ impl From<AssetCondition> for Column {
         }
     }
 }
+
+/// Available fields to search assets by
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, EnumIter)]
+pub enum AssetSearchField {
+    /// Search by network id
+    NetworkId,
+    /// Search by address
+    Address,
+    /// Search by asset id
+    Id,
+    /// Search by case id
+    CaseId,
+    /// Search by reporter id
+    ReporterId,
+}
+
+impl SearchField for AssetSearchField {
+    type Column = Column;
+
+    fn column(&self) -> Column {
+        match self {
+            AssetSearchField::NetworkId => Column::NetworkId,
+            AssetSearchField::Address => Column::Address,
+            AssetSearchField::Id => Column::Id,
+            AssetSearchField::CaseId => Column::CaseId,
+            AssetSearchField::ReporterId => Column::ReporterId,
+        }
+    }
+
+    fn mode(&self) -> SearchMode {
+        match self {
+            AssetSearchField::NetworkId | AssetSearchField::Address | AssetSearchField::Id => {
+                SearchMode::Prefix
+            }
+            AssetSearchField::CaseId | AssetSearchField::ReporterId => SearchMode::Uuid,
+        }
+    }
+}
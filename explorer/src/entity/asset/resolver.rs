@@ -1,16 +1,22 @@
 use {
-    async_graphql::{Context, Object, Result},
+    async_graphql::{dataloader::DataLoader, ComplexObject, Context, Object, Result},
     sea_orm::DatabaseConnection,
     tracing::instrument,
 };
 
 use super::{
     model::Model,
-    query_utils::{AssetCondition, AssetFilter},
+    query_utils::{AssetCondition, AssetFilter, AssetSearchField},
 };
 
 use crate::{
-    entity::pagination::{EntityInput, EntityPage},
+    entity::{
+        case,
+        loader::{CaseKey, EntityLoader, ReporterKey},
+        normalization::normalize_network_address,
+        pagination::{EntityCursorInput, EntityCursorPage, EntityInput, EntityPage},
+        reporter,
+    },
     service::EntityQuery,
 };
 
@@ -31,6 +37,10 @@ impl AssetQuery {
         #[graphql(desc = "Asset network")] network_id: String,
     ) -> Result<Option<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
+        // Addresses invalid on the network are never stored
+        let Some(address) = normalize_network_address(db, &network_id, &address).await? else {
+            return Ok(None);
+        };
         let address = EntityQuery::find_entity_by_id::<super::model::Entity, _>(
             db,
             (network_id, address, id),
@@ -45,11 +55,56 @@ impl AssetQuery {
     pub async fn get_many_assets(
         &self,
         ctx: &Context<'_>,
-        #[graphql(desc = "Asset input parameters")] input: EntityInput<AssetFilter, AssetCondition>,
+        #[graphql(desc = "Asset input parameters")] input: EntityInput<
+            AssetFilter,
+            AssetCondition,
+            AssetSearchField,
+        >,
     ) -> Result<EntityPage<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
         let page = EntityQuery::find_many::<super::model::Entity>(db, input).await?;
 
         Ok(page)
     }
+
+    /// Get multiple assets page by page after the cursor
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn get_many_assets_by_cursor(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset cursor input parameters")] input: EntityCursorInput<
+            AssetFilter,
+            AssetCondition,
+            AssetSearchField,
+        >,
+    ) -> Result<EntityCursorPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_many_by_cursor::<super::model::Entity>(db, input).await?;
+
+        Ok(page)
+    }
+}
+
+/// Relations of the `Asset` model
+#[ComplexObject]
+impl Model {
+    /// Case the asset belongs to
+    async fn case(&self, ctx: &Context<'_>) -> Result<Option<case::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let case = loader
+            .load_one(CaseKey(self.network_id.clone(), self.case_id))
+            .await?;
+
+        Ok(case)
+    }
+
+    /// Reporter of the asset
+    async fn reporter(&self, ctx: &Context<'_>) -> Result<Option<reporter::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let reporter = loader
+            .load_one(ReporterKey(self.network_id.clone(), self.reporter_id))
+            .await?;
+
+        Ok(reporter)
+    }
 }
@@ -4,9 +4,10 @@ use {
     sea_orm::{
         entity::prelude::*, EntityTrait, JoinType, NotSet, QueryOrder, QuerySelect, Select, Set,
     },
+    serde::Serialize,
 };
 
-use super::query_utils::{CaseCondition, CaseFilter};
+use super::query_utils::{CaseCondition, CaseFilter, CaseSearchField};
 use crate::entity::{
     address, asset,
     pagination::{order_by_column, Ordering},
@@ -15,8 +16,8 @@ use crate::entity::{
     EntityFilter, FromPayload,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
-#[graphql(name = "Case")]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject, Serialize)]
+#[graphql(name = "Case", complex)]
 #[sea_orm(table_name = "case")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -34,6 +35,7 @@ pub struct Model {
 impl EntityFilter for Entity {
     type Filter = CaseFilter;
     type Condition = CaseCondition;
+    type SearchField = CaseSearchField;
 
     // Filtering query
     fn filter(selected: Select<Entity>, filter_options: &CaseFilter) -> Select<Entity> {
@@ -77,18 +79,12 @@ impl EntityFilter for Entity {
         }
     }
 
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("network_id"),
-            String::from("id::text"),
-            String::from("name"),
-            String::from("url"),
-            String::from("status::text"),
-            String::from("reporter_id::text"),
-            String::from("created_at::text"),
-            String::from("updated_at::text"),
-        ]
+    // Counts of the related entities are not columns to resume from
+    fn cursor_column(condition: Option<CaseCondition>) -> Option<Column> {
+        match condition {
+            Some(CaseCondition::AddressCount) | Some(CaseCondition::AssetCount) => None,
+            _ => Some(Column::from(condition.unwrap_or_default())),
+        }
     }
 }
 
@@ -1,18 +1,29 @@
 use {
     async_graphql::{Enum, InputObject},
+    clap::Args,
+    sea_orm::EnumIter,
+    serde::Deserialize,
     uuid::Uuid,
 };
 
 use super::model::Column;
-use crate::entity::types::CaseStatus;
+use crate::entity::{
+    search::{SearchField, SearchMode},
+    types::CaseStatus,
+};
 
 /// Conditions to filter address listings by
-#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default, Deserialize, Args)]
 pub struct CaseFilter {
+    #[arg(long, help = "Network string identifier")]
     pub network_id: Option<String>,
+    #[arg(long, help = "Part of the case name")]
     pub name: Option<String>,
+    #[arg(long, help = "Case url")]
     pub url: Option<String>,
+    #[arg(long, value_enum, help = "Case status")]
     pub status: Option<CaseStatus>,
+    #[arg(long, help = "Reporter id")]
     pub reporter_id: Option<Uuid>,
 }
 
@@ -57,3 +68,40 @@ impl From<CaseCondition> for Column {
         }
     }
 }
+
+/// Available fields to search cases by
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, EnumIter)]
+pub enum CaseSearchField {
+    /// Search by network id
+    NetworkId,
+    /// Search by case id
+    Id,
+    /// Search by name
+    Name,
+    /// Search by url
+    Url,
+    /// Search by reporter id
+    ReporterId,
+}
+
+impl SearchField for CaseSearchField {
+    type Column = Column;
+
+    fn column(&self) -> Column {
+        match self {
+            CaseSearchField::NetworkId => Column::NetworkId,
+            CaseSearchField::Id => Column::Id,
+            CaseSearchField::Name => Column::Name,
+            CaseSearchField::Url => Column::Url,
+            CaseSearchField::ReporterId => Column::ReporterId,
+        }
+    }
+
+    fn mode(&self) -> SearchMode {
+        match self {
+            CaseSearchField::NetworkId => SearchMode::Prefix,
+            CaseSearchField::Id | CaseSearchField::ReporterId => SearchMode::Uuid,
+            CaseSearchField::Name | CaseSearchField::Url => SearchMode::Text,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    async_graphql::{Context, Object, Result},
+    async_graphql::{dataloader::DataLoader, ComplexObject, Context, Object, Result},
     sea_orm::DatabaseConnection,
     tracing::instrument,
     uuid::Uuid,
@@ -7,11 +7,16 @@ use {
 
 use super::{
     model::Model,
-    query_utils::{CaseCondition, CaseFilter},
+    query_utils::{CaseCondition, CaseFilter, CaseSearchField},
 };
 
 use crate::{
-    entity::pagination::{EntityInput, EntityPage},
+    entity::{
+        address, asset,
+        loader::{CaseAddressesKey, CaseAssetsKey, EntityLoader, ReporterKey},
+        pagination::{EntityCursorInput, EntityCursorPage, EntityInput, EntityPage},
+        reporter,
+    },
     service::EntityQuery,
 };
 
@@ -42,11 +47,66 @@ impl CaseQuery {
     pub async fn get_many_cases(
         &self,
         ctx: &Context<'_>,
-        #[graphql(desc = "Case input parameters")] input: EntityInput<CaseFilter, CaseCondition>,
+        #[graphql(desc = "Case input parameters")] input: EntityInput<
+            CaseFilter,
+            CaseCondition,
+            CaseSearchField,
+        >,
     ) -> Result<EntityPage<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
         let page = EntityQuery::find_many::<super::model::Entity>(db, input).await?;
 
         Ok(page)
     }
+
+    /// Get multiple cases page by page after the cursor
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn get_many_cases_by_cursor(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Case cursor input parameters")] input: EntityCursorInput<
+            CaseFilter,
+            CaseCondition,
+            CaseSearchField,
+        >,
+    ) -> Result<EntityCursorPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_many_by_cursor::<super::model::Entity>(db, input).await?;
+
+        Ok(page)
+    }
+}
+
+/// Relations of the `Case` model
+#[ComplexObject]
+impl Model {
+    /// Addresses of the case
+    async fn addresses(&self, ctx: &Context<'_>) -> Result<Vec<address::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let addresses = loader
+            .load_one(CaseAddressesKey(self.network_id.clone(), self.id))
+            .await?;
+
+        Ok(addresses.unwrap_or_default())
+    }
+
+    /// Assets of the case
+    async fn assets(&self, ctx: &Context<'_>) -> Result<Vec<asset::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let assets = loader
+            .load_one(CaseAssetsKey(self.network_id.clone(), self.id))
+            .await?;
+
+        Ok(assets.unwrap_or_default())
+    }
+
+    /// Reporter of the case
+    async fn reporter(&self, ctx: &Context<'_>) -> Result<Option<reporter::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let reporter = loader
+            .load_one(ReporterKey(self.network_id.clone(), self.reporter_id))
+            .await?;
+
+        Ok(reporter)
+    }
 }
@@ -0,0 +1,6 @@
+pub(super) mod model;
+pub(super) mod resolver;
+
+pub(crate) use model::{get_asset_entity_id, get_event_entity};
+pub use model::{ActiveModel, Column, Entity, Model};
+pub(crate) use resolver::EventQuery;
@@ -0,0 +1,72 @@
+use {async_graphql::SimpleObject, hapi_indexer::PushData, sea_orm::entity::prelude::*};
+
+use crate::entity::types::EventEntity;
+
+/// Event that was applied to the explorer state
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
+#[graphql(name = "Event")]
+#[sea_orm(table_name = "event")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub network_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tx_hash: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tx_index: i64,
+    pub name: String,
+    /// Timestamp of the transaction block
+    pub timestamp: DateTime,
+    pub created_at: DateTime,
+    pub entity_type: Option<EventEntity>,
+    pub entity_id: Option<String>,
+    /// Snapshot of the entity payload carried by the event
+    pub payload: Option<Json>,
+    /// Reporter that confirmed the address or asset
+    pub confirmed_by: Option<Uuid>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Identifier of the asset in the event history
+pub(crate) fn get_asset_entity_id(address: &str, id: &str) -> String {
+    format!("{address}:{id}")
+}
+
+/// Returns the type and id of the entity changed by the event along with its payload snapshot
+pub(crate) fn get_event_entity(
+    network_id: &str,
+    data: &PushData,
+) -> Result<(EventEntity, String, Json), serde_json::Error> {
+    let entity = match data {
+        PushData::Address(address) => (
+            EventEntity::Address,
+            address.address.clone(),
+            serde_json::to_value(address)?,
+        ),
+        PushData::Asset(asset) => (
+            EventEntity::Asset,
+            get_asset_entity_id(&asset.address, &asset.asset_id.to_string()),
+            serde_json::to_value(asset)?,
+        ),
+        PushData::Case(case) => (
+            EventEntity::Case,
+            case.id.to_string(),
+            serde_json::to_value(case)?,
+        ),
+        PushData::Reporter(reporter) => (
+            EventEntity::Reporter,
+            reporter.id.to_string(),
+            serde_json::to_value(reporter)?,
+        ),
+        PushData::Configuration(configuration) => (
+            EventEntity::Network,
+            network_id.to_string(),
+            serde_json::to_value(configuration)?,
+        ),
+    };
+
+    Ok(entity)
+}
@@ -0,0 +1,117 @@
+use {
+    async_graphql::{Context, Object, Result},
+    sea_orm::DatabaseConnection,
+    tracing::instrument,
+    uuid::Uuid,
+};
+
+use super::model::{get_asset_entity_id, Model};
+
+use crate::{
+    entity::{
+        normalization::normalize_network_address,
+        pagination::{EntityPage, Paginator},
+        types::EventEntity,
+    },
+    service::EntityQuery,
+};
+
+/// The GraphQl Query segment
+#[derive(Default)]
+pub struct EventQuery {}
+
+/// Queries for the `Event` model
+#[Object]
+impl EventQuery {
+    /// Get the history of events applied to the address
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_address_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address address")] address: String,
+        #[graphql(desc = "Address network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        // Addresses invalid on the network are never stored, so their history is empty
+        let address = normalize_network_address(db, &network_id, &address)
+            .await?
+            .unwrap_or(address);
+        let page =
+            EntityQuery::find_history(db, network_id, EventEntity::Address, address, pagination)
+                .await?;
+
+        Ok(page)
+    }
+
+    /// Get the history of events applied to the asset
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_asset_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset address")] address: String,
+        #[graphql(desc = "Asset id")] id: String,
+        #[graphql(desc = "Asset network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        // Addresses invalid on the network are never stored, so their history is empty
+        let address = normalize_network_address(db, &network_id, &address)
+            .await?
+            .unwrap_or(address);
+        let page = EntityQuery::find_history(
+            db,
+            network_id,
+            EventEntity::Asset,
+            get_asset_entity_id(&address, &id),
+            pagination,
+        )
+        .await?;
+
+        Ok(page)
+    }
+
+    /// Get the history of events applied to the case
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_case_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Case id")] id: Uuid,
+        #[graphql(desc = "Case network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_history(
+            db,
+            network_id,
+            EventEntity::Case,
+            id.to_string(),
+            pagination,
+        )
+        .await?;
+
+        Ok(page)
+    }
+
+    /// Get the history of events applied to the reporter
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_reporter_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Reporter id")] id: Uuid,
+        #[graphql(desc = "Reporter network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_history(
+            db,
+            network_id,
+            EventEntity::Reporter,
+            id.to_string(),
+            pagination,
+        )
+        .await?;
+
+        Ok(page)
+    }
+}
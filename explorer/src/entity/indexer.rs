@@ -9,6 +9,8 @@ pub struct Model {
     pub created_at: DateTime,
     pub last_heartbeat: DateTime,
     pub cursor: String,
+    /// Tokens issued with another generation are rejected
+    pub token_generation: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
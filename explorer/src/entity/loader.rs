@@ -0,0 +1,198 @@
+use {
+    async_graphql::dataloader::{DataLoader, Loader},
+    async_trait::async_trait,
+    sea_orm::{
+        sea_query::Condition, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    },
+    std::{collections::HashMap, hash::Hash, sync::Arc},
+    uuid::Uuid,
+};
+
+use crate::entity::{address, asset, case, reporter};
+
+/// Case with the given id in the network
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CaseKey(pub String, pub Uuid);
+
+/// Reporter with the given id in the network
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReporterKey(pub String, pub Uuid);
+
+/// Addresses of the case with the given id in the network
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CaseAddressesKey(pub String, pub Uuid);
+
+/// Assets of the case with the given id in the network
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CaseAssetsKey(pub String, pub Uuid);
+
+/// Cases of the reporter with the given id in the network
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReporterCasesKey(pub String, pub Uuid);
+
+/// Batches relation lookups of a single GraphQL request.
+/// A new loader is attached to every request and subscription connection,
+/// so the loaded entities are never shared between them
+pub struct EntityLoader {
+    db: DatabaseConnection,
+}
+
+impl EntityLoader {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a loader that batches lookups without caching them
+    pub fn data_loader(db: DatabaseConnection) -> DataLoader<Self> {
+        DataLoader::new(Self::new(db), tokio::spawn)
+    }
+}
+
+/// Matches rows by any of the given network and id pairs
+fn keys_condition<'a, C: ColumnTrait>(
+    network_column: C,
+    id_column: C,
+    keys: impl Iterator<Item = (&'a String, &'a Uuid)>,
+) -> Condition {
+    keys.fold(Condition::any(), |condition, (network_id, id)| {
+        condition.add(
+            Condition::all()
+                .add(network_column.eq(network_id.as_str()))
+                .add(id_column.eq(*id)),
+        )
+    })
+}
+
+fn group_by<K: Eq + Hash, V>(values: Vec<V>, key: impl Fn(&V) -> K) -> HashMap<K, Vec<V>> {
+    let mut groups: HashMap<K, Vec<V>> = HashMap::new();
+
+    for value in values {
+        groups.entry(key(&value)).or_default().push(value);
+    }
+
+    groups
+}
+
+#[async_trait]
+impl Loader<CaseKey> for EntityLoader {
+    type Value = case::Model;
+    type Error = Arc<DbErr>;
+
+    async fn load(&self, keys: &[CaseKey]) -> Result<HashMap<CaseKey, Self::Value>, Self::Error> {
+        let cases = case::Entity::find()
+            .filter(keys_condition(
+                case::Column::NetworkId,
+                case::Column::Id,
+                keys.iter().map(|key| (&key.0, &key.1)),
+            ))
+            .all(&self.db)
+            .await?;
+
+        Ok(cases
+            .into_iter()
+            .map(|case| (CaseKey(case.network_id.clone(), case.id), case))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Loader<ReporterKey> for EntityLoader {
+    type Value = reporter::Model;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[ReporterKey],
+    ) -> Result<HashMap<ReporterKey, Self::Value>, Self::Error> {
+        let reporters = reporter::Entity::find()
+            .filter(keys_condition(
+                reporter::Column::NetworkId,
+                reporter::Column::Id,
+                keys.iter().map(|key| (&key.0, &key.1)),
+            ))
+            .all(&self.db)
+            .await?;
+
+        Ok(reporters
+            .into_iter()
+            .map(|reporter| {
+                (
+                    ReporterKey(reporter.network_id.clone(), reporter.id),
+                    reporter,
+                )
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Loader<CaseAddressesKey> for EntityLoader {
+    type Value = Vec<address::Model>;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[CaseAddressesKey],
+    ) -> Result<HashMap<CaseAddressesKey, Self::Value>, Self::Error> {
+        let addresses = address::Entity::find()
+            .filter(keys_condition(
+                address::Column::NetworkId,
+                address::Column::CaseId,
+                keys.iter().map(|key| (&key.0, &key.1)),
+            ))
+            .all(&self.db)
+            .await?;
+
+        Ok(group_by(addresses, |address| {
+            CaseAddressesKey(address.network_id.clone(), address.case_id)
+        }))
+    }
+}
+
+#[async_trait]
+impl Loader<CaseAssetsKey> for EntityLoader {
+    type Value = Vec<asset::Model>;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[CaseAssetsKey],
+    ) -> Result<HashMap<CaseAssetsKey, Self::Value>, Self::Error> {
+        let assets = asset::Entity::find()
+            .filter(keys_condition(
+                asset::Column::NetworkId,
+                asset::Column::CaseId,
+                keys.iter().map(|key| (&key.0, &key.1)),
+            ))
+            .all(&self.db)
+            .await?;
+
+        Ok(group_by(assets, |asset| {
+            CaseAssetsKey(asset.network_id.clone(), asset.case_id)
+        }))
+    }
+}
+
+#[async_trait]
+impl Loader<ReporterCasesKey> for EntityLoader {
+    type Value = Vec<case::Model>;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[ReporterCasesKey],
+    ) -> Result<HashMap<ReporterCasesKey, Self::Value>, Self::Error> {
+        let cases = case::Entity::find()
+            .filter(keys_condition(
+                case::Column::NetworkId,
+                case::Column::ReporterId,
+                keys.iter().map(|key| (&key.0, &key.1)),
+            ))
+            .all(&self.db)
+            .await?;
+
+        Ok(group_by(cases, |case| {
+            ReporterCasesKey(case.network_id.clone(), case.reporter_id)
+        }))
+    }
+}
@@ -0,0 +1,78 @@
+use {
+    async_graphql::{Context, Object, Result, SimpleObject},
+    sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder},
+    tracing::instrument,
+};
+
+use crate::entity::{address, asset, normalization::address_candidates, types::Category};
+
+/// Address and asset reports of an address on every network
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject)]
+pub struct AddressLookup {
+    pub address: String,
+    /// Highest risk among the reports
+    pub risk: Option<i16>,
+    /// Category of the report with the highest risk
+    pub category: Option<Category>,
+    pub addresses: Vec<address::Model>,
+    pub assets: Vec<asset::Model>,
+}
+
+/// The GraphQl Query segment
+#[derive(Default)]
+pub struct LookupQuery {}
+
+/// Queries for the address reports across networks
+#[Object]
+impl LookupQuery {
+    /// Get the address and asset reports of an address on every network
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn lookup_address(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address to look up")] address: String,
+    ) -> Result<AddressLookup> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let lookup = lookup_address(db, address).await?;
+
+        Ok(lookup)
+    }
+}
+
+/// Looks the address up on every network and aggregates the worst report
+pub async fn lookup_address(
+    db: &DatabaseConnection,
+    address: String,
+) -> Result<AddressLookup, DbErr> {
+    let candidates = address_candidates(&address);
+
+    let addresses = address::Entity::find()
+        .filter(address::Column::Address.is_in(candidates.clone()))
+        .order_by_asc(address::Column::NetworkId)
+        .all(db)
+        .await?;
+
+    let assets = asset::Entity::find()
+        .filter(asset::Column::Address.is_in(candidates))
+        .order_by_asc(asset::Column::NetworkId)
+        .order_by_asc(asset::Column::Id)
+        .all(db)
+        .await?;
+
+    let worst = addresses
+        .iter()
+        .map(|address| (address.risk, address.category))
+        .chain(assets.iter().map(|asset| (asset.risk, asset.category)))
+        .fold(None, |worst: Option<(i16, Category)>, report| match worst {
+            Some((risk, _)) if risk >= report.0 => worst,
+            _ => Some(report),
+        });
+
+    Ok(AddressLookup {
+        address,
+        risk: worst.map(|(risk, _)| risk),
+        category: worst.map(|(_, category)| category),
+        addresses,
+        assets,
+    })
+}
@@ -1,14 +1,25 @@
 pub mod address;
 pub mod asset;
 pub mod case;
+pub mod event;
 pub mod indexer;
+pub mod loader;
+pub mod lookup;
 pub mod network;
+pub mod normalization;
 pub mod pagination;
 pub mod reporter;
+pub mod screening;
+pub mod search;
 pub mod statistics;
+pub mod subscription;
 pub mod types;
+pub mod watchlist;
 
-use self::pagination::{order_by_column, Ordering};
+use self::{
+    pagination::{order_by_column, Ordering},
+    search::SearchField,
+};
 use sea_orm::{prelude::DateTime, EntityTrait, Select};
 
 pub trait FromPayload<T>: Sized {
@@ -24,11 +35,10 @@ pub trait FromPayload<T>: Sized {
 pub trait EntityFilter: Sized + EntityTrait {
     type Filter;
     type Condition;
+    type SearchField: SearchField<Column = Self::Column>;
 
     fn filter(selected: Select<Self>, filter_options: &Self::Filter) -> Select<Self>;
 
-    fn columns_for_search() -> Vec<String>;
-
     fn order(
         selected: Select<Self>,
         ordering: Option<Ordering>,
@@ -40,4 +50,13 @@ pub trait EntityFilter: Sized + EntityTrait {
     {
         order_by_column(selected, ordering, condition)
     }
+
+    /// Column the rows are ordered by, if cursor pagination is supported for the condition
+    fn cursor_column(condition: Option<Self::Condition>) -> Option<Self::Column>
+    where
+        Self::Column: From<Self::Condition>,
+        Self::Condition: Default,
+    {
+        Some(Self::Column::from(condition.unwrap_or_default()))
+    }
 }
@@ -1,4 +1,4 @@
-use super::query_utils::{NetworkCondition, NetworkFilter};
+use super::query_utils::{NetworkCondition, NetworkFilter, NetworkSearchField};
 use crate::entity::{types::NetworkBackend, EntityFilter};
 
 use {async_graphql::SimpleObject, sea_orm::entity::prelude::*};
@@ -14,6 +14,16 @@ pub struct Model {
     pub chain_id: Option<String>,
     pub authority: String,
     pub stake_token: String,
+    pub stake_unlock_duration: Option<String>,
+    pub validator_stake: Option<String>,
+    pub tracer_stake: Option<String>,
+    pub publisher_stake: Option<String>,
+    pub authority_stake: Option<String>,
+    pub reward_token: Option<String>,
+    pub address_confirmation_reward: Option<String>,
+    pub address_tracer_reward: Option<String>,
+    pub asset_confirmation_reward: Option<String>,
+    pub asset_tracer_reward: Option<String>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
@@ -21,6 +31,7 @@ pub struct Model {
 impl EntityFilter for Entity {
     type Filter = NetworkFilter;
     type Condition = NetworkCondition;
+    type SearchField = NetworkSearchField;
 
     // Filtering query
     fn filter(selected: Select<Entity>, filter_options: &NetworkFilter) -> Select<Entity> {
@@ -44,20 +55,6 @@ impl EntityFilter for Entity {
 
         query
     }
-
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("id"),
-            String::from("name"),
-            String::from("backend::text"),
-            String::from("chain_id"),
-            String::from("authority"),
-            String::from("stake_token"),
-            String::from("created_at::text"),
-            String::from("updated_at::text"),
-        ]
-    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
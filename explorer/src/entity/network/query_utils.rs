@@ -1,7 +1,13 @@
-use async_graphql::{Enum, InputObject};
+use {
+    async_graphql::{Enum, InputObject},
+    sea_orm::EnumIter,
+};
 
 use super::model::Column;
-use crate::entity::types::NetworkBackend;
+use crate::entity::{
+    search::{SearchField, SearchMode},
+    types::NetworkBackend,
+};
 
 /// Conditions to filter address listings by
 #[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
@@ -45,3 +51,41 @@ impl From<NetworkCondition> for Column {
         }
     }
 }
+
+/// Available fields to search networks by
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, EnumIter)]
+pub enum NetworkSearchField {
+    /// Search by network id
+    Id,
+    /// Search by name
+    Name,
+    /// Search by chain id
+    ChainId,
+    /// Search by authority
+    Authority,
+    /// Search by stake token
+    StakeToken,
+}
+
+impl SearchField for NetworkSearchField {
+    type Column = Column;
+
+    fn column(&self) -> Column {
+        match self {
+            NetworkSearchField::Id => Column::Id,
+            NetworkSearchField::Name => Column::Name,
+            NetworkSearchField::ChainId => Column::ChainId,
+            NetworkSearchField::Authority => Column::Authority,
+            NetworkSearchField::StakeToken => Column::StakeToken,
+        }
+    }
+
+    fn mode(&self) -> SearchMode {
+        match self {
+            NetworkSearchField::Id
+            | NetworkSearchField::Authority
+            | NetworkSearchField::StakeToken => SearchMode::Prefix,
+            NetworkSearchField::Name | NetworkSearchField::ChainId => SearchMode::Text,
+        }
+    }
+}
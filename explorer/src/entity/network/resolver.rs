@@ -6,11 +6,11 @@ use {
 
 use super::{
     model::Model,
-    query_utils::{NetworkCondition, NetworkFilter},
+    query_utils::{NetworkCondition, NetworkFilter, NetworkSearchField},
 };
 
 use crate::{
-    entity::pagination::{EntityInput, EntityPage},
+    entity::pagination::{EntityCursorInput, EntityCursorPage, EntityInput, EntityPage},
     service::EntityQuery,
 };
 
@@ -42,6 +42,7 @@ impl NetworkQuery {
         #[graphql(desc = "Network input parameters")] input: EntityInput<
             NetworkFilter,
             NetworkCondition,
+            NetworkSearchField,
         >,
     ) -> Result<EntityPage<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
@@ -49,4 +50,21 @@ impl NetworkQuery {
 
         Ok(page)
     }
+
+    /// Get multiple networks page by page after the cursor
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn get_many_networks_by_cursor(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network cursor input parameters")] input: EntityCursorInput<
+            NetworkFilter,
+            NetworkCondition,
+            NetworkSearchField,
+        >,
+    ) -> Result<EntityCursorPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_many_by_cursor::<super::model::Entity>(db, input).await?;
+
+        Ok(page)
+    }
 }
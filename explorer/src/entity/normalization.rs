@@ -0,0 +1,162 @@
+use {
+    anyhow::{bail, Result},
+    hapi_core::HapiCoreNetwork,
+    sea_orm::{ConnectionTrait, EntityTrait},
+    web3::signing::keccak256,
+};
+
+use crate::entity::{network, types::NetworkBackend};
+
+/// Length of the Solana public key in bytes
+const SOLANA_PUBKEY_LENGTH: usize = 32;
+
+/// Length limits of the NEAR account id
+const NEAR_ACCOUNT_ID_LENGTH: std::ops::RangeInclusive<usize> = 2..=64;
+
+impl NetworkBackend {
+    /// Canonical form of the address on the network backend
+    pub fn normalize_address(&self, address: &str) -> Result<String> {
+        match self {
+            NetworkBackend::Evm => normalize_evm_address(address),
+            NetworkBackend::Solana => normalize_solana_address(address),
+            NetworkBackend::Near => normalize_near_account_id(address),
+        }
+    }
+}
+
+/// Canonical form of the address indexed on the network. Solana addresses are also checked to be
+/// public keys, which can't be done by the backend as it serves Bitcoin as well
+pub fn normalize_indexed_address(network: &HapiCoreNetwork, address: &str) -> Result<String> {
+    let address = NetworkBackend::from(network.clone()).normalize_address(address)?;
+
+    if *network == HapiCoreNetwork::Solana {
+        validate_solana_pubkey(&address)?;
+    }
+
+    Ok(address)
+}
+
+/// Canonical forms the address may have on any network backend, used when the network is not known
+pub fn address_candidates(address: &str) -> Vec<String> {
+    let mut candidates = vec![];
+
+    for backend in [
+        NetworkBackend::Evm,
+        NetworkBackend::Solana,
+        NetworkBackend::Near,
+    ] {
+        if let Ok(candidate) = backend.normalize_address(address) {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Canonical form of the address on the network, `None` if the address is invalid on the network.
+/// The address is kept as is if the network does not exist
+pub async fn normalize_network_address(
+    db: &impl ConnectionTrait,
+    network_id: &str,
+    address: &str,
+) -> Result<Option<String>> {
+    let network = network::Entity::find_by_id(network_id.to_owned())
+        .one(db)
+        .await?;
+
+    let address = match network {
+        Some(network) => network.backend.normalize_address(address).ok(),
+        None => Some(address.to_owned()),
+    };
+
+    Ok(address)
+}
+
+/// EVM addresses are stored in lowercase hex, mixed case input must carry a valid EIP-55 checksum
+fn normalize_evm_address(address: &str) -> Result<String> {
+    let address = address.trim();
+
+    let Some(hex) = address.strip_prefix("0x") else {
+        bail!("Invalid EVM address: {address}");
+    };
+
+    if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("Invalid EVM address: {address}");
+    }
+
+    let lowercase = hex.to_ascii_lowercase();
+    let is_mixed_case = hex != lowercase && hex != hex.to_ascii_uppercase();
+
+    if is_mixed_case && hex != to_checksum(&lowercase) {
+        bail!("Invalid EIP-55 checksum of EVM address: {address}");
+    }
+
+    Ok(format!("0x{lowercase}"))
+}
+
+/// EIP-55 mixed case form of the lowercase hex address without the prefix
+fn to_checksum(lowercase: &str) -> String {
+    let hash = keccak256(lowercase.as_bytes());
+
+    lowercase
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Solana backend also serves Bitcoin, so its addresses are kept as opaque case-sensitive strings
+fn normalize_solana_address(address: &str) -> Result<String> {
+    let address = address.trim();
+
+    if address.is_empty() {
+        bail!("Empty address");
+    }
+
+    Ok(address.to_owned())
+}
+
+/// Solana addresses are base58 encoded public keys
+fn validate_solana_pubkey(address: &str) -> Result<()> {
+    match bs58::decode(address).into_vec() {
+        Ok(bytes) if bytes.len() == SOLANA_PUBKEY_LENGTH => Ok(()),
+        _ => bail!("Invalid Solana address: {address}"),
+    }
+}
+
+/// NEAR account ids consist of lowercase alphanumeric parts joined by single `.`, `-` or `_` separators
+fn normalize_near_account_id(address: &str) -> Result<String> {
+    let account_id = address.trim().to_lowercase();
+
+    let is_separator = |c: char| matches!(c, '.' | '-' | '_');
+    let mut previous_separator = true;
+
+    for c in account_id.chars() {
+        if is_separator(c) {
+            if previous_separator {
+                bail!("Invalid NEAR account id: {address}");
+            }
+            previous_separator = true;
+        } else if c.is_ascii_lowercase() || c.is_ascii_digit() {
+            previous_separator = false;
+        } else {
+            bail!("Invalid NEAR account id: {address}");
+        }
+    }
+
+    if previous_separator || !NEAR_ACCOUNT_ID_LENGTH.contains(&account_id.len()) {
+        bail!("Invalid NEAR account id: {address}");
+    }
+
+    Ok(account_id)
+}
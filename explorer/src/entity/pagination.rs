@@ -1,28 +1,37 @@
 use {
     async_graphql::{Enum, InputObject, InputType, OutputType, SimpleObject},
-    sea_orm::{EntityTrait, QueryOrder, Select},
+    base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _},
+    chrono::NaiveDateTime,
+    sea_orm::{
+        sea_query::{Expr, SimpleExpr},
+        ColumnTrait, ColumnType, Condition, DbErr, EntityTrait, ModelTrait, QueryOrder, Select,
+        Value,
+    },
+    uuid::Uuid,
 };
 
 use super::{
     address::{
         model::Model as Address,
-        query_utils::{AddressCondition, AddressFilter},
+        query_utils::{AddressCondition, AddressFilter, AddressSearchField},
     },
     asset::{
         model::Model as Asset,
-        query_utils::{AssetCondition, AssetFilter},
+        query_utils::{AssetCondition, AssetFilter, AssetSearchField},
     },
     case::{
         model::Model as Case,
-        query_utils::{CaseCondition, CaseFilter},
+        query_utils::{CaseCondition, CaseFilter, CaseSearchField},
     },
+    event::model::Model as Event,
     network::{
         model::Model as Network,
-        query_utils::{NetworkCondition, NetworkFilter},
+        query_utils::{NetworkCondition, NetworkFilter, NetworkSearchField},
     },
     reporter::{
         model::Model as Reporter,
-        query_utils::{ReporterCondition, ReporterFilter},
+        query_utils::{ReporterCondition, ReporterFilter, ReporterSearchField},
+        stats::{ReporterStats, ReporterStatsCondition},
     },
 };
 
@@ -45,6 +54,17 @@ impl Default for Paginator {
     }
 }
 
+/// Cursor pagination options
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct CursorPaginator {
+    /// Cursor of the last row of the previous page, the first page if omitted
+    pub after: Option<String>,
+    pub page_size: u64,
+    /// Do not count the total number of rows
+    #[graphql(default)]
+    pub skip_total: bool,
+}
+
 /// A convenience wrapper for ordering
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Default, Debug)]
 pub enum Ordering {
@@ -74,6 +94,119 @@ where
     }
 }
 
+const CURSOR_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// Encodes the values of the columns in the row into an opaque cursor
+pub fn encode_cursor<M>(model: &M::Model, columns: &[M::Column]) -> Result<String, DbErr>
+where
+    M: EntityTrait,
+{
+    let values = columns
+        .iter()
+        .map(|column| match model.get(*column) {
+            Value::String(value) => Ok(value.map(|value| *value)),
+            Value::Uuid(value) => Ok(value.map(|value| value.to_string())),
+            Value::ChronoDateTime(value) => {
+                Ok(value.map(|value| value.format(CURSOR_DATE_TIME_FORMAT).to_string()))
+            }
+            Value::SmallInt(value) => Ok(value.map(|value| value.to_string())),
+            Value::Int(value) => Ok(value.map(|value| value.to_string())),
+            Value::BigInt(value) => Ok(value.map(|value| value.to_string())),
+            value => Err(DbErr::Custom(format!(
+                "Unsupported cursor value: {value:?}"
+            ))),
+        })
+        .collect::<Result<Vec<Option<String>>, DbErr>>()?;
+
+    Ok(URL_SAFE_NO_PAD.encode(serde_json::json!(values).to_string()))
+}
+
+/// Decodes the values of the columns from the cursor
+pub fn decode_cursor<M>(cursor: &str, columns: &[M::Column]) -> Result<Vec<Option<Value>>, DbErr>
+where
+    M: EntityTrait,
+{
+    let invalid_cursor = || DbErr::Custom("Invalid cursor".to_string());
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|_| invalid_cursor())?;
+    let values: Vec<Option<String>> =
+        serde_json::from_slice(&bytes).map_err(|_| invalid_cursor())?;
+
+    if values.len() != columns.len() {
+        return Err(invalid_cursor());
+    }
+
+    columns
+        .iter()
+        .zip(values)
+        .map(|(column, value)| {
+            let Some(value) = value else {
+                return Ok(None);
+            };
+
+            let value = match column.def().get_column_type() {
+                ColumnType::String(_)
+                | ColumnType::Text
+                | ColumnType::Char(_)
+                | ColumnType::Enum { .. } => Value::from(value),
+                ColumnType::Uuid => {
+                    Value::from(Uuid::parse_str(&value).map_err(|_| invalid_cursor())?)
+                }
+                ColumnType::DateTime | ColumnType::Timestamp => Value::from(
+                    NaiveDateTime::parse_from_str(&value, CURSOR_DATE_TIME_FORMAT)
+                        .map_err(|_| invalid_cursor())?,
+                ),
+                ColumnType::SmallInteger => {
+                    Value::from(value.parse::<i16>().map_err(|_| invalid_cursor())?)
+                }
+                ColumnType::Integer => {
+                    Value::from(value.parse::<i32>().map_err(|_| invalid_cursor())?)
+                }
+                ColumnType::BigInteger => {
+                    Value::from(value.parse::<i64>().map_err(|_| invalid_cursor())?)
+                }
+                _ => return Err(invalid_cursor()),
+            };
+
+            Ok(Some(value))
+        })
+        .collect()
+}
+
+/// Condition selecting the rows that follow the cursor values in the ordering.
+/// Postgres puts nulls last in ascending and first in descending order.
+pub fn after_cursor<C>(columns: &[C], values: &[Option<Value>], ordering: Ordering) -> Condition
+where
+    C: ColumnTrait,
+{
+    let mut condition: Option<Condition> = None;
+
+    for (column, value) in columns.iter().zip(values).rev() {
+        let same = match value {
+            Some(value) => column.eq(value.clone()),
+            None => column.is_null(),
+        };
+
+        let following: SimpleExpr = match (value, ordering) {
+            (Some(value), Ordering::Asc) => column.gt(value.clone()).or(column.is_null()),
+            (Some(value), Ordering::Desc) => column.lt(value.clone()),
+            (None, Ordering::Asc) => Expr::cust("FALSE"),
+            (None, Ordering::Desc) => column.is_not_null(),
+        };
+
+        condition = Some(match condition {
+            Some(next) => Condition::any()
+                .add(following)
+                .add(Condition::all().add(same).add(next)),
+            None => Condition::all().add(following),
+        });
+    }
+
+    condition.unwrap_or_else(Condition::all)
+}
+
 /// Paginated response for an entity
 #[derive(Clone, Debug, Eq, PartialEq, SimpleObject)]
 #[graphql(concrete(name = "NetworkPage", params(Network)))]
@@ -81,6 +214,8 @@ where
 #[graphql(concrete(name = "CasePage", params(Case)))]
 #[graphql(concrete(name = "AddressPage", params(Address)))]
 #[graphql(concrete(name = "AssetPage", params(Asset)))]
+#[graphql(concrete(name = "EventPage", params(Event)))]
+#[graphql(concrete(name = "ReporterStatsPage", params(ReporterStats)))]
 pub struct EntityPage<Entity: Send + Sync + OutputType> {
     /// The page of data being returned
     pub data: Vec<Entity>,
@@ -90,14 +225,48 @@ pub struct EntityPage<Entity: Send + Sync + OutputType> {
     pub page_count: u64,
 }
 
+/// Cursor paginated response for an entity
+#[derive(Clone, Debug, Eq, PartialEq, SimpleObject)]
+#[graphql(concrete(name = "NetworkCursorPage", params(Network)))]
+#[graphql(concrete(name = "ReporterCursorPage", params(Reporter)))]
+#[graphql(concrete(name = "CaseCursorPage", params(Case)))]
+#[graphql(concrete(name = "AddressCursorPage", params(Address)))]
+#[graphql(concrete(name = "AssetCursorPage", params(Asset)))]
+pub struct EntityCursorPage<Entity: Send + Sync + OutputType> {
+    /// The page of data being returned
+    pub data: Vec<Entity>,
+    /// The total number of rows available, unless counting is skipped
+    pub total: Option<u64>,
+    /// The number of pages available, unless counting is skipped
+    pub page_count: Option<u64>,
+    /// Cursor of the next page, if there is one
+    pub next_cursor: Option<String>,
+}
+
 /// Entity input type
 #[derive(Clone, Default, Eq, PartialEq, InputObject, Debug)]
-#[graphql(concrete(name = "NetworkInput", params(NetworkFilter, NetworkCondition)))]
-#[graphql(concrete(name = "ReporterInput", params(ReporterFilter, ReporterCondition)))]
-#[graphql(concrete(name = "CaseInput", params(CaseFilter, CaseCondition)))]
-#[graphql(concrete(name = "AddressInput", params(AddressFilter, AddressCondition)))]
-#[graphql(concrete(name = "AssetInput", params(AssetFilter, AssetCondition)))]
-pub struct EntityInput<F: InputType, C: InputType> {
+#[graphql(concrete(
+    name = "NetworkInput",
+    params(NetworkFilter, NetworkCondition, NetworkSearchField)
+))]
+#[graphql(concrete(
+    name = "ReporterInput",
+    params(ReporterFilter, ReporterCondition, ReporterSearchField)
+))]
+#[graphql(concrete(name = "CaseInput", params(CaseFilter, CaseCondition, CaseSearchField)))]
+#[graphql(concrete(
+    name = "AddressInput",
+    params(AddressFilter, AddressCondition, AddressSearchField)
+))]
+#[graphql(concrete(
+    name = "AssetInput",
+    params(AssetFilter, AssetCondition, AssetSearchField)
+))]
+#[graphql(concrete(
+    name = "ReporterStatsInput",
+    params(ReporterFilter, ReporterStatsCondition, ReporterSearchField)
+))]
+pub struct EntityInput<F: InputType, C: InputType, S: InputType> {
     /// Conditions to filter entities by
     pub filtering: Option<F>,
 
@@ -112,4 +281,46 @@ pub struct EntityInput<F: InputType, C: InputType> {
 
     /// String value to search entities
     pub search: Option<String>,
+
+    /// Field to search in, all of the searchable fields by default
+    pub search_field: Option<S>,
+}
+
+/// Entity input type for cursor pagination
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+#[graphql(concrete(
+    name = "NetworkCursorInput",
+    params(NetworkFilter, NetworkCondition, NetworkSearchField)
+))]
+#[graphql(concrete(
+    name = "ReporterCursorInput",
+    params(ReporterFilter, ReporterCondition, ReporterSearchField)
+))]
+#[graphql(concrete(name = "CaseCursorInput", params(CaseFilter, CaseCondition, CaseSearchField)))]
+#[graphql(concrete(
+    name = "AddressCursorInput",
+    params(AddressFilter, AddressCondition, AddressSearchField)
+))]
+#[graphql(concrete(
+    name = "AssetCursorInput",
+    params(AssetFilter, AssetCondition, AssetSearchField)
+))]
+pub struct EntityCursorInput<F: InputType, C: InputType, S: InputType> {
+    /// Conditions to filter entities by
+    pub filtering: Option<F>,
+
+    /// Available ordering
+    pub ordering: Option<Ordering>,
+
+    /// Available ordering values for entities
+    pub ordering_condition: Option<C>,
+
+    /// Cursor pagination options
+    pub cursor: CursorPaginator,
+
+    /// String value to search entities
+    pub search: Option<String>,
+
+    /// Field to search in, all of the searchable fields by default
+    pub search_field: Option<S>,
 }
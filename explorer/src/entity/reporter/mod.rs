@@ -1,6 +1,10 @@
 pub(super) mod model;
 pub(super) mod query_utils;
 pub(super) mod resolver;
+pub(super) mod stats;
 
 pub use model::{ActiveModel, Column, Entity, Model};
+pub use query_utils::ReporterFilter;
 pub(crate) use resolver::ReporterQuery;
+pub(crate) use stats::select_reporter_stats;
+pub use stats::{ReporterStats, ReporterStatsCondition};
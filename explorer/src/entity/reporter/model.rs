@@ -2,9 +2,10 @@ use {
     async_graphql::SimpleObject,
     hapi_core::client::entities::reporter::Reporter as ReporterPayload,
     sea_orm::{entity::prelude::*, NotSet, Set},
+    serde::Serialize,
 };
 
-use super::query_utils::{ReporterCondition, ReporterFilter};
+use super::query_utils::{ReporterCondition, ReporterFilter, ReporterSearchField};
 use crate::entity::{
     address, asset, case,
     types::{ReporterRole, ReporterStatus},
@@ -12,8 +13,8 @@ use crate::entity::{
 };
 
 // Note: unlock_timestamp and stake do not correspond to the types of contracts (due to Postgresql restrictions)
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
-#[graphql(name = "Reporter")]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject, Serialize)]
+#[graphql(name = "Reporter", complex)]
 #[sea_orm(table_name = "reporter")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -34,6 +35,7 @@ pub struct Model {
 impl EntityFilter for Entity {
     type Filter = ReporterFilter;
     type Condition = ReporterCondition;
+    type SearchField = ReporterSearchField;
 
     // Filtering query
     fn filter(selected: Select<Entity>, filter_options: &ReporterFilter) -> Select<Entity> {
@@ -65,23 +67,6 @@ impl EntityFilter for Entity {
 
         query
     }
-
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("network_id"),
-            String::from("id::text"),
-            String::from("account"),
-            String::from("role::text"),
-            String::from("status::text"),
-            String::from("name"),
-            String::from("url"),
-            String::from("stake"),
-            String::from("unlock_timestamp"),
-            String::from("created_at::text"),
-            String::from("updated_at::text"),
-        ]
-    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,16 +1,30 @@
-use async_graphql::{Enum, InputObject};
+use {
+    async_graphql::{Enum, InputObject},
+    clap::Args,
+    sea_orm::EnumIter,
+    serde::Deserialize,
+};
 
 use super::model::Column;
-use crate::entity::types::{ReporterRole, ReporterStatus};
+use crate::entity::{
+    search::{SearchField, SearchMode},
+    types::{ReporterRole, ReporterStatus},
+};
 
 /// Conditions to filter address listings by
-#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default, Deserialize, Args)]
 pub struct ReporterFilter {
+    #[arg(long, help = "Network string identifier")]
     pub network_id: Option<String>,
+    #[arg(long, help = "Reporter account address")]
     pub account: Option<String>,
+    #[arg(long, value_enum, help = "Reporter role")]
     pub role: Option<ReporterRole>,
+    #[arg(long, value_enum, help = "Reporter status")]
     pub status: Option<ReporterStatus>,
+    #[arg(long, help = "Reporter name")]
     pub name: Option<String>,
+    #[arg(long, help = "Reporter url")]
     pub url: Option<String>,
 }
 
@@ -59,3 +73,40 @@ impl From<ReporterCondition> for Column {
         }
     }
 }
+
+/// Available fields to search reporters by
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, EnumIter)]
+pub enum ReporterSearchField {
+    /// Search by network id
+    NetworkId,
+    /// Search by reporter id
+    Id,
+    /// Search by account
+    Account,
+    /// Search by name
+    Name,
+    /// Search by url
+    Url,
+}
+
+impl SearchField for ReporterSearchField {
+    type Column = Column;
+
+    fn column(&self) -> Column {
+        match self {
+            ReporterSearchField::NetworkId => Column::NetworkId,
+            ReporterSearchField::Id => Column::Id,
+            ReporterSearchField::Account => Column::Account,
+            ReporterSearchField::Name => Column::Name,
+            ReporterSearchField::Url => Column::Url,
+        }
+    }
+
+    fn mode(&self) -> SearchMode {
+        match self {
+            ReporterSearchField::NetworkId | ReporterSearchField::Account => SearchMode::Prefix,
+            ReporterSearchField::Id => SearchMode::Uuid,
+            ReporterSearchField::Name | ReporterSearchField::Url => SearchMode::Text,
+        }
+    }
+}
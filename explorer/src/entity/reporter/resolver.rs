@@ -1,5 +1,5 @@
 use {
-    async_graphql::{Context, Object, Result},
+    async_graphql::{dataloader::DataLoader, ComplexObject, Context, Object, Result},
     sea_orm::DatabaseConnection,
     tracing::instrument,
     uuid::Uuid,
@@ -7,11 +7,16 @@ use {
 
 use super::{
     model::Model,
-    query_utils::{ReporterCondition, ReporterFilter},
+    query_utils::{ReporterCondition, ReporterFilter, ReporterSearchField},
+    stats::{ReporterStats, ReporterStatsCondition},
 };
 
 use crate::{
-    entity::pagination::{EntityInput, EntityPage},
+    entity::{
+        case,
+        loader::{EntityLoader, ReporterCasesKey},
+        pagination::{EntityCursorInput, EntityCursorPage, EntityInput, EntityPage},
+    },
     service::EntityQuery,
 };
 
@@ -45,6 +50,7 @@ impl ReporterQuery {
         #[graphql(desc = "Reporter input parameters")] input: EntityInput<
             ReporterFilter,
             ReporterCondition,
+            ReporterSearchField,
         >,
     ) -> Result<EntityPage<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
@@ -52,4 +58,52 @@ impl ReporterQuery {
 
         Ok(page)
     }
+
+    /// Get multiple reporters page by page after the cursor
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn get_many_reporters_by_cursor(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Reporter cursor input parameters")] input: EntityCursorInput<
+            ReporterFilter,
+            ReporterCondition,
+            ReporterSearchField,
+        >,
+    ) -> Result<EntityCursorPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_many_by_cursor::<super::model::Entity>(db, input).await?;
+
+        Ok(page)
+    }
+
+    /// Get reporters with their activity metrics
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn get_reporter_stats(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Reporter statistics input parameters")] input: EntityInput<
+            ReporterFilter,
+            ReporterStatsCondition,
+            ReporterSearchField,
+        >,
+    ) -> Result<EntityPage<ReporterStats>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let page = EntityQuery::find_reporter_stats(db, input).await?;
+
+        Ok(page)
+    }
+}
+
+/// Relations of the `Reporter` model
+#[ComplexObject]
+impl Model {
+    /// Cases of the reporter
+    async fn cases(&self, ctx: &Context<'_>) -> Result<Vec<case::Model>> {
+        let loader = ctx.data_unchecked::<DataLoader<EntityLoader>>();
+        let cases = loader
+            .load_one(ReporterCasesKey(self.network_id.clone(), self.id))
+            .await?;
+
+        Ok(cases.unwrap_or_default())
+    }
 }
@@ -0,0 +1,136 @@
+use {
+    async_graphql::{Enum, SimpleObject},
+    sea_orm::{
+        entity::prelude::*, sea_query::SimpleExpr, FromQueryResult, QueryOrder, QuerySelect,
+        SelectModel, Selector,
+    },
+};
+
+use super::model::{Column, Entity};
+use crate::entity::{pagination::Ordering, types::ReporterStatus};
+
+/// Activity metrics of a reporter
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, FromQueryResult)]
+pub struct ReporterStats {
+    pub network_id: String,
+    pub id: Uuid,
+    pub account: String,
+    pub name: String,
+    pub status: ReporterStatus,
+    pub stake: String,
+    pub case_count: i64,
+    pub address_count: i64,
+    pub asset_count: i64,
+    /// Confirmations of the addresses and assets created by the reporter
+    pub confirmations_received: i64,
+    /// Confirmations of addresses and assets given by the reporter
+    pub confirmations_given: i64,
+    /// The latest update of the reporter, of the entities created by it or of its confirmations
+    pub last_activity: DateTime,
+}
+
+/// Available ordering values for reporter statistics
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub enum ReporterStatsCondition {
+    /// Order by the number of created cases
+    CaseCount,
+    /// Order by the number of created addresses
+    AddressCount,
+    /// Order by the number of created assets
+    AssetCount,
+    /// Order by the number of received confirmations
+    ConfirmationsReceived,
+    /// Order by the number of given confirmations
+    ConfirmationsGiven,
+    /// Order by stake
+    Stake,
+    /// Order by the last activity time
+    #[default]
+    LastActivity,
+}
+
+impl ReporterStatsCondition {
+    fn expr(&self) -> SimpleExpr {
+        match self {
+            ReporterStatsCondition::CaseCount => Expr::cust("case_count"),
+            ReporterStatsCondition::AddressCount => Expr::cust("address_count"),
+            ReporterStatsCondition::AssetCount => Expr::cust("asset_count"),
+            ReporterStatsCondition::ConfirmationsReceived => Expr::cust("confirmations_received"),
+            ReporterStatsCondition::ConfirmationsGiven => Expr::cust("confirmations_given"),
+            // Stake is stored as text, so it is compared as a number
+            ReporterStatsCondition::Stake => Expr::cust("reporter.stake::numeric"),
+            ReporterStatsCondition::LastActivity => Expr::cust("last_activity"),
+        }
+    }
+}
+
+/// Subquery aggregating the entities of the table created by the reporter
+fn related(table: &str, aggregate: &str) -> String {
+    format!(
+        "(SELECT {aggregate} FROM \"{table}\" \
+        WHERE \"{table}\".network_id = reporter.network_id \
+        AND \"{table}\".reporter_id = reporter.id)"
+    )
+}
+
+/// Subquery aggregating the confirmation events sent by the reporter
+fn confirmed(aggregate: &str) -> String {
+    format!(
+        "(SELECT {aggregate} FROM \"event\" \
+        WHERE \"event\".network_id = reporter.network_id \
+        AND \"event\".confirmed_by = reporter.id)"
+    )
+}
+
+/// Selects reporters along with their activity metrics
+pub(crate) fn select_reporter_stats(
+    query: Select<Entity>,
+    ordering: Option<Ordering>,
+    condition: Option<ReporterStatsCondition>,
+) -> Selector<SelectModel<ReporterStats>> {
+    let confirmations = "COALESCE(SUM(confirmations::numeric), 0)";
+
+    let query = query
+        .select_only()
+        .column(Column::NetworkId)
+        .column(Column::Id)
+        .column(Column::Account)
+        .column(Column::Name)
+        .column(Column::Status)
+        .column(Column::Stake)
+        .column_as(Expr::cust(related("case", "COUNT(*)")), "case_count")
+        .column_as(Expr::cust(related("address", "COUNT(*)")), "address_count")
+        .column_as(Expr::cust(related("asset", "COUNT(*)")), "asset_count")
+        .column_as(
+            Expr::cust(format!(
+                "CAST({} + {} AS BIGINT)",
+                related("address", confirmations),
+                related("asset", confirmations)
+            )),
+            "confirmations_received",
+        )
+        .column_as(Expr::cust(confirmed("COUNT(*)")), "confirmations_given")
+        // GREATEST skips the subqueries of the reporters without entities
+        .column_as(
+            Expr::cust(format!(
+                "GREATEST(reporter.updated_at, {}, {}, {}, {})",
+                related("case", "MAX(updated_at)"),
+                related("address", "MAX(updated_at)"),
+                related("asset", "MAX(updated_at)"),
+                confirmed("MAX(timestamp)")
+            )),
+            "last_activity",
+        );
+
+    let column = condition.unwrap_or_default().expr();
+    let query = match ordering.unwrap_or_default() {
+        Ordering::Asc => query.order_by_asc(column),
+        Ordering::Desc => query.order_by_desc(column),
+    };
+
+    // Reporters with the same metrics keep a stable order between pages
+    query
+        .order_by_asc(Column::NetworkId)
+        .order_by_asc(Column::Id)
+        .into_model::<ReporterStats>()
+}
@@ -0,0 +1,212 @@
+use {
+    async_graphql::{Context, Enum, InputObject, Object, Result, SimpleObject},
+    sea_orm::{ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter},
+    serde::{Deserialize, Serialize},
+    std::collections::HashMap,
+    tracing::instrument,
+    uuid::Uuid,
+};
+
+use crate::entity::{
+    address, case, network,
+    normalization::address_candidates,
+    types::{Category, NetworkBackend},
+};
+
+/// Upper bound of the addresses screened in a single request
+pub const MAX_SCREENING_TARGETS: usize = 1000;
+
+/// Address to screen on the network, or on every network if it is omitted
+#[derive(Clone, Debug, PartialEq, Eq, InputObject, Deserialize)]
+pub struct ScreeningTarget {
+    pub network_id: Option<String>,
+    pub address: String,
+}
+
+/// Outcome of the screening against the risk threshold
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    /// The address is not reported
+    Unknown,
+    /// Every report of the address is below the threshold
+    Clear,
+    /// The address is reported with a risk at or above the threshold
+    Flagged,
+}
+
+/// Report of the screened address on a network
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
+pub struct ScreeningMatch {
+    pub network_id: String,
+    pub category: Category,
+    pub risk: i16,
+    pub confirmations: String,
+    pub case_id: Uuid,
+    pub case: Option<case::Model>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
+pub struct ScreeningResult {
+    pub network_id: Option<String>,
+    pub address: String,
+    pub verdict: Verdict,
+    pub matches: Vec<ScreeningMatch>,
+}
+
+/// The GraphQl Query segment
+#[derive(Default)]
+pub struct ScreeningQuery {}
+
+/// Queries for the address screening
+#[Object]
+impl ScreeningQuery {
+    /// Screen addresses against the risk threshold
+    #[instrument(level = "debug", skip(self, ctx, targets), fields(targets = targets.len()))]
+    pub async fn screen_addresses(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Addresses to screen")] targets: Vec<ScreeningTarget>,
+        #[graphql(desc = "Risk from which addresses are flagged")] threshold: i16,
+    ) -> Result<Vec<ScreeningResult>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+
+        validate_targets(&targets)?;
+
+        let results = screen_addresses(db, &targets, threshold).await?;
+
+        Ok(results)
+    }
+}
+
+/// Checks that the number of addresses fits a single request
+pub fn validate_targets(targets: &[ScreeningTarget]) -> Result<(), String> {
+    if targets.len() > MAX_SCREENING_TARGETS {
+        return Err(format!(
+            "At most {MAX_SCREENING_TARGETS} addresses can be screened at once"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Looks the addresses and their cases up in two queries and gives a verdict for each of them
+pub async fn screen_addresses(
+    db: &DatabaseConnection,
+    targets: &[ScreeningTarget],
+    threshold: i16,
+) -> Result<Vec<ScreeningResult>, DbErr> {
+    let backends: HashMap<String, NetworkBackend> = network::Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|network| (network.id, network.backend))
+        .collect();
+
+    // Canonical forms of the target addresses, invalid addresses and unknown networks match nothing
+    let candidates: Vec<Vec<String>> = targets
+        .iter()
+        .map(|target| match &target.network_id {
+            Some(network_id) => backends
+                .get(network_id)
+                .and_then(|backend| backend.normalize_address(&target.address).ok())
+                .into_iter()
+                .collect(),
+            None => address_candidates(&target.address),
+        })
+        .collect();
+
+    let addresses = if candidates.iter().all(Vec::is_empty) {
+        vec![]
+    } else {
+        let condition = targets.iter().zip(&candidates).fold(
+            Condition::any(),
+            |condition, (target, candidates)| {
+                let mut target_condition =
+                    Condition::all().add(address::Column::Address.is_in(candidates.clone()));
+
+                if let Some(network_id) = &target.network_id {
+                    target_condition =
+                        target_condition.add(address::Column::NetworkId.eq(network_id.clone()));
+                }
+
+                condition.add(target_condition)
+            },
+        );
+
+        address::Entity::find().filter(condition).all(db).await?
+    };
+
+    let cases: HashMap<(String, Uuid), case::Model> = if addresses.is_empty() {
+        HashMap::new()
+    } else {
+        let condition = addresses
+            .iter()
+            .fold(Condition::any(), |condition, address| {
+                condition.add(
+                    Condition::all()
+                        .add(case::Column::NetworkId.eq(address.network_id.clone()))
+                        .add(case::Column::Id.eq(address.case_id)),
+                )
+            });
+
+        case::Entity::find()
+            .filter(condition)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|case| ((case.network_id.clone(), case.id), case))
+            .collect()
+    };
+
+    let mut reports: HashMap<&str, Vec<&address::Model>> = HashMap::new();
+    for address in &addresses {
+        reports
+            .entry(address.address.as_str())
+            .or_default()
+            .push(address);
+    }
+
+    Ok(targets
+        .iter()
+        .zip(&candidates)
+        .map(|(target, candidates)| {
+            let matches: Vec<ScreeningMatch> = candidates
+                .iter()
+                .filter_map(|candidate| reports.get(candidate.as_str()))
+                .flatten()
+                .filter(|address| {
+                    target
+                        .network_id
+                        .as_ref()
+                        .map_or(true, |network_id| &address.network_id == network_id)
+                })
+                .map(|address| ScreeningMatch {
+                    network_id: address.network_id.clone(),
+                    category: address.category,
+                    risk: address.risk,
+                    confirmations: address.confirmations.clone(),
+                    case_id: address.case_id,
+                    case: cases
+                        .get(&(address.network_id.clone(), address.case_id))
+                        .cloned(),
+                })
+                .collect();
+
+            let verdict = if matches.is_empty() {
+                Verdict::Unknown
+            } else if matches.iter().any(|report| report.risk >= threshold) {
+                Verdict::Flagged
+            } else {
+                Verdict::Clear
+            };
+
+            ScreeningResult {
+                network_id: target.network_id.clone(),
+                address: target.address.clone(),
+                verdict,
+                matches,
+            }
+        })
+        .collect())
+}
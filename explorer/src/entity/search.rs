@@ -0,0 +1,85 @@
+use {
+    sea_orm::{
+        sea_query::{extension::postgres::PgExpr, Expr, SimpleExpr},
+        ColumnTrait, Condition, EntityTrait, Iterable,
+    },
+    uuid::Uuid,
+};
+
+/// How the search value is matched against a column
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SearchMode {
+    /// Case-insensitive substring of the text
+    Text,
+    /// Case-insensitive prefix, e.g. of an address or an account
+    Prefix,
+    /// Uuid starting with the given hex digits
+    Uuid,
+}
+
+/// Field of an entity available to search by
+pub trait SearchField: Copy + Iterable {
+    type Column: ColumnTrait;
+
+    fn column(&self) -> Self::Column;
+
+    fn mode(&self) -> SearchMode;
+}
+
+/// Condition matching the value in the given field, or in any of the searchable fields
+pub fn search_condition<M, F>(value: &str, field: Option<F>) -> Condition
+where
+    M: EntityTrait,
+    F: SearchField<Column = M::Column>,
+{
+    let fields = field.map_or_else(|| F::iter().collect(), |field| vec![field]);
+    let exprs = fields
+        .into_iter()
+        .filter_map(|field| field_condition::<M, F>(field, value))
+        .collect::<Vec<_>>();
+
+    // A value that can not match any of the fields must not fall back to all rows
+    if exprs.is_empty() {
+        return Condition::all().add(Expr::cust("FALSE"));
+    }
+
+    exprs
+        .into_iter()
+        .fold(Condition::any(), |condition, expr| condition.add(expr))
+}
+
+fn field_condition<M, F>(field: F, value: &str) -> Option<SimpleExpr>
+where
+    M: EntityTrait,
+    F: SearchField<Column = M::Column>,
+{
+    let column = Expr::col((M::default(), field.column()));
+
+    match field.mode() {
+        SearchMode::Text => Some(column.ilike(format!("%{}%", escape_like(value)))),
+        SearchMode::Prefix => Some(column.ilike(format!("{}%", escape_like(value)))),
+        SearchMode::Uuid => uuid_range(value).map(|(from, to)| column.between(from, to)),
+    }
+}
+
+/// Escapes the wildcards of the LIKE pattern
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Range of the uuids starting with the hex digits, hyphens are ignored
+fn uuid_range(value: &str) -> Option<(Uuid, Uuid)> {
+    let digits = value.replace('-', "").to_lowercase();
+
+    if digits.is_empty() || digits.len() > 32 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let from = Uuid::parse_str(&format!("{digits:0<32}")).ok()?;
+    let to = Uuid::parse_str(&format!("{digits:f<32}")).ok()?;
+
+    Some((from, to))
+}
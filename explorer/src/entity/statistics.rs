@@ -1,7 +1,12 @@
 use {
-    async_graphql::{Context, Object, Result, SimpleObject},
-    chrono::{Datelike, Utc, Weekday},
-    sea_orm::{DatabaseConnection, EntityTrait, PaginatorTrait, QuerySelect},
+    async_graphql::{Context, Enum, InputObject, Object, Result, SimpleObject},
+    chrono::{Datelike, Duration, Months, NaiveDate, Utc},
+    sea_orm::{
+        sea_query::{Alias, Expr},
+        ActiveEnum, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter,
+        QueryOrder, QuerySelect, Select,
+    },
+    serde::{Deserialize, Serialize},
     tracing::instrument,
     web3::types::U256,
 };
@@ -12,13 +17,20 @@ use super::{
 };
 
 use crate::{
-    entity::{address, asset, case, reporter, EntityFilter},
-    service::count_rows_per_week,
+    entity::{
+        address, asset, case, reporter,
+        types::{Category, ReporterStatus},
+        EntityFilter,
+    },
+    service::{count_rows_per_bucket, count_rows_per_week},
 };
 
 pub const CHART_LENGTH: usize = 10;
 
-#[derive(Clone, Debug, PartialEq, Eq, SimpleObject)]
+/// Upper bound of the chart buckets in a single request
+pub const MAX_CHART_LENGTH: usize = 1000;
+
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
 #[graphql(name = "Dashboard")]
 pub struct Dashboard {
     pub staked_by_reporters: String,
@@ -35,7 +47,7 @@ pub struct Dashboard {
     pub top_cases_by_asset: Vec<case::Model>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, SimpleObject)]
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
 pub struct Charts {
     pub labels: Vec<String>,
     pub addresses: Vec<u64>,
@@ -43,6 +55,166 @@ pub struct Charts {
     pub cases: Vec<u64>,
 }
 
+/// Number of entities with the same network, category and risk score
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
+pub struct RiskBreakdown {
+    pub network_id: String,
+    pub category: Category,
+    pub risk: i16,
+    pub count: u64,
+    /// Number of distinct reporters of the entities
+    pub reporter_count: u64,
+}
+
+/// Number of reporters with the same network and status
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
+pub struct ReporterActivity {
+    pub network_id: String,
+    pub status: ReporterStatus,
+    pub count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
+pub struct Breakdown {
+    pub addresses: Vec<RiskBreakdown>,
+    pub assets: Vec<RiskBreakdown>,
+    pub reporters: Vec<ReporterActivity>,
+}
+
+/// Conditions to narrow the statistics down
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, InputObject, Deserialize)]
+pub struct StatisticsFilter {
+    /// Network to count entities in
+    pub network_id: Option<String>,
+    /// First day of the range, inclusive
+    pub from: Option<NaiveDate>,
+    /// Last day of the range, inclusive
+    pub to: Option<NaiveDate>,
+}
+
+impl StatisticsFilter {
+    /// Checks that the range is ordered
+    pub fn validate_range(&self) -> Result<(), String> {
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from > to {
+                return Err("The start of the range is after its end".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the range is ordered and fits the chart limits
+    pub fn validate(&self, granularity: ChartGranularity) -> Result<(), String> {
+        self.validate_range()?;
+
+        if granularity.buckets(self.from, self.to).len() > MAX_CHART_LENGTH {
+            return Err(format!(
+                "The range exceeds {MAX_CHART_LENGTH} chart buckets"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Applies the network and date range conditions to the entity query
+    fn apply<M: EntityTrait>(&self, mut query: Select<M>) -> Select<M> {
+        if let Some(network_id) = &self.network_id {
+            query =
+                query.filter(Expr::col((M::default(), Alias::new("network_id"))).eq(network_id));
+        }
+
+        if let Some(from) = self.from {
+            query = query.filter(Expr::col((M::default(), Alias::new("created_at"))).gte(from));
+        }
+
+        if let Some(to) = self.to {
+            query = query.filter(
+                Expr::col((M::default(), Alias::new("created_at"))).lt(to + Duration::days(1)),
+            );
+        }
+
+        query
+    }
+}
+
+/// Size of the chart buckets
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartGranularity {
+    Day,
+    #[default]
+    Week,
+    Month,
+}
+
+impl ChartGranularity {
+    /// The `date_trunc` field of the bucket
+    pub fn unit(&self) -> &'static str {
+        match self {
+            ChartGranularity::Day => "day",
+            ChartGranularity::Week => "week",
+            ChartGranularity::Month => "month",
+        }
+    }
+
+    /// Start of the bucket containing the date, weeks start on Monday
+    fn truncate(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            ChartGranularity::Day => date,
+            ChartGranularity::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            ChartGranularity::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// Moves the bucket start by the given number of buckets
+    fn shift(&self, date: NaiveDate, count: i64) -> NaiveDate {
+        match self {
+            ChartGranularity::Day => date + Duration::days(count),
+            ChartGranularity::Week => date + Duration::weeks(count),
+            ChartGranularity::Month => {
+                let months = Months::new(count.unsigned_abs() as u32);
+
+                if count >= 0 {
+                    date.checked_add_months(months).unwrap_or(NaiveDate::MAX)
+                } else {
+                    date.checked_sub_months(months).unwrap_or(NaiveDate::MIN)
+                }
+            }
+        }
+    }
+
+    fn label(&self, date: NaiveDate) -> String {
+        match self {
+            ChartGranularity::Day => date.format("%Y-%m-%d").to_string(),
+            ChartGranularity::Week => {
+                let iso_week = date.iso_week();
+                format!("{}:{}", iso_week.year(), iso_week.week())
+            }
+            ChartGranularity::Month => date.format("%Y-%m").to_string(),
+        }
+    }
+
+    /// Returns bucket starts of the range, the last `CHART_LENGTH` buckets up to `to` by default
+    pub fn buckets(&self, from: Option<NaiveDate>, to: Option<NaiveDate>) -> Vec<NaiveDate> {
+        let last = self.truncate(to.unwrap_or_else(|| Utc::now().date_naive()));
+        let mut bucket = from.map_or_else(
+            || self.shift(last, 1 - CHART_LENGTH as i64),
+            |from| self.truncate(from),
+        );
+
+        let mut buckets = vec![];
+        while bucket <= last && buckets.len() <= MAX_CHART_LENGTH {
+            buckets.push(bucket);
+            bucket = self.shift(bucket, 1);
+        }
+
+        buckets
+    }
+}
+
 /// The GraphQl Query segment
 #[derive(Default)]
 pub struct StatisticsQuery {}
@@ -54,107 +226,249 @@ impl StatisticsQuery {
     #[instrument(level = "debug", skip(self, ctx))]
     pub async fn get_dashboard(&self, ctx: &Context<'_>) -> Result<Dashboard> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
-        let (year, week) = get_current_week();
+        let dashboard = get_dashboard(db, &StatisticsFilter::default()).await?;
 
-        let (staked_by_reporters, total_reporters_count) = get_reporter_dashboard(db).await?;
+        Ok(dashboard)
+    }
 
-        let (total_addresses_count, new_weekly_address_count, last_added_addresses) =
-            get_address_dashboard(db, year, week).await?;
+    /// Get a chart statistics
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_charts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network and date range of the charts")] filter: Option<StatisticsFilter>,
+        #[graphql(desc = "Size of the chart buckets, weekly by default")] granularity: Option<
+            ChartGranularity,
+        >,
+    ) -> Result<Charts> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let filter = filter.unwrap_or_default();
+        let granularity = granularity.unwrap_or_default();
 
-        let (total_asset_count, new_weekly_asset_count, last_added_assets) =
-            get_asset_dashboard(db, year, week).await?;
+        filter.validate(granularity)?;
 
-        let (total_case_count, new_weekly_case_count, top_cases_by_address, top_cases_by_asset) =
-            get_case_dashboard(db, year, week).await?;
+        let charts = get_charts(db, &filter, granularity).await?;
 
-        let dashboard = Dashboard {
-            staked_by_reporters,
-            total_reporters_count,
+        Ok(charts)
+    }
 
-            total_case_count,
-            new_weekly_case_count,
-            top_cases_by_address,
-            top_cases_by_asset,
+    /// Get a breakdown of addresses and assets by network, category and risk score
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_breakdown(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network and date range of the breakdown")] filter: Option<
+            StatisticsFilter,
+        >,
+        #[graphql(desc = "Category of the entities")] category: Option<Category>,
+        #[graphql(desc = "Minimal risk score of the entities")] min_risk: Option<i16>,
+    ) -> Result<Breakdown> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let filter = filter.unwrap_or_default();
 
-            total_addresses_count,
-            new_weekly_address_count,
-            last_added_addresses,
+        filter.validate_range()?;
 
-            total_asset_count,
-            new_weekly_asset_count,
-            last_added_assets,
-        };
+        let breakdown = get_breakdown(db, &filter, category, min_risk).await?;
 
-        Ok(dashboard)
+        Ok(breakdown)
     }
+}
 
-    /// Get a chart statistics
-    #[instrument(level = "debug", skip(self, ctx))]
-    pub async fn get_charts(&self, ctx: &Context<'_>) -> Result<Charts> {
-        let db = ctx.data_unchecked::<DatabaseConnection>();
-        let weeks = get_past_weeks();
+/// Collects the dashboard statistics
+pub async fn get_dashboard(
+    db: &DatabaseConnection,
+    filter: &StatisticsFilter,
+) -> Result<Dashboard, DbErr> {
+    let (year, week) = get_current_week();
 
-        let mut labels = vec![];
-        let mut addresses = vec![];
-        let mut assets = vec![];
-        let mut cases = vec![];
+    let (staked_by_reporters, total_reporters_count) = get_reporter_dashboard(db, filter).await?;
 
-        for (year, week) in weeks {
-            let label = format!("{}:{}", year, week);
-            labels.push(label);
+    let (total_addresses_count, new_weekly_address_count, last_added_addresses) =
+        get_address_dashboard(db, filter, year, week).await?;
 
-            let addresses_count =
-                count_rows_per_week(db, address::Entity::find(), year, week).await?;
-            addresses.push(addresses_count);
+    let (total_asset_count, new_weekly_asset_count, last_added_assets) =
+        get_asset_dashboard(db, filter, year, week).await?;
 
-            let assets_count = count_rows_per_week(db, asset::Entity::find(), year, week).await?;
-            assets.push(assets_count);
+    let (total_case_count, new_weekly_case_count, top_cases_by_address, top_cases_by_asset) =
+        get_case_dashboard(db, filter, year, week).await?;
 
-            let cases_count = count_rows_per_week(db, case::Entity::find(), year, week).await?;
-            cases.push(cases_count);
-        }
+    Ok(Dashboard {
+        staked_by_reporters,
+        total_reporters_count,
 
-        let charts = Charts {
-            labels,
-            addresses,
-            assets,
-            cases,
-        };
+        total_case_count,
+        new_weekly_case_count,
+        top_cases_by_address,
+        top_cases_by_asset,
 
-        Ok(charts)
-    }
+        total_addresses_count,
+        new_weekly_address_count,
+        last_added_addresses,
+
+        total_asset_count,
+        new_weekly_asset_count,
+        last_added_assets,
+    })
 }
 
-fn get_current_week() -> (i32, u32) {
-    let now = Utc::now();
-    let iso_week = now.iso_week();
-    (iso_week.year(), iso_week.week())
+/// Collects the counts of new entities per bucket
+pub async fn get_charts(
+    db: &DatabaseConnection,
+    filter: &StatisticsFilter,
+    granularity: ChartGranularity,
+) -> Result<Charts, DbErr> {
+    let buckets = granularity.buckets(filter.from, filter.to);
+
+    // Counts cover whole buckets, even if the range starts or ends inside of them
+    let filter = StatisticsFilter {
+        network_id: filter.network_id.clone(),
+        from: buckets.first().copied(),
+        to: buckets
+            .last()
+            .map(|last| granularity.shift(*last, 1) - Duration::days(1)),
+    };
+
+    let addresses = count_rows_per_bucket(
+        db,
+        filter.apply(address::Entity::find()),
+        granularity.unit(),
+        &buckets,
+    )
+    .await?;
+    let assets = count_rows_per_bucket(
+        db,
+        filter.apply(asset::Entity::find()),
+        granularity.unit(),
+        &buckets,
+    )
+    .await?;
+    let cases = count_rows_per_bucket(
+        db,
+        filter.apply(case::Entity::find()),
+        granularity.unit(),
+        &buckets,
+    )
+    .await?;
+
+    Ok(Charts {
+        labels: buckets
+            .iter()
+            .map(|bucket| granularity.label(*bucket))
+            .collect(),
+        addresses,
+        assets,
+        cases,
+    })
 }
 
-fn get_past_weeks() -> Vec<(i32, u32)> {
-    let mut weeks = Vec::new();
-    let mut current_date = Utc::now();
+/// Collects the entity counts by network, category and risk score and the reporter counts by status
+pub async fn get_breakdown(
+    db: &DatabaseConnection,
+    filter: &StatisticsFilter,
+    category: Option<Category>,
+    min_risk: Option<i16>,
+) -> Result<Breakdown, DbErr> {
+    let addresses = get_risk_breakdown(
+        db,
+        filter.apply(address::Entity::find()),
+        category,
+        min_risk,
+    )
+    .await?;
+    let assets =
+        get_risk_breakdown(db, filter.apply(asset::Entity::find()), category, min_risk).await?;
+
+    let reporters = filter
+        .apply(reporter::Entity::find())
+        .select_only()
+        .column(reporter::Column::NetworkId)
+        .column(reporter::Column::Status)
+        .column_as(Expr::cust("COUNT(*)"), "count")
+        .group_by(reporter::Column::NetworkId)
+        .group_by(reporter::Column::Status)
+        .order_by_asc(reporter::Column::NetworkId)
+        .order_by_asc(reporter::Column::Status)
+        .into_tuple::<(String, ReporterStatus, i64)>()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|(network_id, status, count)| ReporterActivity {
+            network_id,
+            status,
+            count: count as u64,
+        })
+        .collect();
+
+    Ok(Breakdown {
+        addresses,
+        assets,
+        reporters,
+    })
+}
 
-    // Adjust to the start of the current week (assuming weeks start on Monday)
-    if current_date.weekday() != Weekday::Mon {
-        current_date = current_date
-            - chrono::Duration::days(current_date.weekday().num_days_from_monday() as i64);
+async fn get_risk_breakdown<M: EntityTrait>(
+    db: &DatabaseConnection,
+    mut query: Select<M>,
+    category: Option<Category>,
+    min_risk: Option<i16>,
+) -> Result<Vec<RiskBreakdown>, DbErr> {
+    let network_id = Expr::col((M::default(), Alias::new("network_id")));
+    // Enum values are compared and returned as text
+    let category_text =
+        Expr::col((M::default(), Alias::new("category"))).cast_as(Alias::new("text"));
+    let risk = Expr::col((M::default(), Alias::new("risk")));
+
+    if let Some(category) = category {
+        query = query.filter(Expr::expr(category_text.clone()).eq(category.to_value()));
     }
 
-    for _ in 0..CHART_LENGTH {
-        let iso_week = current_date.iso_week();
-        weeks.push((iso_week.year(), iso_week.week()));
-
-        current_date -= chrono::Duration::weeks(1);
+    if let Some(min_risk) = min_risk {
+        query = query.filter(risk.clone().gte(min_risk));
     }
 
-    weeks.reverse();
+    let rows: Vec<(String, Category, i16, i64, i64)> = query
+        .select_only()
+        .column_as(network_id.clone(), "network_id")
+        .column_as(category_text.clone(), "category")
+        .column_as(risk.clone(), "risk")
+        .column_as(Expr::cust("COUNT(*)"), "count")
+        .column_as(Expr::cust("COUNT(DISTINCT reporter_id)"), "reporter_count")
+        .group_by(network_id.clone())
+        .group_by(category_text.clone())
+        .group_by(risk.clone())
+        .order_by_asc(network_id)
+        .order_by_asc(category_text)
+        .order_by_desc(risk)
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(network_id, category, risk, count, reporter_count)| RiskBreakdown {
+                network_id,
+                category,
+                risk,
+                count: count as u64,
+                reporter_count: reporter_count as u64,
+            },
+        )
+        .collect())
+}
 
-    weeks
+fn get_current_week() -> (i32, u32) {
+    let now = Utc::now();
+    let iso_week = now.iso_week();
+    (iso_week.year(), iso_week.week())
 }
 
-async fn get_reporter_dashboard(db: &DatabaseConnection) -> Result<(String, u64)> {
-    let stakes: Vec<String> = reporter::Entity::find()
+async fn get_reporter_dashboard(
+    db: &DatabaseConnection,
+    filter: &StatisticsFilter,
+) -> Result<(String, u64), DbErr> {
+    let stakes: Vec<String> = filter
+        .apply(reporter::Entity::find())
         .select_only()
         .column(reporter::Column::Stake)
         .into_tuple()
@@ -166,8 +480,9 @@ async fn get_reporter_dashboard(db: &DatabaseConnection) -> Result<(String, u64)
     let total_reporters_count = stakes.len() as u64;
     let staked_by_reporters = stakes
         .iter()
-        .map(|s: &String| U256::from_dec_str(s).unwrap())
-        .fold(U256::zero(), |acc, x| acc + x)
+        .map(|s: &String| U256::from_dec_str(s))
+        .try_fold(U256::zero(), |acc, x| x.map(|x| acc + x))
+        .map_err(|e| DbErr::Custom(format!("Invalid reporter stake: {e:?}")))?
         .to_string();
 
     Ok((staked_by_reporters, total_reporters_count))
@@ -175,10 +490,11 @@ async fn get_reporter_dashboard(db: &DatabaseConnection) -> Result<(String, u64)
 
 async fn get_case_dashboard(
     db: &DatabaseConnection,
+    filter: &StatisticsFilter,
     year: i32,
     week: u32,
-) -> Result<(u64, u64, Vec<case::Model>, Vec<case::Model>)> {
-    let query = case::Entity::find();
+) -> Result<(u64, u64, Vec<case::Model>, Vec<case::Model>), DbErr> {
+    let query = filter.apply(case::Entity::find());
 
     let total_case_count = query.clone().count(db).await?;
     let new_weekly_case_count = count_rows_per_week(db, query.clone(), year, week).await?;
@@ -204,10 +520,11 @@ async fn get_case_dashboard(
 
 async fn get_address_dashboard(
     db: &DatabaseConnection,
+    filter: &StatisticsFilter,
     year: i32,
     week: u32,
-) -> Result<(u64, u64, Vec<address::Model>)> {
-    let query = address::Entity::find();
+) -> Result<(u64, u64, Vec<address::Model>), DbErr> {
+    let query = filter.apply(address::Entity::find());
 
     let total_addresses_count = query.clone().count(db).await?;
     let new_weekly_address_count = count_rows_per_week(db, query.clone(), year, week).await?;
@@ -226,10 +543,11 @@ async fn get_address_dashboard(
 
 async fn get_asset_dashboard(
     db: &DatabaseConnection,
+    filter: &StatisticsFilter,
     year: i32,
     week: u32,
-) -> Result<(u64, u64, Vec<asset::Model>)> {
-    let query = asset::Entity::find();
+) -> Result<(u64, u64, Vec<asset::Model>), DbErr> {
+    let query = filter.apply(asset::Entity::find());
 
     let total_asset_count = query.clone().count(db).await?;
     let new_weekly_asset_count = count_rows_per_week(db, query.clone(), year, week).await?;
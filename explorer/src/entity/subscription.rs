@@ -0,0 +1,137 @@
+use {
+    async_graphql::{Context, Enum, InputObject, Subscription},
+    serde::Serialize,
+    tokio::sync::broadcast,
+    tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt},
+};
+
+use crate::entity::{address, asset, types::Category};
+
+/// The number of updates kept for slow subscribers before they start lagging
+const ENTITY_UPDATES_CAPACITY: usize = 1024;
+
+/// Sender of entity updates applied by the event handler
+pub type EntityUpdates = broadcast::Sender<EntityUpdate>;
+
+pub fn create_entity_updates() -> EntityUpdates {
+    broadcast::channel(ENTITY_UPDATES_CAPACITY).0
+}
+
+/// Kind of the applied entity change
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateKind {
+    Created,
+    Updated,
+}
+
+/// Entity change that is streamed to subscribers
+#[derive(Clone, Debug)]
+pub enum EntityUpdate {
+    Address(UpdateKind, address::Model),
+    Asset(UpdateKind, asset::Model),
+}
+
+/// Conditions to filter streamed entities by
+#[derive(Clone, Default, Eq, PartialEq, InputObject, Debug)]
+pub struct SubscriptionFilter {
+    pub network_id: Option<String>,
+    pub category: Option<Category>,
+    pub min_risk: Option<i16>,
+}
+
+impl SubscriptionFilter {
+    fn matches(&self, network_id: &str, category: Category, risk: i16) -> bool {
+        self.network_id.as_ref().map_or(true, |id| id == network_id)
+            && self.category.map_or(true, |c| c == category)
+            && self.min_risk.map_or(true, |min_risk| risk >= min_risk)
+    }
+}
+
+/// The GraphQl Subscription root
+#[derive(Default)]
+pub struct EntitySubscription;
+
+/// Subscriptions for entity changes
+#[Subscription]
+impl EntitySubscription {
+    /// Stream of created addresses
+    async fn address_created(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address stream filter")] filter: Option<SubscriptionFilter>,
+    ) -> impl Stream<Item = address::Model> {
+        address_updates(ctx, UpdateKind::Created, filter.unwrap_or_default())
+    }
+
+    /// Stream of updated addresses
+    async fn address_updated(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address stream filter")] filter: Option<SubscriptionFilter>,
+    ) -> impl Stream<Item = address::Model> {
+        address_updates(ctx, UpdateKind::Updated, filter.unwrap_or_default())
+    }
+
+    /// Stream of created assets
+    async fn asset_created(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset stream filter")] filter: Option<SubscriptionFilter>,
+    ) -> impl Stream<Item = asset::Model> {
+        asset_updates(ctx, UpdateKind::Created, filter.unwrap_or_default())
+    }
+
+    /// Stream of updated assets
+    async fn asset_updated(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset stream filter")] filter: Option<SubscriptionFilter>,
+    ) -> impl Stream<Item = asset::Model> {
+        asset_updates(ctx, UpdateKind::Updated, filter.unwrap_or_default())
+    }
+}
+
+fn subscribe(ctx: &Context<'_>) -> impl Stream<Item = EntityUpdate> {
+    BroadcastStream::new(ctx.data_unchecked::<EntityUpdates>().subscribe()).filter_map(|update| {
+        match update {
+            Ok(update) => Some(update),
+            Err(error) => {
+                tracing::warn!(%error, "Subscriber is lagging behind entity updates");
+                None
+            }
+        }
+    })
+}
+
+fn address_updates(
+    ctx: &Context<'_>,
+    kind: UpdateKind,
+    filter: SubscriptionFilter,
+) -> impl Stream<Item = address::Model> {
+    subscribe(ctx).filter_map(move |update| match update {
+        EntityUpdate::Address(update_kind, address)
+            if update_kind == kind
+                && filter.matches(&address.network_id, address.category, address.risk) =>
+        {
+            Some(address)
+        }
+        _ => None,
+    })
+}
+
+fn asset_updates(
+    ctx: &Context<'_>,
+    kind: UpdateKind,
+    filter: SubscriptionFilter,
+) -> impl Stream<Item = asset::Model> {
+    subscribe(ctx).filter_map(move |update| match update {
+        EntityUpdate::Asset(update_kind, asset)
+            if update_kind == kind
+                && filter.matches(&asset.network_id, asset.category, asset.risk) =>
+        {
+            Some(asset)
+        }
+        _ => None,
+    })
+}
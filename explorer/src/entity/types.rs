@@ -1,6 +1,7 @@
 use {
     anyhow::{anyhow, Error, Result},
     async_graphql::Enum,
+    clap::ValueEnum,
     hapi_core::{
         client::entities::{
             case::CaseStatus as CaseStatusPayload,
@@ -12,7 +13,7 @@ use {
         HapiCoreNetwork,
     },
     sea_orm::entity::prelude::*,
-    serde::Serialize,
+    serde::{Deserialize, Serialize},
     std::{fmt, str::FromStr},
 };
 
@@ -63,8 +64,21 @@ impl From<HapiCoreNetwork> for NetworkBackend {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Enum,
+    Serialize,
+    Deserialize,
+    ValueEnum,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "category")]
+#[value(rename_all = "verbatim")]
 pub enum Category {
     #[sea_orm(string_value = "none")]
     None,
@@ -170,8 +184,21 @@ impl fmt::Display for Category {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Enum,
+    Serialize,
+    Deserialize,
+    ValueEnum,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "reporter_role")]
+#[value(rename_all = "verbatim")]
 pub enum ReporterRole {
     #[sea_orm(string_value = "authority")]
     Authority,
@@ -205,8 +232,21 @@ impl fmt::Display for ReporterRole {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Enum,
+    Serialize,
+    Deserialize,
+    ValueEnum,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "reporter_status")]
+#[value(rename_all = "verbatim")]
 pub enum ReporterStatus {
     #[sea_orm(string_value = "active")]
     Active,
@@ -236,8 +276,21 @@ impl fmt::Display for ReporterStatus {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Enum,
+    Serialize,
+    Deserialize,
+    ValueEnum,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "case_status")]
+#[value(rename_all = "verbatim")]
 pub enum CaseStatus {
     #[sea_orm(string_value = "closed")]
     Closed,
@@ -262,3 +315,30 @@ impl fmt::Display for CaseStatus {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "event_entity")]
+pub enum EventEntity {
+    #[sea_orm(string_value = "address")]
+    Address,
+    #[sea_orm(string_value = "asset")]
+    Asset,
+    #[sea_orm(string_value = "case")]
+    Case,
+    #[sea_orm(string_value = "reporter")]
+    Reporter,
+    #[sea_orm(string_value = "network")]
+    Network,
+}
+
+impl fmt::Display for EventEntity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventEntity::Address => write!(f, "address"),
+            EventEntity::Asset => write!(f, "asset"),
+            EventEntity::Case => write!(f, "case"),
+            EventEntity::Reporter => write!(f, "reporter"),
+            EventEntity::Network => write!(f, "network"),
+        }
+    }
+}
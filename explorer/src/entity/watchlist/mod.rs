@@ -0,0 +1,7 @@
+pub(super) mod model;
+pub(super) mod resolver;
+pub(super) mod webhook;
+
+pub use model::{ActiveModel, Column, Entity, Model};
+pub(crate) use resolver::{WatchlistMutation, WatchlistQuery};
+pub use webhook::{webhook_host, AlertAllowedHosts};
@@ -0,0 +1,27 @@
+use {async_graphql::SimpleObject, sea_orm::entity::prelude::*, serde::Serialize};
+
+/// Address watched by the token owner, alerts about its changes are sent to the webhook url
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject, Serialize)]
+#[graphql(name = "Watchlist")]
+#[sea_orm(table_name = "watchlist")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// Id of the token the watchlist was created with
+    #[graphql(skip)]
+    pub owner: String,
+    pub network_id: String,
+    pub address: String,
+    pub webhook_url: String,
+    /// Secret the alert payloads are signed with
+    #[graphql(skip)]
+    #[serde(skip)]
+    pub secret: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
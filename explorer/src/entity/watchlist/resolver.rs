@@ -0,0 +1,198 @@
+use {
+    async_graphql::{Context, InputObject, Object, Result},
+    reqwest::Url,
+    sea_orm::{
+        ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, NotSet, QueryFilter,
+        QueryOrder, Set,
+    },
+    tracing::instrument,
+    uuid::Uuid,
+};
+
+use super::{
+    model::{ActiveModel, Column, Entity, Model},
+    webhook::{webhook_host, AlertAllowedHosts},
+};
+
+use crate::{entity::network, error::AppError, server::TokenClaims};
+
+/// Address to watch along with the webhook to alert
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct CreateWatchlistInput {
+    pub network_id: String,
+    pub address: String,
+    pub webhook_url: String,
+    /// Secret to sign the alert payloads with
+    pub secret: String,
+}
+
+/// Webhook settings of the watchlist to change
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+pub struct UpdateWatchlistInput {
+    pub webhook_url: Option<String>,
+    pub secret: Option<String>,
+}
+
+/// The GraphQl Query segment
+#[derive(Default)]
+pub struct WatchlistQuery {}
+
+/// Queries for the `Watchlist` model
+#[Object]
+impl WatchlistQuery {
+    /// Get the watchlists of the token owner
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_watchlists(&self, ctx: &Context<'_>) -> Result<Vec<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let owner = get_owner(ctx)?;
+
+        let watchlists = Entity::find()
+            .filter(Column::Owner.eq(owner))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await?;
+
+        Ok(watchlists)
+    }
+}
+
+/// The GraphQl Mutation segment
+#[derive(Default)]
+pub struct WatchlistMutation {}
+
+/// Mutations for the `Watchlist` model
+#[Object]
+impl WatchlistMutation {
+    /// Watch an address and alert the webhook about its changes
+    #[instrument(
+        level = "debug",
+        skip(self, ctx, input),
+        fields(network_id = %input.network_id, address = %input.address)
+    )]
+    pub async fn create_watchlist(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Watchlist input parameters")] input: CreateWatchlistInput,
+    ) -> Result<Model> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let owner = get_owner(ctx)?;
+
+        let network = network::Entity::find_by_id(input.network_id.clone())
+            .one(db)
+            .await?
+            .ok_or("This network does not exist")?;
+        let address = network.backend.normalize_address(&input.address)?;
+
+        validate_webhook(ctx, &input.webhook_url, &input.secret)?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let watchlist = ActiveModel {
+            id: Set(Uuid::new_v4()),
+            owner: Set(owner),
+            network_id: Set(network.id),
+            address: Set(address),
+            webhook_url: Set(input.webhook_url),
+            secret: Set(input.secret),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(db)
+        .await?;
+
+        Ok(watchlist)
+    }
+
+    /// Change the webhook of the watchlist
+    #[instrument(level = "debug", skip(self, ctx, input))]
+    pub async fn update_watchlist(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Watchlist id")] id: Uuid,
+        #[graphql(desc = "Watchlist update parameters")] input: UpdateWatchlistInput,
+    ) -> Result<Model> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let old = find_owned(ctx, id).await?;
+
+        validate_webhook(
+            ctx,
+            input.webhook_url.as_deref().unwrap_or(&old.webhook_url),
+            input.secret.as_deref().unwrap_or(&old.secret),
+        )?;
+
+        let watchlist = ActiveModel {
+            id: Set(old.id),
+            webhook_url: input.webhook_url.map_or(NotSet, Set),
+            secret: input.secret.map_or(NotSet, Set),
+            updated_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .update(db)
+        .await?;
+
+        Ok(watchlist)
+    }
+
+    /// Stop watching the address
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn delete_watchlist(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Watchlist id")] id: Uuid,
+    ) -> Result<bool> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let watchlist = find_owned(ctx, id).await?;
+
+        Entity::delete_by_id(watchlist.id).exec(db).await?;
+
+        Ok(true)
+    }
+}
+
+/// Id of the token the request is authenticated with. There are no user tokens yet,
+/// so watchlists are owned by the indexer the explorer token is issued to
+fn get_owner(ctx: &Context<'_>) -> Result<String> {
+    if let Some(claims) = ctx.data_opt::<TokenClaims>() {
+        return Ok(claims.id.clone());
+    }
+
+    // The token was provided, but rejected by the authentication
+    match ctx.data_opt::<AppError>() {
+        Some(error) => Err(error.description.as_str().into()),
+        None => Err("You are not authenticated, please provide token".into()),
+    }
+}
+
+/// Watchlist of the token owner, watchlists of other owners are reported as missing
+async fn find_owned(ctx: &Context<'_>, id: Uuid) -> Result<Model> {
+    let db = ctx.data_unchecked::<DatabaseConnection>();
+    let owner = get_owner(ctx)?;
+
+    let watchlist = Entity::find_by_id(id)
+        .filter(Column::Owner.eq(owner))
+        .one(db)
+        .await?
+        .ok_or("This watchlist does not exist")?;
+
+    Ok(watchlist)
+}
+
+fn validate_webhook(ctx: &Context<'_>, webhook_url: &str, secret: &str) -> Result<()> {
+    let url = Url::parse(webhook_url).map_err(|e| format!("Invalid webhook url: {e}"))?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("Webhook url must use http or https".into());
+    }
+
+    let host = webhook_host(&url).ok_or("Webhook url must have a host")?;
+
+    // Names resolved to local addresses are refused when the alert is sent
+    if !ctx.data_unchecked::<AlertAllowedHosts>().allows_host(host) {
+        return Err("Webhook host must not be a local or private address".into());
+    }
+
+    if secret.is_empty() {
+        return Err("Webhook secret must not be empty".into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use {
+    reqwest::Url,
+    std::net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+/// Loopback, link-local and private webhook hosts allowed by the configuration
+#[derive(Clone, Debug, Default)]
+pub struct AlertAllowedHosts(pub Vec<String>);
+
+impl AlertAllowedHosts {
+    /// Whether alerts may be posted to the host, local hosts must be allowed by the configuration
+    pub fn allows_host(&self, host: &str) -> bool {
+        !is_local_host(host) || self.contains(host)
+    }
+
+    /// Whether alerts may be posted to the address the host is resolved to
+    pub fn allows_ip(&self, host: &str, ip: &IpAddr) -> bool {
+        !is_local_ip(ip) || self.contains(host)
+    }
+
+    fn contains(&self, host: &str) -> bool {
+        self.0.iter().any(|allowed| allowed == host)
+    }
+}
+
+/// Host of the webhook url, IPv6 addresses are without the brackets
+pub fn webhook_host(url: &Url) -> Option<&str> {
+    url.host_str()
+        .map(|host| host.trim_start_matches('[').trim_end_matches(']'))
+}
+
+/// Whether the host is `localhost` or a loopback, link-local, private or unspecified IP address
+fn is_local_host(host: &str) -> bool {
+    match host.parse::<IpAddr>() {
+        Ok(ip) => is_local_ip(&ip),
+        Err(_) => {
+            let domain = host.trim_end_matches('.').to_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+    }
+}
+
+fn is_local_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_local_ipv4(ip),
+        IpAddr::V6(ip) => is_local_ipv6(ip),
+    }
+}
+
+fn is_local_ipv4(ip: &Ipv4Addr) -> bool {
+    ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_private()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+}
+
+fn is_local_ipv6(ip: &Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+
+    ip.is_loopback()
+        || ip.is_unspecified()
+        // Link-local fe80::/10 and unique local fc00::/7 addresses
+        || first_segment & 0xffc0 == 0xfe80
+        || first_segment & 0xfe00 == 0xfc00
+        || ip.to_ipv4_mapped().map_or(false, |ip| is_local_ipv4(&ip))
+}
@@ -2,11 +2,22 @@ use {
     anyhow::Result,
     clap::{command, Parser, Subcommand},
     hapi_explorer::{
-        application::Application, configuration::get_configuration, entity::types::NetworkBackend,
+        application::Application,
+        configuration::get_configuration,
+        entity::{
+            address::{self, AddressFilter},
+            asset::{self, AssetFilter},
+            case::{self, CaseFilter},
+            reporter::{self, ReporterFilter},
+            types::NetworkBackend,
+        },
         observability::setup_tracing,
+        service::ExportFormat,
     },
     sea_orm_cli::MigrateSubcommands,
+    std::path::PathBuf,
     tokio::net::TcpListener,
+    uuid::Uuid,
 };
 
 #[derive(Subcommand, PartialEq, Eq, Debug, Clone)]
@@ -47,6 +58,46 @@ pub enum NetworkSubcommands {
     },
 }
 
+#[derive(Subcommand, PartialEq, Eq, Debug, Clone)]
+pub enum IndexerSubcommands {
+    #[command(about = "List indexers")]
+    List,
+    #[command(about = "Revoke all tokens of the indexer")]
+    Revoke {
+        #[arg(long, help = "Indexer id")]
+        id: Uuid,
+    },
+    #[command(about = "Revoke previous tokens of the indexer and issue a new one")]
+    Rotate {
+        #[arg(long, help = "Indexer id")]
+        id: Uuid,
+    },
+}
+
+#[derive(Subcommand, PartialEq, Eq, Debug, Clone)]
+pub enum ExportSubcommands {
+    #[command(about = "Export addresses")]
+    Addresses {
+        #[command(flatten)]
+        filter: AddressFilter,
+    },
+    #[command(about = "Export assets")]
+    Assets {
+        #[command(flatten)]
+        filter: AssetFilter,
+    },
+    #[command(about = "Export cases")]
+    Cases {
+        #[command(flatten)]
+        filter: CaseFilter,
+    },
+    #[command(about = "Export reporters")]
+    Reporters {
+        #[command(flatten)]
+        filter: ReporterFilter,
+    },
+}
+
 #[derive(Parser)]
 enum ExplorerCli {
     #[command(about = "Run explorer server")]
@@ -61,12 +112,28 @@ enum ExplorerCli {
         #[command(subcommand)]
         subcommand: Option<MigrateSubcommands>,
     },
+    #[command(about = "Run commands related to indexers")]
+    Indexer {
+        #[command(subcommand)]
+        subcommand: IndexerSubcommands,
+    },
     CreateIndexer {
         #[arg(long, help = "Network backend type")]
         backend: NetworkBackend,
         #[arg(long, default_value = None, help = "Network chain id (optional)")]
         chain_id: Option<String>,
     },
+    #[command(about = "Export explorer data to a file")]
+    Export {
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv, help = "Export file format")]
+        format: ExportFormat,
+
+        #[arg(long, help = "Output file path")]
+        output: PathBuf,
+
+        #[command(subcommand)]
+        subcommand: ExportSubcommands,
+    },
 }
 
 #[tokio::main]
@@ -109,11 +176,34 @@ async fn main() -> Result<()> {
                 authority,
             } => app.update_network(id, name, authority, stake_token).await,
         },
+        ExplorerCli::Indexer { subcommand } => match subcommand {
+            IndexerSubcommands::List => app.list_indexers().await.map(|_| ()),
+            IndexerSubcommands::Revoke { id } => app.revoke_indexer(id).await,
+            IndexerSubcommands::Rotate { id } => app.rotate_indexer_token(id).await.map(|_| ()),
+        },
         ExplorerCli::CreateIndexer { backend, chain_id } => {
             app.create_indexer(backend, chain_id).await?;
 
             Ok(())
         }
+        ExplorerCli::Export {
+            format,
+            output,
+            subcommand,
+        } => match subcommand {
+            ExportSubcommands::Addresses { filter } => {
+                app.export::<address::Entity>(filter, format, output).await
+            }
+            ExportSubcommands::Assets { filter } => {
+                app.export::<asset::Entity>(filter, format, output).await
+            }
+            ExportSubcommands::Cases { filter } => {
+                app.export::<case::Entity>(filter, format, output).await
+            }
+            ExportSubcommands::Reporters { filter } => {
+                app.export::<reporter::Entity>(filter, format, output).await
+            }
+        },
     }?;
 
     app.shutdown().await
@@ -0,0 +1,66 @@
+use super::Network;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Network::Table)
+                    .add_column(ColumnDef::new(NetworkConfiguration::StakeUnlockDuration).string())
+                    .add_column(ColumnDef::new(NetworkConfiguration::ValidatorStake).string())
+                    .add_column(ColumnDef::new(NetworkConfiguration::TracerStake).string())
+                    .add_column(ColumnDef::new(NetworkConfiguration::PublisherStake).string())
+                    .add_column(ColumnDef::new(NetworkConfiguration::AuthorityStake).string())
+                    .add_column(ColumnDef::new(NetworkConfiguration::RewardToken).string())
+                    .add_column(
+                        ColumnDef::new(NetworkConfiguration::AddressConfirmationReward).string(),
+                    )
+                    .add_column(ColumnDef::new(NetworkConfiguration::AddressTracerReward).string())
+                    .add_column(
+                        ColumnDef::new(NetworkConfiguration::AssetConfirmationReward).string(),
+                    )
+                    .add_column(ColumnDef::new(NetworkConfiguration::AssetTracerReward).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Network::Table)
+                    .drop_column(NetworkConfiguration::StakeUnlockDuration)
+                    .drop_column(NetworkConfiguration::ValidatorStake)
+                    .drop_column(NetworkConfiguration::TracerStake)
+                    .drop_column(NetworkConfiguration::PublisherStake)
+                    .drop_column(NetworkConfiguration::AuthorityStake)
+                    .drop_column(NetworkConfiguration::RewardToken)
+                    .drop_column(NetworkConfiguration::AddressConfirmationReward)
+                    .drop_column(NetworkConfiguration::AddressTracerReward)
+                    .drop_column(NetworkConfiguration::AssetConfirmationReward)
+                    .drop_column(NetworkConfiguration::AssetTracerReward)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum NetworkConfiguration {
+    StakeUnlockDuration,
+    ValidatorStake,
+    TracerStake,
+    PublisherStake,
+    AuthorityStake,
+    RewardToken,
+    AddressConfirmationReward,
+    AddressTracerReward,
+    AssetConfirmationReward,
+    AssetTracerReward,
+}
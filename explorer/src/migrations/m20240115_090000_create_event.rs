@@ -0,0 +1,58 @@
+use super::Network;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Event::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Event::NetworkId).string().not_null())
+                    .col(ColumnDef::new(Event::TxHash).string().not_null())
+                    .col(ColumnDef::new(Event::TxIndex).big_integer().not_null())
+                    .col(ColumnDef::new(Event::Name).string().not_null())
+                    .col(ColumnDef::new(Event::Timestamp).timestamp().not_null())
+                    .col(ColumnDef::new(Event::CreatedAt).timestamp().not_null())
+                    .primary_key(
+                        Index::create()
+                            .name("event_id")
+                            .col(Event::NetworkId)
+                            .col(Event::TxHash)
+                            .col(Event::TxIndex),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-event_network_id")
+                            .from(Event::Table, Event::NetworkId)
+                            .to(Network::Table, Network::Id)
+                            .on_delete(ForeignKeyAction::NoAction)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Event::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum Event {
+    // Composite key: network_id + tx_hash + tx_index
+    Table,
+    NetworkId,
+    TxHash,
+    TxIndex,
+    Name,
+    Timestamp,
+    CreatedAt,
+}
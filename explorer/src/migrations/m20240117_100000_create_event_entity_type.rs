@@ -0,0 +1,38 @@
+use {
+    sea_orm::{EnumIter, Iterable},
+    sea_orm_migration::{prelude::*, sea_query::extension::postgres::Type},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(EventEntity::Type)
+                    .values(EventEntity::iter().skip(1))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_type(Type::drop().name(EventEntity::Type).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden, EnumIter)]
+pub enum EventEntity {
+    #[iden = "event_entity"]
+    Type,
+    Address,
+    Asset,
+    Case,
+    Reporter,
+    Network,
+}
@@ -0,0 +1,66 @@
+use super::{Event, EventEntity};
+use {sea_orm::Iterable, sea_orm_migration::prelude::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Columns are nullable since events recorded earlier have no snapshot
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Event::Table)
+                    .add_column(
+                        ColumnDef::new(EventHistory::EntityType)
+                            .enumeration(EventEntity::Type, EventEntity::iter().skip(1)),
+                    )
+                    .add_column(ColumnDef::new(EventHistory::EntityId).string())
+                    .add_column(ColumnDef::new(EventHistory::Payload).json_binary())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-event_entity")
+                    .table(Event::Table)
+                    .col(Event::NetworkId)
+                    .col(EventHistory::EntityType)
+                    .col(EventHistory::EntityId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-event_entity")
+                    .table(Event::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Event::Table)
+                    .drop_column(EventHistory::EntityType)
+                    .drop_column(EventHistory::EntityId)
+                    .drop_column(EventHistory::Payload)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventHistory {
+    EntityType,
+    EntityId,
+    Payload,
+}
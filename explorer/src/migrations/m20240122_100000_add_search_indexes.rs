@@ -0,0 +1,92 @@
+use sea_orm_migration::prelude::*;
+
+// Trigram indexes serve the substring and prefix search over text columns
+const TRIGRAM_INDEXES: [(&str, &str); 8] = [
+    ("address", "address"),
+    ("asset", "address"),
+    ("asset", "id"),
+    ("case", "name"),
+    ("case", "url"),
+    ("reporter", "account"),
+    ("reporter", "name"),
+    ("reporter", "url"),
+];
+
+// B-tree indexes serve the exact and prefix search over uuid columns
+const UUID_INDEXES: [(&str, &str); 7] = [
+    ("address", "case_id"),
+    ("address", "reporter_id"),
+    ("asset", "case_id"),
+    ("asset", "reporter_id"),
+    ("case", "id"),
+    ("case", "reporter_id"),
+    ("reporter", "id"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS pg_trgm")
+            .await?;
+
+        for (table, column) in TRIGRAM_INDEXES {
+            db.execute_unprepared(&format!(
+                r#"CREATE INDEX "{}" ON "{table}" USING GIN ("{column}" gin_trgm_ops)"#,
+                trigram_index_name(table, column)
+            ))
+            .await?;
+        }
+
+        for (table, column) in UUID_INDEXES {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(&uuid_index_name(table, column))
+                        .table(Alias::new(table))
+                        .col(Alias::new(column))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let indexes = TRIGRAM_INDEXES
+            .iter()
+            .map(|(table, column)| (table, trigram_index_name(table, column)))
+            .chain(
+                UUID_INDEXES
+                    .iter()
+                    .map(|(table, column)| (table, uuid_index_name(table, column))),
+            );
+
+        for (table, name) in indexes {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(&name)
+                        .table(Alias::new(*table))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // The extension is kept, since other schemas may rely on it
+        Ok(())
+    }
+}
+
+fn trigram_index_name(table: &str, column: &str) -> String {
+    format!("idx-{table}-{column}-trgm")
+}
+
+fn uuid_index_name(table: &str, column: &str) -> String {
+    format!("idx-{table}-{column}")
+}
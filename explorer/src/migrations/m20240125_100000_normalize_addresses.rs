@@ -0,0 +1,90 @@
+use {
+    sea_orm::{ConnectionTrait, Statement},
+    sea_orm_migration::prelude::*,
+};
+
+// Tables with addresses along with the other columns of their primary key
+const ADDRESS_TABLES: [(&str, &str); 2] = [("address", ""), ("asset", r#", x."id""#)];
+
+// Backends with case-insensitive addresses, stored in lowercase
+const LOWERCASE_BACKENDS: &str = "'evm', 'near'";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (table, key) in ADDRESS_TABLES {
+            // Of the rows differing only by case, the canonical one or the latest updated one is kept
+            let duplicates = format!(
+                r#"SELECT ranked.ctid FROM (
+                    SELECT x.ctid, ROW_NUMBER() OVER (
+                        PARTITION BY x."network_id", lower(x."address"){key}
+                        ORDER BY x."address" = lower(x."address") DESC, x."updated_at" DESC
+                    ) AS rank
+                    FROM "{table}" AS x
+                    JOIN "network" AS n ON n."id" = x."network_id"
+                    WHERE n."backend" IN ({LOWERCASE_BACKENDS})
+                ) AS ranked
+                WHERE ranked.rank > 1"#
+            );
+
+            // Dropped reports are logged in full, so they can be restored by hand
+            let rows = db
+                .query_all(Statement::from_string(
+                    manager.get_database_backend(),
+                    format!(
+                        r#"SELECT row_to_json(x)::text AS "row" FROM "{table}" AS x
+                        WHERE x.ctid IN ({duplicates})"#
+                    ),
+                ))
+                .await?;
+
+            for row in rows {
+                let row: String = row.try_get("", "row")?;
+                tracing::warn!(
+                    table,
+                    row,
+                    "Dropping a report that differs only by address case"
+                );
+            }
+
+            db.execute_unprepared(&format!(
+                r#"DELETE FROM "{table}" WHERE ctid IN ({duplicates})"#
+            ))
+            .await?;
+
+            db.execute_unprepared(&format!(
+                r#"UPDATE "{table}" AS x SET "address" = lower(x."address")
+                FROM "network" AS n
+                WHERE n."id" = x."network_id"
+                    AND n."backend" IN ({LOWERCASE_BACKENDS})
+                    AND x."address" <> lower(x."address")"#
+            ))
+            .await?;
+        }
+
+        // Event history is looked up by the canonical address as well
+        db.execute_unprepared(&format!(
+            r#"UPDATE "event" AS e SET
+                "entity_id" = lower(e."payload"->>'address') || substring(e."entity_id" FROM length(e."payload"->>'address') + 1),
+                "payload" = jsonb_set(e."payload", '{{address}}', to_jsonb(lower(e."payload"->>'address')))
+            FROM "network" AS n
+            WHERE n."id" = e."network_id"
+                AND n."backend" IN ({LOWERCASE_BACKENDS})
+                AND e."entity_type" IN ('address', 'asset')
+                AND e."payload"->>'address' <> lower(e."payload"->>'address')"#
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // The original case of the addresses is not preserved, so there is nothing to revert
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use super::Network;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Watchlist::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Watchlist::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Watchlist::Owner).string().not_null())
+                    .col(ColumnDef::new(Watchlist::NetworkId).string().not_null())
+                    .col(ColumnDef::new(Watchlist::Address).string().not_null())
+                    .col(ColumnDef::new(Watchlist::WebhookUrl).string().not_null())
+                    .col(ColumnDef::new(Watchlist::Secret).string().not_null())
+                    .col(ColumnDef::new(Watchlist::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(Watchlist::UpdatedAt).timestamp().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-watchlist_network_id")
+                            .from(Watchlist::Table, Watchlist::NetworkId)
+                            .to(Network::Table, Network::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Watchlists are matched by the address of every applied address and asset event
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-watchlist_address")
+                    .table(Watchlist::Table)
+                    .col(Watchlist::NetworkId)
+                    .col(Watchlist::Address)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-watchlist_owner")
+                    .table(Watchlist::Table)
+                    .col(Watchlist::Owner)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Watchlist::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum Watchlist {
+    Table,
+    Id,
+    Owner,
+    NetworkId,
+    Address,
+    WebhookUrl,
+    Secret,
+    CreatedAt,
+    UpdatedAt,
+}
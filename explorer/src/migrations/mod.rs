@@ -9,13 +9,25 @@ mod m20231127_170630_create_case_status_type;
 mod m20231205_131413_create_indexer;
 mod m20231205_131413_create_network;
 mod m20231211_164133_create_network_backend;
+mod m20231220_101500_add_network_configuration;
+mod m20240110_120000_add_indexer_revocation;
+mod m20240115_090000_create_event;
+mod m20240117_100000_create_event_entity_type;
+mod m20240117_100100_add_event_history;
+mod m20240122_100000_add_search_indexes;
+mod m20240125_100000_normalize_addresses;
+mod m20240129_100000_create_watchlist;
+mod m20240201_100000_add_event_confirmations;
 
 pub(super) use m20231127_162603_create_category_type::Category;
 pub(super) use m20231127_165849_create_reporter_role_type::ReporterRole;
 pub(super) use m20231127_170357_create_reporter_status_type::ReporterStatus;
 pub(super) use m20231127_170630_create_case_status_type::CaseStatus;
+pub(super) use m20231205_131413_create_indexer::Indexer;
 pub(super) use m20231205_131413_create_network::Network;
 pub(super) use m20231211_164133_create_network_backend::NetworkBackend;
+pub(super) use m20240115_090000_create_event::Event;
+pub(super) use m20240117_100000_create_event_entity_type::EventEntity;
 
 pub(super) use m20231127_161317_create_reporter::Reporter;
 pub(super) use m20231127_162130_create_case::Case;
@@ -39,6 +51,15 @@ impl MigratorTrait for Migrator {
             Box::new(m20231127_162130_create_case::Migration),
             Box::new(m20231127_140636_create_address::Migration),
             Box::new(m20231127_160838_create_asset::Migration),
+            Box::new(m20231220_101500_add_network_configuration::Migration),
+            Box::new(m20240110_120000_add_indexer_revocation::Migration),
+            Box::new(m20240115_090000_create_event::Migration),
+            Box::new(m20240117_100000_create_event_entity_type::Migration),
+            Box::new(m20240117_100100_add_event_history::Migration),
+            Box::new(m20240122_100000_add_search_indexes::Migration),
+            Box::new(m20240125_100000_normalize_addresses::Migration),
+            Box::new(m20240129_100000_create_watchlist::Migration),
+            Box::new(m20240201_100000_add_event_confirmations::Migration),
         ]
     }
 }
@@ -21,6 +21,8 @@ const CASE_METRIC: &str = "case";
 const ADDRESS_METRIC: &str = "address";
 const ASSET_METRIC: &str = "asset";
 const NETWORK_METRIC: &str = "network";
+const FORBIDDEN_HEARTBEAT_METRIC: &str = "indexer_forbidden_heartbeats_total";
+const DROPPED_ALERT_METRIC: &str = "watchlist_alerts_dropped_total";
 
 pub(crate) fn setup_metrics() -> PrometheusHandle {
     const EXPONENTIAL_SECONDS: &[f64] = &[
@@ -152,6 +154,21 @@ pub fn update_network_metrics(model: network::Model, op: MetricOp) {
     process_metric_op(NETWORK_METRIC, op, labels);
 }
 
+/// Counts heartbeats sent with a token of another indexer.
+/// Only the verified token id is a label: the requested id is arbitrary and would add unbounded series
+pub fn record_forbidden_heartbeat(token_id: String) {
+    let labels = [("token_id", token_id)];
+
+    metrics::increment_counter!(FORBIDDEN_HEARTBEAT_METRIC, &labels);
+}
+
+/// Counts watchlist alerts that are not delivered, by the reason of the drop
+pub fn record_dropped_alert(reason: &'static str) {
+    let labels = [("reason", reason.to_string())];
+
+    metrics::increment_counter!(DROPPED_ALERT_METRIC, &labels);
+}
+
 fn process_metric_op(metric_name: &'static str, op: MetricOp, labels: Vec<(&'static str, String)>) {
     match op {
         MetricOp::Increment => {
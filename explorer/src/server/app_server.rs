@@ -12,34 +12,56 @@ use {
 
 use super::{
     handlers::{
-        auth_handler, event_handler, graphiql_playground, graphql_handler, health_handler,
-        indexer_handler, indexer_heartbeat_handler, stats_handler,
+        auth_handler, event_handler, export_handler, graphql_handler, graphql_ws_handler,
+        health_handler, indexer_handler, indexer_heartbeat_handler, screening_handler,
+        signature_handler, stats_handler,
     },
     schema::create_graphql_schema,
 };
 
 use crate::{
     application::Application,
+    entity::{address, asset, case, reporter},
     observability::{setup_metrics, track_metrics},
 };
 
 impl Application {
     async fn create_router(&self) -> Result<Router> {
-        let schema = create_graphql_schema(self.state.database_conn.clone())?;
+        let schema = create_graphql_schema(
+            self.state.database_conn.clone(),
+            self.state.entity_updates.clone(),
+            self.state.alert_allowed_hosts.clone(),
+        )?;
 
         let router = Router::new()
             .route("/health", get(health_handler))
             .route(
                 "/events",
-                post(event_handler).route_layer(middleware::from_fn_with_state(
+                post(event_handler)
+                    .route_layer(middleware::from_fn_with_state(
+                        self.state.clone(),
+                        signature_handler,
+                    ))
+                    .route_layer(middleware::from_fn_with_state(
+                        self.state.clone(),
+                        auth_handler,
+                    )),
+            )
+            .route("/stats", get(stats_handler))
+            .route("/screening", post(screening_handler))
+            .route("/export/addresses", get(export_handler::<address::Entity>))
+            .route("/export/assets", get(export_handler::<asset::Entity>))
+            .route("/export/cases", get(export_handler::<case::Entity>))
+            .route("/export/reporters", get(export_handler::<reporter::Entity>))
+            .route("/graphql", get(graphql_ws_handler).post(graphql_handler))
+            .route("/indexer", get(indexer_handler))
+            .route(
+                "/indexer/:id/heartbeat",
+                put(indexer_heartbeat_handler).route_layer(middleware::from_fn_with_state(
                     self.state.clone(),
                     auth_handler,
                 )),
             )
-            .route("/stats", get(stats_handler))
-            .route("/graphql", get(graphiql_playground).post(graphql_handler))
-            .route("/indexer", get(indexer_handler))
-            .route("/indexer/:id/heartbeat", put(indexer_heartbeat_handler))
             .with_state(self.state.clone())
             .layer(Extension(schema));
 
@@ -3,6 +3,7 @@ use {
         extract::{Json, State},
         http::StatusCode,
     },
+    chrono::NaiveDateTime,
     hapi_core::client::{
         entities::{
             address::Address as AddressPayload, asset::Asset as AssetPayload,
@@ -10,18 +11,25 @@ use {
         },
         events::EventName,
     },
-    hapi_indexer::{PushData, PushPayload},
-    sea_orm::DatabaseConnection,
+    hapi_core::HapiCoreNetwork,
+    hapi_indexer::{NetworkConfiguration, PushData, PushPayload},
+    sea_orm::{DatabaseTransaction, TransactionTrait},
     tracing::instrument,
 };
 
 use crate::{
     application::AppState,
-    entity::{address, asset, case, reporter},
+    entity::{
+        address, asset, case, network,
+        normalization::normalize_indexed_address,
+        reporter,
+        subscription::{EntityUpdate, UpdateKind},
+        types::NetworkBackend,
+    },
     error::AppError,
     observability::{
-        update_address_metrics, update_asset_metrics, update_case_metrics, update_reporter_metrics,
-        MetricOp,
+        update_address_metrics, update_asset_metrics, update_case_metrics, update_network_metrics,
+        update_reporter_metrics, MetricOp,
     },
     service::{get_network_id, EntityMutation, EntityQuery},
 };
@@ -33,18 +41,27 @@ pub(crate) async fn event_handler(
     Json(payload): Json<PushPayload>,
 ) -> Result<StatusCode, AppError> {
     tracing::info!(event = ?payload.event, "Received event");
-    let event_name = payload.event.name;
-    let timestamp = payload.event.timestamp;
-    let db = &state.database_conn;
+    let backend = NetworkBackend::from(payload.network_data.network.clone());
+    let network_id =
+        get_network_id(&state.database_conn, backend, payload.network_data.chain_id).await?;
+
+    // Addresses are stored in their canonical form, so lookups match regardless of the input format
+    let data = normalize_payload(&payload.network_data.network, payload.data)?;
+
+    // The event record and the entity mutation are committed together,
+    // so a redelivered event is either skipped or applied exactly once
+    let txn = state.database_conn.begin().await?;
+    let db = &txn;
 
-    let network_id = get_network_id(
-        db,
-        payload.network_data.network.into(),
-        payload.network_data.chain_id,
-    )
-    .await?;
+    if EntityQuery::is_event_applied(db, network_id.clone(), &payload.event).await? {
+        tracing::info!(tx_hash = %payload.event.tx_hash, "Event is already applied");
+        return Ok(StatusCode::OK);
+    }
+
+    let event_name = payload.event.name.clone();
+    let timestamp = payload.event.timestamp;
 
-    match payload.data {
+    let outcome = match data.clone() {
         PushData::Address(address) => {
             process_address_payload(address, event_name, db, network_id, timestamp).await
         }
@@ -57,27 +74,131 @@ pub(crate) async fn event_handler(
         PushData::Reporter(reporter) => {
             process_reporter_payload(reporter, event_name, db, network_id, timestamp).await
         }
+        PushData::Configuration(configuration) => {
+            process_configuration_payload(configuration, event_name, db, network_id, timestamp)
+                .await
+        }
+    }?;
+
+    // Stale events don't change the state, so they are not recorded in the history
+    let EventOutcome::Applied { models, update } = outcome else {
+        return Ok(StatusCode::OK);
+    };
+
+    // A concurrent delivery of the same event has been recorded first, this one is rolled back
+    if !EntityMutation::register_event(db, network_id, &payload.event, &data).await? {
+        tracing::info!(tx_hash = %payload.event.tx_hash, "Event is already applied");
+        return Ok(StatusCode::OK);
     }
+
+    txn.commit().await?;
+
+    // Gauges follow the committed state only, so redelivered events don't change them
+    models.update_metrics();
+
+    if let Some(update) = update {
+        // The event is already applied, so failed watchlist lookups are only reported
+        if let Err(e) = state.alerts.notify(&state.database_conn, &update).await {
+            tracing::error!(error = ?e, "Failed to queue watchlist alerts");
+        }
+
+        // Sending fails only when there are no subscribers
+        state.entity_updates.send(update).ok();
+    }
+
+    Ok(StatusCode::OK)
+}
+
+/// Brings the addresses of the payload to the canonical form of the indexed network
+fn normalize_payload(network: &HapiCoreNetwork, data: PushData) -> Result<PushData, AppError> {
+    let normalize = |address: &str| {
+        normalize_indexed_address(network, address)
+            .map_err(|e| AppError::invalid_request(&e.to_string()))
+    };
+
+    let data = match data {
+        PushData::Address(mut address) => {
+            address.address = normalize(&address.address)?;
+            PushData::Address(address)
+        }
+        PushData::Asset(mut asset) => {
+            asset.address = normalize(&asset.address)?;
+            PushData::Asset(asset)
+        }
+        data => data,
+    };
+
+    Ok(data)
+}
+
+/// Result of applying the event to the explorer state
+enum EventOutcome {
+    /// The state is changed, the update is sent to subscribers if there is one
+    Applied {
+        models: ChangedModels,
+        update: Option<EntityUpdate>,
+    },
+    /// The entity was already updated by a later event
+    Stale,
+}
+
+/// Previous and new state of the changed entity, the previous one is absent for created entities
+enum ChangedModels {
+    Address(Option<address::Model>, address::Model),
+    Asset(Option<asset::Model>, asset::Model),
+    Case(Option<case::Model>, case::Model),
+    Reporter(Option<reporter::Model>, reporter::Model),
+    Network(network::Model, network::Model),
+}
+
+impl ChangedModels {
+    /// Moves the entity in the gauges from the previous state to the new one
+    fn update_metrics(self) {
+        match self {
+            ChangedModels::Address(old, new) => swap_metrics(update_address_metrics, old, new),
+            ChangedModels::Asset(old, new) => swap_metrics(update_asset_metrics, old, new),
+            ChangedModels::Case(old, new) => swap_metrics(update_case_metrics, old, new),
+            ChangedModels::Reporter(old, new) => swap_metrics(update_reporter_metrics, old, new),
+            ChangedModels::Network(old, new) => {
+                swap_metrics(update_network_metrics, Some(old), new)
+            }
+        }
+    }
+}
+
+fn swap_metrics<M>(update: fn(M, MetricOp), old: Option<M>, new: M) {
+    if let Some(old) = old {
+        update(old, MetricOp::Decrement);
+    }
+
+    update(new, MetricOp::Increment);
+}
+
+/// Whether the stored entity was updated by a later event than the received one
+fn is_stale(updated_at: NaiveDateTime, timestamp: u64) -> bool {
+    updated_at.timestamp() > timestamp as i64
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_address_payload(
     address: AddressPayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
     timestamp: u64,
-) -> Result<StatusCode, AppError> {
+) -> Result<EventOutcome, AppError> {
     tracing::info!(address = ?address, "Received address");
 
-    let address = match event_name {
+    let (old, address, kind) = match event_name {
         EventName::CreateAddress => {
-            EntityMutation::create_entity::<address::ActiveModel, _>(
+            let new = EntityMutation::create_entity::<address::ActiveModel, _>(
                 db, address, network_id, timestamp,
             )
-            .await?
+            .await?;
+
+            (None, new, UpdateKind::Created)
         }
-        EventName::UpdateAddress => {
+        EventName::UpdateAddress | EventName::ConfirmAddress => {
             let old = EntityQuery::find_entity_by_id::<address::Entity, _>(
                 db,
                 (network_id.clone(), address.address.clone()),
@@ -85,14 +206,17 @@ async fn process_address_payload(
             .await?
             .ok_or(AppError::invalid_request("This address does not exist"))?;
 
+            if is_stale(old.updated_at, timestamp) {
+                tracing::info!(updated_at = %old.updated_at, "Ignoring stale address update");
+                return Ok(EventOutcome::Stale);
+            }
+
             let new = EntityMutation::update_entity::<address::ActiveModel, _>(
                 db, address, network_id, timestamp,
             )
             .await?;
 
-            update_address_metrics(old, MetricOp::Decrement);
-
-            new
+            (Some(old), new, UpdateKind::Updated)
         }
         _ => {
             return Err(AppError::invalid_request(&format!(
@@ -101,27 +225,32 @@ async fn process_address_payload(
         }
     };
 
-    update_address_metrics(address, MetricOp::Increment);
-
-    Ok(StatusCode::OK)
+    Ok(EventOutcome::Applied {
+        models: ChangedModels::Address(old, address.clone()),
+        update: Some(EntityUpdate::Address(kind, address)),
+    })
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_asset_payload(
     asset: AssetPayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
     timestamp: u64,
-) -> Result<StatusCode, AppError> {
+) -> Result<EventOutcome, AppError> {
     tracing::info!(asset = ?asset, "Received asset");
 
-    let asset = match event_name {
+    let (old, asset, kind) = match event_name {
         EventName::CreateAsset => {
-            EntityMutation::create_entity::<asset::ActiveModel, _>(db, asset, network_id, timestamp)
-                .await?
+            let new = EntityMutation::create_entity::<asset::ActiveModel, _>(
+                db, asset, network_id, timestamp,
+            )
+            .await?;
+
+            (None, new, UpdateKind::Created)
         }
-        EventName::UpdateAsset => {
+        EventName::UpdateAsset | EventName::ConfirmAsset => {
             let old = EntityQuery::find_entity_by_id::<asset::Entity, _>(
                 db,
                 (
@@ -133,14 +262,17 @@ async fn process_asset_payload(
             .await?
             .ok_or(AppError::invalid_request("This asset does not exist"))?;
 
+            if is_stale(old.updated_at, timestamp) {
+                tracing::info!(updated_at = %old.updated_at, "Ignoring stale asset update");
+                return Ok(EventOutcome::Stale);
+            }
+
             let new = EntityMutation::update_entity::<asset::ActiveModel, _>(
                 db, asset, network_id, timestamp,
             )
             .await?;
 
-            update_asset_metrics(old, MetricOp::Decrement);
-
-            new
+            (Some(old), new, UpdateKind::Updated)
         }
         _ => {
             return Err(AppError::invalid_request(&format!(
@@ -149,25 +281,30 @@ async fn process_asset_payload(
         }
     };
 
-    update_asset_metrics(asset, MetricOp::Increment);
-
-    Ok(StatusCode::OK)
+    Ok(EventOutcome::Applied {
+        models: ChangedModels::Asset(old, asset.clone()),
+        update: Some(EntityUpdate::Asset(kind, asset)),
+    })
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_case_payload(
     case: CasePayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
     timestamp: u64,
-) -> Result<StatusCode, AppError> {
+) -> Result<EventOutcome, AppError> {
     tracing::info!(case = ?case, "Received case");
 
-    let case = match event_name {
+    let (old, case) = match event_name {
         EventName::CreateCase => {
-            EntityMutation::create_entity::<case::ActiveModel, _>(db, case, network_id, timestamp)
-                .await?
+            let new = EntityMutation::create_entity::<case::ActiveModel, _>(
+                db, case, network_id, timestamp,
+            )
+            .await?;
+
+            (None, new)
         }
         EventName::UpdateCase => {
             let old = EntityQuery::find_entity_by_id::<case::Entity, _>(
@@ -177,14 +314,17 @@ async fn process_case_payload(
             .await?
             .ok_or(AppError::invalid_request("This case does not exist"))?;
 
+            if is_stale(old.updated_at, timestamp) {
+                tracing::info!(updated_at = %old.updated_at, "Ignoring stale case update");
+                return Ok(EventOutcome::Stale);
+            }
+
             let new = EntityMutation::update_entity::<case::ActiveModel, _>(
                 db, case, network_id, timestamp,
             )
             .await?;
 
-            update_case_metrics(old, MetricOp::Decrement);
-
-            new
+            (Some(old), new)
         }
         _ => {
             return Err(AppError::invalid_request(&format!(
@@ -193,27 +333,30 @@ async fn process_case_payload(
         }
     };
 
-    update_case_metrics(case, MetricOp::Increment);
-
-    Ok(StatusCode::OK)
+    Ok(EventOutcome::Applied {
+        models: ChangedModels::Case(old, case),
+        update: None,
+    })
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_reporter_payload(
     reporter: ReporterPayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
     timestamp: u64,
-) -> Result<StatusCode, AppError> {
+) -> Result<EventOutcome, AppError> {
     tracing::info!(reporter = ?reporter, "Received reporter");
 
-    let reporter = match event_name {
+    let (old, reporter) = match event_name {
         EventName::CreateReporter => {
-            EntityMutation::create_entity::<reporter::ActiveModel, _>(
+            let new = EntityMutation::create_entity::<reporter::ActiveModel, _>(
                 db, reporter, network_id, timestamp,
             )
-            .await?
+            .await?;
+
+            (None, new)
         }
         EventName::UpdateReporter
         | EventName::ActivateReporter
@@ -226,14 +369,17 @@ async fn process_reporter_payload(
             .await?
             .ok_or(AppError::invalid_request("This reporter does not exist"))?;
 
+            if is_stale(old.updated_at, timestamp) {
+                tracing::info!(updated_at = %old.updated_at, "Ignoring stale reporter update");
+                return Ok(EventOutcome::Stale);
+            }
+
             let new = EntityMutation::update_entity::<reporter::ActiveModel, _>(
                 db, reporter, network_id, timestamp,
             )
             .await?;
 
-            update_reporter_metrics(old, MetricOp::Decrement);
-
-            new
+            (Some(old), new)
         }
         _ => {
             return Err(AppError::invalid_request(&format!(
@@ -242,7 +388,59 @@ async fn process_reporter_payload(
         }
     };
 
-    update_reporter_metrics(reporter, MetricOp::Increment);
+    Ok(EventOutcome::Applied {
+        models: ChangedModels::Reporter(old, reporter),
+        update: None,
+    })
+}
 
-    Ok(StatusCode::OK)
+#[instrument(level = "trace", skip(db))]
+async fn process_configuration_payload(
+    configuration: NetworkConfiguration,
+    event_name: EventName,
+    db: &DatabaseTransaction,
+    network_id: String,
+    timestamp: u64,
+) -> Result<EventOutcome, AppError> {
+    tracing::info!(configuration = ?configuration, "Received network configuration");
+
+    let models = match event_name {
+        EventName::Initialize
+        | EventName::SetAuthority
+        | EventName::UpdateStakeConfiguration
+        | EventName::UpdateRewardConfiguration => {
+            let old = EntityQuery::find_entity_by_id::<network::Entity, _>(db, network_id.clone())
+                .await?
+                .ok_or(AppError::invalid_request("This network does not exist"))?;
+
+            let updated_at =
+                EntityQuery::find_configuration_updated_at(db, network_id.clone(), &event_name)
+                    .await?;
+
+            if let Some(updated_at) = updated_at.filter(|&at| is_stale(at, timestamp)) {
+                tracing::info!(%updated_at, "Ignoring stale network configuration update");
+                return Ok(EventOutcome::Stale);
+            }
+
+            let new = EntityMutation::update_network_configuration(
+                db,
+                network_id,
+                configuration,
+                timestamp,
+            )
+            .await?;
+
+            ChangedModels::Network(old, new)
+        }
+        _ => {
+            return Err(AppError::invalid_request(&format!(
+                "Received unexpected event with configuration payload: {event_name}"
+            )));
+        }
+    };
+
+    Ok(EventOutcome::Applied {
+        models,
+        update: None,
+    })
 }
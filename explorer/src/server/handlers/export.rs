@@ -0,0 +1,52 @@
+use {
+    axum::{
+        body::StreamBody,
+        extract::{Query, State},
+        http::header,
+        response::IntoResponse,
+    },
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    tracing::instrument,
+};
+
+use crate::{
+    application::AppState,
+    service::{export_entities, ExportFormat, Exportable},
+};
+
+/// Query parameters of the `/export` endpoints besides the entity filter
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct ExportParams {
+    #[serde(default)]
+    format: ExportFormat,
+}
+
+/// Handle export Requests, streaming all the filtered entities as a file
+#[instrument(level = "info", skip(state, filter), fields(entity = E::NAME))]
+pub(crate) async fn export_handler<E>(
+    state: State<AppState>,
+    Query(params): Query<ExportParams>,
+    Query(filter): Query<E::Filter>,
+) -> impl IntoResponse
+where
+    E: Exportable,
+    E::Filter: DeserializeOwned + Send + Sync + 'static,
+    E::Model: Serialize,
+{
+    let format = params.format;
+    let headers = [
+        (header::CONTENT_TYPE, format.content_type().to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"{}.{}\"",
+                E::NAME,
+                format.extension()
+            ),
+        ),
+    ];
+
+    let chunks = export_entities::<E>(state.database_conn.clone(), filter, format);
+
+    (headers, StreamBody::new(chunks))
+}
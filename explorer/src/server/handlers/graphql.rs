@@ -1,24 +1,69 @@
 use {
-    async_graphql_axum::{GraphQLRequest, GraphQLResponse},
+    async_graphql::{http::ALL_WEBSOCKET_PROTOCOLS, Data},
+    async_graphql_axum::{GraphQLProtocol, GraphQLRequest, GraphQLResponse, GraphQLWebSocket},
     axum::{
-        response::{Html, IntoResponse},
+        extract::{State, WebSocketUpgrade},
+        http::HeaderMap,
+        response::{Html, IntoResponse, Response},
         Extension,
     },
+    axum_extra::extract::cookie::CookieJar,
 };
 
-use crate::server::schema::AppSchema;
+use super::jwt_auth::{authenticate, get_token};
+use crate::{application::AppState, entity::loader::EntityLoader, server::schema::AppSchema};
 
 /// Handle GraphQL playground
 pub(crate) async fn graphiql_playground() -> impl IntoResponse {
     Html(async_graphql::http::playground_source(
-        async_graphql::http::GraphQLPlaygroundConfig::new("/graphql"),
+        async_graphql::http::GraphQLPlaygroundConfig::new("/graphql")
+            .subscription_endpoint("/graphql"),
     ))
 }
 
 /// Handle GraphQL Requests
 pub(crate) async fn graphql_handler(
+    state: State<AppState>,
     schema: Extension<AppSchema>,
+    cookie_jar: CookieJar,
+    headers: HeaderMap,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
-    schema.execute(req.into_inner()).await.into()
+    let mut req = req
+        .into_inner()
+        .data(EntityLoader::data_loader(state.database_conn.clone()));
+
+    // Queries are public, mutations require the claims and report why the token was rejected
+    if let Some(token) = get_token(&cookie_jar, &headers) {
+        req = match authenticate(&state, &token).await {
+            Ok(claims) => req.data(claims),
+            Err(error) => req.data(error),
+        };
+    }
+
+    schema.execute(req).await.into()
+}
+
+/// Handle GraphQL subscriptions over WebSocket, serving the playground for plain requests
+pub(crate) async fn graphql_ws_handler(
+    state: State<AppState>,
+    Extension(schema): Extension<AppSchema>,
+    protocol: Option<GraphQLProtocol>,
+    upgrade: Option<WebSocketUpgrade>,
+) -> Response {
+    match (protocol, upgrade) {
+        (Some(protocol), Some(upgrade)) => {
+            let mut data = Data::default();
+            data.insert(EntityLoader::data_loader(state.database_conn.clone()));
+
+            upgrade
+                .protocols(ALL_WEBSOCKET_PROTOCOLS)
+                .on_upgrade(move |stream| {
+                    GraphQLWebSocket::new(stream, schema, protocol)
+                        .with_data(data)
+                        .serve()
+                })
+        }
+        _ => graphiql_playground().await.into_response(),
+    }
 }
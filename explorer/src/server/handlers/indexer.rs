@@ -1,14 +1,19 @@
 use {
     axum::{
         extract::{Path, Query, State},
+        http::StatusCode,
         response::IntoResponse,
-        Json,
+        Extension, Json,
     },
     sea_orm::{ActiveModelTrait, EntityTrait, PaginatorTrait, Set},
     uuid::Uuid,
 };
 
-use crate::{application::AppState, entity::indexer, error::AppError};
+use super::TokenClaims;
+use crate::{
+    application::AppState, entity::indexer, error::AppError,
+    observability::record_forbidden_heartbeat,
+};
 
 const DEFAULT_PAGE_SIZE: u64 = 25;
 
@@ -50,8 +55,23 @@ pub(crate) async fn indexer_handler(
 pub(crate) async fn indexer_heartbeat_handler(
     state: State<AppState>,
     Path(id): Path<Uuid>,
+    Extension(claims): Extension<TokenClaims>,
     cursor: String,
 ) -> Result<impl IntoResponse, AppError> {
+    if claims.id.parse::<Uuid>().ok() != Some(id) {
+        tracing::warn!(
+            token_id = %claims.id,
+            requested_id = %id,
+            "Heartbeat with a token of another indexer"
+        );
+        record_forbidden_heartbeat(claims.id);
+
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            "Token does not belong to the indexer".to_string(),
+        ));
+    }
+
     let db = &state.database_conn;
 
     indexer::ActiveModel {
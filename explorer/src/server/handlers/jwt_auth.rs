@@ -2,7 +2,7 @@ use {
     axum::{
         extract::State,
         http::Request,
-        http::{header, StatusCode},
+        http::{header, HeaderMap, StatusCode},
         middleware::Next,
         response::IntoResponse,
     },
@@ -10,29 +10,42 @@ use {
     jsonwebtoken::{decode, DecodingKey, Validation},
     secrecy::ExposeSecret,
     serde::{Deserialize, Serialize},
+    uuid::Uuid,
 };
 
-use crate::{application::AppState, error::AppError};
+use crate::{application::AppState, entity::indexer, error::AppError, service::EntityQuery};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenClaims {
     pub id: String,
     pub iat: usize,
     pub exp: usize,
+    /// Token generation of the indexer at the moment of issue
+    #[serde(default)]
+    pub generation: i32,
 }
 
-pub(crate) async fn auth_handler<B>(
-    state: State<AppState>,
-    cookie_jar: CookieJar,
-    req: Request<B>,
-    next: Next<B>,
-) -> Result<impl IntoResponse, AppError> {
-    let jwt_secret = state.jwt_secret.clone();
-    let token = cookie_jar
+/// Checks whether the indexer tokens were revoked or rotated after the token was issued
+async fn is_revoked(state: &AppState, claims: &TokenClaims) -> Result<bool, AppError> {
+    let Ok(id) = claims.id.parse::<Uuid>() else {
+        return Ok(false);
+    };
+
+    let indexer =
+        EntityQuery::find_entity_by_id::<indexer::Entity, _>(&state.database_conn, id).await?;
+
+    Ok(indexer.map_or(false, |indexer| {
+        indexer.token_generation != claims.generation
+    }))
+}
+
+/// Token from the `token` cookie or the bearer authorization header
+pub(crate) fn get_token(cookie_jar: &CookieJar, headers: &HeaderMap) -> Option<String> {
+    cookie_jar
         .get("token")
         .map(|cookie| cookie.value().to_string())
         .or_else(|| {
-            req.headers()
+            headers
                 .get(header::AUTHORIZATION)
                 .and_then(|auth_header| auth_header.to_str().ok())
                 .and_then(|auth_value| {
@@ -40,21 +53,46 @@ pub(crate) async fn auth_handler<B>(
                         .strip_prefix("Bearer ")
                         .map(|payload| payload.to_owned())
                 })
-        });
+        })
+}
+
+/// Decodes the claims of a valid token that is not revoked
+pub(crate) async fn authenticate(state: &AppState, token: &str) -> Result<TokenClaims, AppError> {
+    let claims = decode::<TokenClaims>(
+        token,
+        &DecodingKey::from_secret(state.jwt_secret.expose_secret().as_ref()),
+        &Validation::default(),
+    )
+    .map_err(|_| AppError::new(StatusCode::UNAUTHORIZED, "Invalid token".to_string()))?
+    .claims;
 
-    let token = token.ok_or_else(|| {
+    if is_revoked(state, &claims).await? {
+        return Err(AppError::new(
+            StatusCode::UNAUTHORIZED,
+            "Token is revoked".to_string(),
+        ));
+    }
+
+    Ok(claims)
+}
+
+pub(crate) async fn auth_handler<B>(
+    state: State<AppState>,
+    cookie_jar: CookieJar,
+    mut req: Request<B>,
+    next: Next<B>,
+) -> Result<impl IntoResponse, AppError> {
+    let token = get_token(&cookie_jar, req.headers()).ok_or_else(|| {
         AppError::new(
             StatusCode::UNAUTHORIZED,
             "You are not authenticated, please provide token".to_string(),
         )
     })?;
 
-    decode::<TokenClaims>(
-        &token,
-        &DecodingKey::from_secret(jwt_secret.expose_secret().as_ref()),
-        &Validation::default(),
-    )
-    .map_err(|_| AppError::new(StatusCode::UNAUTHORIZED, "Invalid token".to_string()))?;
+    let claims = authenticate(&state, &token).await?;
+
+    // Handlers can check which indexer the token belongs to
+    req.extensions_mut().insert(claims);
 
     Ok(next.run(req).await)
 }
@@ -1,15 +1,22 @@
 mod events;
+mod export;
 mod graphql;
 mod health;
 mod indexer;
 mod jwt_auth;
+mod screening;
+mod signature;
 mod stats;
 
 pub(crate) use events::event_handler;
-pub(crate) use graphql::{graphiql_playground, graphql_handler};
+pub(crate) use export::export_handler;
+pub(crate) use graphql::{graphql_handler, graphql_ws_handler};
 pub(crate) use health::health_handler;
 pub(crate) use indexer::{indexer_handler, indexer_heartbeat_handler};
 pub(crate) use jwt_auth::auth_handler;
+pub(crate) use screening::screening_handler;
+pub(crate) use signature::signature_handler;
 pub(crate) use stats::stats_handler;
 
 pub use jwt_auth::TokenClaims;
+pub use stats::StatsCache;
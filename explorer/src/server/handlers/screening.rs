@@ -0,0 +1,32 @@
+use {
+    axum::{extract::State, Json},
+    serde::Deserialize,
+    tracing::instrument,
+};
+
+use crate::{
+    application::AppState,
+    entity::screening::{screen_addresses, validate_targets, ScreeningResult, ScreeningTarget},
+    error::AppError,
+};
+
+/// Body of the `/screening` request
+#[derive(Debug, Deserialize)]
+pub(crate) struct ScreeningRequest {
+    targets: Vec<ScreeningTarget>,
+    threshold: i16,
+}
+
+/// Handle screening Requests
+#[instrument(level = "info", skip(state, request), fields(targets = request.targets.len()))]
+pub(crate) async fn screening_handler(
+    state: State<AppState>,
+    Json(request): Json<ScreeningRequest>,
+) -> Result<Json<Vec<ScreeningResult>>, AppError> {
+    validate_targets(&request.targets).map_err(|e| AppError::invalid_request(&e))?;
+
+    let results =
+        screen_addresses(&state.database_conn, &request.targets, request.threshold).await?;
+
+    Ok(Json(results))
+}
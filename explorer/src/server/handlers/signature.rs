@@ -0,0 +1,63 @@
+use {
+    axum::{
+        body::{Body, Bytes},
+        extract::{FromRequest, State},
+        http::{HeaderMap, Request, StatusCode},
+        middleware::Next,
+        response::IntoResponse,
+    },
+    hapi_indexer::{verify_signature, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+    secrecy::ExposeSecret,
+    std::time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{application::AppState, error::AppError};
+
+fn unauthorized(description: &str) -> AppError {
+    AppError::new(StatusCode::UNAUTHORIZED, description.to_string())
+}
+
+fn get_header<'a>(headers: &'a HeaderMap, name: &str) -> Result<&'a str, AppError> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| unauthorized(&format!("Missing {name} header")))
+}
+
+/// Verifies the HMAC signature of the request body if the webhook secret is configured
+pub(crate) async fn signature_handler(
+    state: State<AppState>,
+    req: Request<Body>,
+    next: Next<Body>,
+) -> Result<impl IntoResponse, AppError> {
+    let Some(secret) = &state.webhook_secret else {
+        return Ok(next.run(req).await);
+    };
+
+    let timestamp = get_header(req.headers(), TIMESTAMP_HEADER)?
+        .parse::<u64>()
+        .map_err(|_| unauthorized("Invalid signature timestamp"))?;
+    let signature = get_header(req.headers(), SIGNATURE_HEADER)?.to_owned();
+
+    // Replayed requests are rejected once the signature timestamp is out of the tolerance window
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    if now.abs_diff(timestamp) > state.webhook_signature_tolerance.as_secs() {
+        return Err(unauthorized("Stale signature timestamp"));
+    }
+
+    let (parts, body) = req.into_parts();
+    let body = Bytes::from_request(Request::new(body), &state)
+        .await
+        .map_err(|_| AppError::invalid_request("Failed to read request body"))?;
+
+    if !verify_signature(
+        secret.expose_secret().as_bytes(),
+        timestamp,
+        &body,
+        &signature,
+    ) {
+        return Err(unauthorized("Invalid signature"));
+    }
+
+    Ok(next.run(Request::from_parts(parts, Body::from(body))).await)
+}
@@ -1,6 +1,108 @@
-use axum::response::IntoResponse;
+use {
+    axum::{
+        extract::{Query, State},
+        Json,
+    },
+    chrono::NaiveDate,
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    },
+    tracing::instrument,
+};
+
+use crate::{
+    application::AppState,
+    entity::statistics::{
+        get_charts, get_dashboard, ChartGranularity, Charts, Dashboard, StatisticsFilter,
+    },
+    error::AppError,
+};
+
+/// Statistics returned by the `/stats` endpoint
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct Stats {
+    dashboard: Dashboard,
+    charts: Charts,
+}
+
+/// Query parameters of the `/stats` endpoint
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+pub(crate) struct StatsParams {
+    network_id: Option<String>,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    granularity: Option<ChartGranularity>,
+}
+
+impl StatsParams {
+    fn filter(&self) -> StatisticsFilter {
+        StatisticsFilter {
+            network_id: self.network_id.clone(),
+            from: self.from,
+            to: self.to,
+        }
+    }
+}
+
+/// Recently computed statistics by request parameters
+#[derive(Clone)]
+pub struct StatsCache {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<StatsParams, (Instant, Stats)>>>,
+}
+
+impl StatsCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::default(),
+        }
+    }
+
+    fn get(&self, params: &StatsParams) -> Option<Stats> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        entries
+            .get(params)
+            .filter(|(created_at, _)| created_at.elapsed() < self.ttl)
+            .map(|(_, stats)| stats.clone())
+    }
+
+    fn insert(&self, params: StatsParams, stats: Stats) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        entries.retain(|_, (created_at, _)| created_at.elapsed() < self.ttl);
+        entries.insert(params, (Instant::now(), stats));
+    }
+}
 
 /// Handle stats Requests
-pub(crate) async fn stats_handler() -> impl IntoResponse {
-    unimplemented!()
+#[instrument(level = "info", skip(state))]
+pub(crate) async fn stats_handler(
+    state: State<AppState>,
+    Query(params): Query<StatsParams>,
+) -> Result<Json<Stats>, AppError> {
+    let filter = params.filter();
+    let granularity = params.granularity.unwrap_or_default();
+
+    filter
+        .validate(granularity)
+        .map_err(|e| AppError::invalid_request(&e))?;
+
+    if let Some(stats) = state.stats_cache.get(&params) {
+        return Ok(Json(stats));
+    }
+
+    let db = &state.database_conn;
+    let stats = Stats {
+        dashboard: get_dashboard(db, &filter).await?,
+        charts: get_charts(db, &filter, granularity).await?,
+    };
+
+    state.stats_cache.insert(params, stats.clone());
+
+    Ok(Json(stats))
 }
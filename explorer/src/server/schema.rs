@@ -1,12 +1,21 @@
 use {
     anyhow::Result,
-    async_graphql::{EmptyMutation, EmptySubscription, MergedObject, Schema},
+    async_graphql::{MergedObject, Schema},
     sea_orm::DatabaseConnection,
 };
 
 use crate::entity::{
-    address::AddressQuery, asset::AssetQuery, case::CaseQuery, network::NetworkQuery,
-    reporter::ReporterQuery, statistics::StatisticsQuery,
+    address::AddressQuery,
+    asset::AssetQuery,
+    case::CaseQuery,
+    event::EventQuery,
+    lookup::LookupQuery,
+    network::NetworkQuery,
+    reporter::ReporterQuery,
+    screening::ScreeningQuery,
+    statistics::StatisticsQuery,
+    subscription::{EntitySubscription, EntityUpdates},
+    watchlist::{AlertAllowedHosts, WatchlistMutation, WatchlistQuery},
 };
 
 /// Top-level application Query type
@@ -18,16 +27,31 @@ pub struct Query(
     ReporterQuery,
     NetworkQuery,
     StatisticsQuery,
+    EventQuery,
+    ScreeningQuery,
+    LookupQuery,
+    WatchlistQuery,
 );
 
+/// Top-level application Mutation type, requires an authentication token
+#[derive(Default, MergedObject)]
+pub struct Mutation(WatchlistMutation);
+
 /// Top-level merged application schema
-pub type AppSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+pub type AppSchema = Schema<Query, Mutation, EntitySubscription>;
 
-/// Building the GraphQL application schema, attaching the Database to the context
-pub(crate) fn create_graphql_schema(db: DatabaseConnection) -> Result<AppSchema> {
+/// Building the GraphQL application schema, attaching the Database, entity updates and
+/// allowed webhook hosts. Entity loaders are attached to each request instead
+pub(crate) fn create_graphql_schema(
+    db: DatabaseConnection,
+    entity_updates: EntityUpdates,
+    alert_allowed_hosts: AlertAllowedHosts,
+) -> Result<AppSchema> {
     Ok(
-        Schema::build(Query::default(), EmptyMutation, EmptySubscription)
+        Schema::build(Query::default(), Mutation::default(), EntitySubscription)
             .data(db)
+            .data(entity_updates)
+            .data(alert_allowed_hosts)
             .finish(),
     )
 }
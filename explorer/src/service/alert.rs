@@ -0,0 +1,320 @@
+use {
+    hapi_indexer::{sign_payload, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+    reqwest::{
+        dns::{Addrs, Name, Resolve, Resolving},
+        redirect::Policy,
+        Client, StatusCode, Url,
+    },
+    sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter},
+    serde::Serialize,
+    std::{
+        cmp::min,
+        error::Error,
+        net::SocketAddr,
+        sync::Arc,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+    tokio::{
+        net::lookup_host,
+        sync::{mpsc, Semaphore},
+        time::sleep,
+    },
+    uuid::Uuid,
+};
+
+use crate::{
+    entity::{
+        address, asset,
+        subscription::{EntityUpdate, UpdateKind},
+        watchlist::{self, webhook_host, AlertAllowedHosts},
+    },
+    observability::record_dropped_alert,
+};
+
+/// Upper bound of the delay between alert delivery attempts
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Timeout of a single alert delivery attempt
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Number of alerts delivered at the same time, the others wait in the queue
+const MAX_CONCURRENT_DELIVERIES: usize = 32;
+
+/// Changed entity the alert is sent about
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "entity", content = "data", rename_all = "snake_case")]
+pub enum AlertEntity {
+    Address(address::Model),
+    Asset(asset::Model),
+}
+
+/// Payload posted to the webhook of the watchlist
+#[derive(Clone, Debug, Serialize)]
+pub struct AlertPayload {
+    pub watchlist_id: Uuid,
+    pub network_id: String,
+    pub address: String,
+    pub kind: UpdateKind,
+    #[serde(flatten)]
+    pub entity: AlertEntity,
+}
+
+/// Alert waiting for the delivery
+#[derive(Debug)]
+struct Alert {
+    webhook_url: String,
+    secret: String,
+    payload: AlertPayload,
+}
+
+/// Queue of the watchlist alerts, delivered in the background.
+/// The queue is kept in memory only, so the alerts waiting for the delivery are lost on restart
+#[derive(Clone)]
+pub struct AlertSender {
+    sender: mpsc::Sender<Alert>,
+}
+
+impl AlertSender {
+    /// Starts the delivery of the queued alerts
+    pub fn start(
+        retry_delay: Duration,
+        max_attempts: u32,
+        queue_capacity: usize,
+        allowed_hosts: AlertAllowedHosts,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel(queue_capacity);
+
+        tokio::spawn(deliver_alerts(
+            receiver,
+            retry_delay,
+            max_attempts,
+            allowed_hosts,
+        ));
+
+        Self { sender }
+    }
+
+    /// Queues alerts for the watchlists of the updated address or asset
+    pub async fn notify(
+        &self,
+        db: &DatabaseConnection,
+        update: &EntityUpdate,
+    ) -> Result<(), DbErr> {
+        let (kind, network_id, address, entity) = match update {
+            EntityUpdate::Address(kind, model) => (
+                *kind,
+                &model.network_id,
+                &model.address,
+                AlertEntity::Address(model.clone()),
+            ),
+            EntityUpdate::Asset(kind, model) => (
+                *kind,
+                &model.network_id,
+                &model.address,
+                AlertEntity::Asset(model.clone()),
+            ),
+        };
+
+        let watchlists = watchlist::Entity::find()
+            .filter(watchlist::Column::NetworkId.eq(network_id.clone()))
+            .filter(watchlist::Column::Address.eq(address.clone()))
+            .all(db)
+            .await?;
+
+        for watchlist in watchlists {
+            tracing::info!(
+                watchlist_id = %watchlist.id,
+                address = %address,
+                "Queue watchlist alert"
+            );
+
+            let alert = Alert {
+                webhook_url: watchlist.webhook_url,
+                secret: watchlist.secret,
+                payload: AlertPayload {
+                    watchlist_id: watchlist.id,
+                    network_id: network_id.clone(),
+                    address: address.clone(),
+                    kind,
+                    entity: entity.clone(),
+                },
+            };
+
+            // Events are not held back by slow webhooks, so the alerts over the capacity are dropped
+            if let Err(mpsc::error::TrySendError::Full(alert)) = self.sender.try_send(alert) {
+                tracing::error!(
+                    watchlist_id = %alert.payload.watchlist_id,
+                    address = %address,
+                    "Watchlist alert queue is full, the alert is dropped"
+                );
+                record_dropped_alert("queue_full");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolves the webhook hosts, refusing the local and private addresses of the hosts
+/// that are not allowed, so that the alerts are not sent to the internal network
+struct WebhookResolver {
+    allowed_hosts: AlertAllowedHosts,
+}
+
+impl Resolve for WebhookResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(resolve_webhook_host(
+            name.as_str().to_owned(),
+            self.allowed_hosts.clone(),
+        ))
+    }
+}
+
+async fn resolve_webhook_host(
+    host: String,
+    allowed_hosts: AlertAllowedHosts,
+) -> Result<Addrs, Box<dyn Error + Send + Sync>> {
+    let addrs = lookup_host((host.as_str(), 0))
+        .await?
+        .collect::<Vec<SocketAddr>>();
+
+    if let Some(addr) = addrs
+        .iter()
+        .find(|addr| !allowed_hosts.allows_ip(&host, &addr.ip()))
+    {
+        return Err(format!(
+            "Webhook host {host} is resolved to a local or private address {}",
+            addr.ip()
+        )
+        .into());
+    }
+
+    Ok(Box::new(addrs.into_iter()))
+}
+
+async fn deliver_alerts(
+    mut receiver: mpsc::Receiver<Alert>,
+    retry_delay: Duration,
+    max_attempts: u32,
+    allowed_hosts: AlertAllowedHosts,
+) {
+    // Redirects are not followed, as they could lead to the hosts that are not allowed
+    let client = Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .redirect(Policy::none())
+        .dns_resolver(Arc::new(WebhookResolver {
+            allowed_hosts: allowed_hosts.clone(),
+        }))
+        .build()
+        .expect("Failed to build alert client");
+    let deliveries = Arc::new(Semaphore::new(MAX_CONCURRENT_DELIVERIES));
+
+    // Alerts are delivered concurrently, so a slow webhook does not hold back the others
+    while let Some(alert) = receiver.recv().await {
+        // IP addresses are not resolved, so they are checked before the delivery
+        if !is_allowed_webhook(&alert.webhook_url, &allowed_hosts) {
+            tracing::error!(
+                watchlist_id = %alert.payload.watchlist_id,
+                "Watchlist webhook host is not allowed, the alert is dropped"
+            );
+            record_dropped_alert("host_not_allowed");
+            continue;
+        }
+
+        // The queue fills up while all the deliveries are in progress
+        let Ok(permit) = deliveries.clone().acquire_owned().await else {
+            break;
+        };
+
+        let client = client.clone();
+        tokio::spawn(async move {
+            deliver_alert(client, alert, retry_delay, max_attempts).await;
+            drop(permit);
+        });
+    }
+}
+
+fn is_allowed_webhook(webhook_url: &str, allowed_hosts: &AlertAllowedHosts) -> bool {
+    Url::parse(webhook_url)
+        .ok()
+        .as_ref()
+        .and_then(webhook_host)
+        .map_or(false, |host| allowed_hosts.allows_host(host))
+}
+
+/// Posts the signed alert, retrying with a doubling delay until it is accepted or rejected
+async fn deliver_alert(client: Client, alert: Alert, retry_delay: Duration, max_attempts: u32) {
+    let body = match serde_json::to_vec(&alert.payload) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to serialize watchlist alert");
+            return;
+        }
+    };
+
+    let mut delay = retry_delay;
+
+    for attempt in 1..=max_attempts {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let signature = sign_payload(alert.secret.as_bytes(), timestamp, &body);
+
+        let result = client
+            .post(&alert.webhook_url)
+            .header("content-type", "application/json")
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, signature)
+            .body(body.clone())
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => {
+                tracing::info!(
+                    watchlist_id = %alert.payload.watchlist_id,
+                    "Watchlist alert delivered"
+                );
+                return;
+            }
+            // Redirects and other client errors are not going to be fixed by a retry
+            Ok(response)
+                if response.status().is_redirection()
+                    || (response.status().is_client_error()
+                        && response.status() != StatusCode::TOO_MANY_REQUESTS) =>
+            {
+                tracing::warn!(
+                    watchlist_id = %alert.payload.watchlist_id,
+                    status = %response.status(),
+                    "Watchlist alert is rejected"
+                );
+                return;
+            }
+            Ok(response) => tracing::warn!(
+                watchlist_id = %alert.payload.watchlist_id,
+                status = %response.status(),
+                attempt,
+                "Failed to deliver watchlist alert"
+            ),
+            Err(e) => tracing::warn!(
+                watchlist_id = %alert.payload.watchlist_id,
+                error = ?e,
+                attempt,
+                "Failed to deliver watchlist alert"
+            ),
+        }
+
+        if attempt < max_attempts {
+            sleep(delay).await;
+            delay = min(delay * 2, MAX_RETRY_DELAY);
+        }
+    }
+
+    tracing::error!(
+        watchlist_id = %alert.payload.watchlist_id,
+        max_attempts,
+        "Watchlist alert is dropped after all attempts"
+    );
+    record_dropped_alert("undelivered");
+}
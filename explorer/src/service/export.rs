@@ -0,0 +1,233 @@
+use {
+    anyhow::Result,
+    clap::ValueEnum,
+    sea_orm::{DatabaseConnection, Iterable, PrimaryKeyToColumn, QueryOrder},
+    serde::{Deserialize, Serialize},
+    serde_json::Value,
+    std::mem::take,
+    tokio::sync::mpsc,
+    tokio_stream::{wrappers::ReceiverStream, StreamExt},
+};
+
+use crate::entity::{address, asset, case, reporter, EntityFilter};
+
+/// Size of the buffered rows sent as a single chunk of the export
+const EXPORT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Number of chunks waiting to be consumed before the database stream is paused
+const EXPORT_CHANNEL_CAPACITY: usize = 16;
+
+/// File format of the export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Comma separated values with a header row
+    #[default]
+    Csv,
+    /// One JSON object per line
+    Ndjson,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+        }
+    }
+
+    fn write_header(&self, buffer: &mut Vec<u8>, columns: &[&str]) {
+        if *self == ExportFormat::Csv {
+            write_csv_row(buffer, columns.iter().map(|column| column.to_string()));
+        }
+    }
+
+    fn write_record<T: Serialize>(
+        &self,
+        buffer: &mut Vec<u8>,
+        columns: &[&str],
+        record: &T,
+    ) -> Result<()> {
+        match self {
+            ExportFormat::Csv => {
+                let value = serde_json::to_value(record)?;
+
+                write_csv_row(
+                    buffer,
+                    columns.iter().map(|column| match &value[*column] {
+                        Value::Null => String::new(),
+                        Value::String(field) => field.clone(),
+                        field => field.to_string(),
+                    }),
+                );
+            }
+            ExportFormat::Ndjson => {
+                serde_json::to_writer(&mut *buffer, record)?;
+                buffer.push(b'\n');
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Entity that can be exported in bulk
+pub trait Exportable: EntityFilter {
+    /// Name of the exported collection
+    const NAME: &'static str;
+
+    /// Fields of the model in the order of the CSV columns
+    const COLUMNS: &'static [&'static str];
+}
+
+impl Exportable for address::Entity {
+    const NAME: &'static str = "addresses";
+    const COLUMNS: &'static [&'static str] = &[
+        "network_id",
+        "address",
+        "case_id",
+        "reporter_id",
+        "risk",
+        "category",
+        "confirmations",
+        "created_at",
+        "updated_at",
+    ];
+}
+
+impl Exportable for asset::Entity {
+    const NAME: &'static str = "assets";
+    const COLUMNS: &'static [&'static str] = &[
+        "network_id",
+        "address",
+        "id",
+        "case_id",
+        "reporter_id",
+        "risk",
+        "category",
+        "confirmations",
+        "created_at",
+        "updated_at",
+    ];
+}
+
+impl Exportable for case::Entity {
+    const NAME: &'static str = "cases";
+    const COLUMNS: &'static [&'static str] = &[
+        "network_id",
+        "id",
+        "name",
+        "url",
+        "status",
+        "reporter_id",
+        "created_at",
+        "updated_at",
+    ];
+}
+
+impl Exportable for reporter::Entity {
+    const NAME: &'static str = "reporters";
+    const COLUMNS: &'static [&'static str] = &[
+        "network_id",
+        "id",
+        "account",
+        "role",
+        "status",
+        "name",
+        "url",
+        "stake",
+        "unlock_timestamp",
+        "created_at",
+        "updated_at",
+    ];
+}
+
+/// Streams the filtered entities as chunks of the export file, ordered by the primary key
+pub fn export_entities<E>(
+    db: DatabaseConnection,
+    filter: E::Filter,
+    format: ExportFormat,
+) -> ReceiverStream<Result<Vec<u8>>>
+where
+    E: Exportable,
+    E::Filter: Send + Sync + 'static,
+    E::Model: Serialize,
+{
+    let (sender, receiver) = mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+
+    tokio::spawn(async move {
+        if let Err(e) = write_entities::<E>(&db, &filter, format, &sender).await {
+            tracing::error!(entity = E::NAME, error = ?e, "Failed to export entities");
+
+            sender.send(Err(e)).await.ok();
+        }
+    });
+
+    ReceiverStream::new(receiver)
+}
+
+async fn write_entities<E>(
+    db: &DatabaseConnection,
+    filter: &E::Filter,
+    format: ExportFormat,
+    sender: &mpsc::Sender<Result<Vec<u8>>>,
+) -> Result<()>
+where
+    E: Exportable,
+    E::Filter: Sync,
+    E::Model: Serialize,
+{
+    let mut query = E::filter(E::find(), filter);
+    for key in E::PrimaryKey::iter() {
+        query = query.order_by_asc(key.into_column());
+    }
+
+    let rows = query.stream(db).await?;
+    tokio::pin!(rows);
+
+    let mut buffer = Vec::with_capacity(EXPORT_CHUNK_SIZE);
+    format.write_header(&mut buffer, E::COLUMNS);
+
+    while let Some(row) = rows.next().await {
+        format.write_record(&mut buffer, E::COLUMNS, &row?)?;
+
+        if buffer.len() >= EXPORT_CHUNK_SIZE {
+            // The consumer is gone, so there is no one to export to
+            if sender.send(Ok(take(&mut buffer))).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    if !buffer.is_empty() {
+        sender.send(Ok(buffer)).await.ok();
+    }
+
+    Ok(())
+}
+
+/// Writes a CSV row, quoting the fields with separators, quotes or line breaks
+fn write_csv_row(buffer: &mut Vec<u8>, fields: impl Iterator<Item = String>) {
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            buffer.push(b',');
+        }
+
+        if field.contains([',', '"', '\n', '\r']) {
+            buffer.push(b'"');
+            buffer.extend_from_slice(field.replace('"', "\"\"").as_bytes());
+            buffer.push(b'"');
+        } else {
+            buffer.extend_from_slice(field.as_bytes());
+        }
+    }
+
+    buffer.push(b'\n');
+}
@@ -1,5 +1,10 @@
+mod alert;
+mod export;
 mod mutation;
 mod query;
 
+pub use alert::{AlertEntity, AlertPayload, AlertSender};
+pub use export::{export_entities, ExportFormat, Exportable};
+
 pub use mutation::EntityMutation;
-pub use query::{count_rows_per_week, get_network_id, EntityQuery};
+pub use query::{count_rows_per_bucket, count_rows_per_week, get_network_id, EntityQuery};
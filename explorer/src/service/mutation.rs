@@ -1,4 +1,5 @@
 use crate::entity::{
+    event::{self, get_event_entity},
     indexer, network,
     {types::NetworkBackend, FromPayload},
 };
@@ -7,7 +8,11 @@ use super::get_network_id;
 
 use {
     chrono::{DateTime, NaiveDateTime, Utc},
-    sea_orm::*,
+    hapi_indexer::{NetworkConfiguration, PushData, PushEvent},
+    sea_orm::{
+        sea_query::{Expr, OnConflict},
+        *,
+    },
     uuid::Uuid,
 };
 
@@ -16,7 +21,7 @@ pub struct EntityMutation;
 impl EntityMutation {
     /// Universal method for inserting entities to database
     pub async fn create_entity<M, T>(
-        db: &DbConn,
+        db: &impl ConnectionTrait,
         payload: T,
         network_id: String,
         timestamp: u64,
@@ -37,7 +42,7 @@ impl EntityMutation {
 
     /// Universal method for updating entities in database
     pub async fn update_entity<M, T>(
-        db: &DbConn,
+        db: &impl ConnectionTrait,
         payload: T,
         network_id: String,
         timestamp: u64,
@@ -56,6 +61,49 @@ impl EntityMutation {
             .await
     }
 
+    /// Records the applied event, returns false if the event was recorded before
+    pub async fn register_event(
+        db: &impl ConnectionTrait,
+        network_id: String,
+        push_event: &PushEvent,
+        data: &PushData,
+    ) -> Result<bool, DbErr> {
+        let timestamp = NaiveDateTime::from_timestamp_opt(push_event.timestamp as i64, 0)
+            .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))?;
+        let tx_index = i64::try_from(push_event.tx_index)
+            .map_err(|_| DbErr::Custom("Invalid transaction index".to_string()))?;
+        let (entity_type, entity_id, payload) = get_event_entity(&network_id, data)
+            .map_err(|e| DbErr::Custom(format!("Invalid event payload: {e}")))?;
+
+        let model = event::ActiveModel {
+            network_id: Set(network_id),
+            tx_hash: Set(push_event.tx_hash.clone()),
+            tx_index: Set(tx_index),
+            name: Set(push_event.name.to_string()),
+            timestamp: Set(timestamp),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            entity_type: Set(Some(entity_type)),
+            entity_id: Set(Some(entity_id)),
+            payload: Set(Some(payload)),
+            confirmed_by: Set(push_event.confirmed_by),
+        };
+
+        let inserted = event::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    event::Column::NetworkId,
+                    event::Column::TxHash,
+                    event::Column::TxIndex,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(db)
+            .await?;
+
+        Ok(inserted > 0)
+    }
+
     /// Method for creating network in database
     pub async fn create_network(
         db: &DbConn,
@@ -75,6 +123,7 @@ impl EntityMutation {
             stake_token: Set(stake_token),
             created_at: Set(chrono::Utc::now().naive_utc()),
             updated_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
         };
 
         model.insert(db).await
@@ -101,8 +150,51 @@ impl EntityMutation {
             stake_token,
             created_at: Set(chrono::Utc::now().naive_utc()),
             updated_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        model.update(db).await
+    }
+
+    /// Method for updating network configuration from indexed events
+    pub async fn update_network_configuration(
+        db: &impl ConnectionTrait,
+        id: String,
+        configuration: NetworkConfiguration,
+        timestamp: u64,
+    ) -> Result<network::Model, DbErr> {
+        let updated_at = NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
+            .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))?;
+
+        let mut model = network::ActiveModel {
+            id: Set(id),
+            updated_at: Set(updated_at),
+            ..Default::default()
         };
 
+        if let Some(authority) = configuration.authority {
+            model.authority = Set(authority);
+        }
+
+        if let Some(stake) = configuration.stake_configuration {
+            model.stake_token = Set(stake.token);
+            model.stake_unlock_duration = Set(Some(stake.unlock_duration.to_string()));
+            model.validator_stake = Set(Some(stake.validator_stake.to_string()));
+            model.tracer_stake = Set(Some(stake.tracer_stake.to_string()));
+            model.publisher_stake = Set(Some(stake.publisher_stake.to_string()));
+            model.authority_stake = Set(Some(stake.authority_stake.to_string()));
+        }
+
+        if let Some(reward) = configuration.reward_configuration {
+            model.reward_token = Set(Some(reward.token));
+            model.address_confirmation_reward =
+                Set(Some(reward.address_confirmation_reward.to_string()));
+            model.address_tracer_reward = Set(Some(reward.address_tracer_reward.to_string()));
+            model.asset_confirmation_reward =
+                Set(Some(reward.asset_confirmation_reward.to_string()));
+            model.asset_tracer_reward = Set(Some(reward.asset_tracer_reward.to_string()));
+        }
+
         model.update(db).await
     }
 
@@ -121,8 +213,26 @@ impl EntityMutation {
             created_at: Set(timestamp.naive_utc()),
             last_heartbeat: Set(NaiveDateTime::default()),
             cursor: Set("".to_string()),
+            token_generation: Set(0),
         }
         .insert(db)
         .await
     }
+
+    /// Invalidates all the issued tokens of the indexer
+    pub async fn increment_token_generation(
+        db: &DbConn,
+        id: Uuid,
+    ) -> Result<indexer::Model, DbErr> {
+        indexer::Entity::update_many()
+            .col_expr(
+                indexer::Column::TokenGeneration,
+                Expr::col(indexer::Column::TokenGeneration).add(1),
+            )
+            .filter(indexer::Column::Id.eq(id))
+            .exec_with_returning(db)
+            .await?
+            .pop()
+            .ok_or(DbErr::RecordNotFound(format!("Indexer {id} not found")))
+    }
 }
@@ -1,13 +1,21 @@
 use {
     async_graphql::{InputType, OutputType},
-    chrono::{Duration, NaiveDate},
-    sea_orm::{prelude::*, sea_query::Cond, PaginatorTrait},
+    chrono::{Duration, NaiveDate, NaiveDateTime},
+    hapi_core::client::events::EventName,
+    hapi_indexer::PushEvent,
+    sea_orm::{prelude::*, PaginatorTrait, QueryOrder, QuerySelect, Selector, SelectorTrait},
+    std::collections::HashMap,
 };
 
 use crate::entity::{
-    network,
-    pagination::{EntityInput, EntityPage, Paginator},
-    types::NetworkBackend,
+    event, network,
+    pagination::{
+        after_cursor, decode_cursor, encode_cursor, CursorPaginator, EntityCursorInput,
+        EntityCursorPage, EntityInput, EntityPage, Ordering, Paginator,
+    },
+    reporter::{self, ReporterStats, ReporterStatsCondition},
+    search::search_condition,
+    types::{EventEntity, NetworkBackend},
     EntityFilter,
 };
 
@@ -15,7 +23,10 @@ pub struct EntityQuery;
 
 impl EntityQuery {
     /// Universal method for fetching entity from database
-    pub async fn find_entity_by_id<M, T>(db: &DbConn, id: T) -> Result<Option<M::Model>, DbErr>
+    pub async fn find_entity_by_id<M, T>(
+        db: &impl ConnectionTrait,
+        id: T,
+    ) -> Result<Option<M::Model>, DbErr>
     where
         M: EntityTrait,
         T: Into<<M::PrimaryKey as PrimaryKeyTrait>::ValueType>,
@@ -23,46 +34,173 @@ impl EntityQuery {
         M::find_by_id(id).one(db).await
     }
 
+    /// Whether the event was already recorded as applied
+    pub async fn is_event_applied(
+        db: &impl ConnectionTrait,
+        network_id: String,
+        push_event: &PushEvent,
+    ) -> Result<bool, DbErr> {
+        let tx_index = i64::try_from(push_event.tx_index)
+            .map_err(|_| DbErr::Custom("Invalid transaction index".to_string()))?;
+
+        let event = Self::find_entity_by_id::<event::Entity, _>(
+            db,
+            (network_id, push_event.tx_hash.clone(), tx_index),
+        )
+        .await?;
+
+        Ok(event.is_some())
+    }
+
+    /// Block timestamp of the latest applied event that changed the same part of the network
+    /// configuration. Network `updated_at` is also changed by the CLI, so it can't be used instead
+    pub async fn find_configuration_updated_at(
+        db: &impl ConnectionTrait,
+        network_id: String,
+        event_name: &EventName,
+    ) -> Result<Option<NaiveDateTime>, DbErr> {
+        let names = match event_name {
+            EventName::SetAuthority => vec![EventName::Initialize, EventName::SetAuthority],
+            EventName::UpdateStakeConfiguration => {
+                vec![EventName::Initialize, EventName::UpdateStakeConfiguration]
+            }
+            EventName::UpdateRewardConfiguration => {
+                vec![EventName::Initialize, EventName::UpdateRewardConfiguration]
+            }
+            _ => vec![
+                EventName::Initialize,
+                EventName::SetAuthority,
+                EventName::UpdateStakeConfiguration,
+                EventName::UpdateRewardConfiguration,
+            ],
+        };
+
+        let event = event::Entity::find()
+            .filter(event::Column::NetworkId.eq(network_id))
+            .filter(event::Column::Name.is_in(names.iter().map(ToString::to_string)))
+            .order_by_desc(event::Column::Timestamp)
+            .one(db)
+            .await?;
+
+        Ok(event.map(|event| event.timestamp))
+    }
+
     /// Universal method for fetching entities from database
     pub async fn find_many<M>(
         db: &DbConn,
-        input: EntityInput<<M as EntityFilter>::Filter, <M as EntityFilter>::Condition>,
+        input: EntityInput<
+            <M as EntityFilter>::Filter,
+            <M as EntityFilter>::Condition,
+            <M as EntityFilter>::SearchField,
+        >,
     ) -> Result<EntityPage<M::Model>, DbErr>
     where
         M: EntityTrait + EntityFilter,
         <M as EntityFilter>::Filter: InputType,
         <M as EntityFilter>::Condition: InputType + Default,
+        <M as EntityFilter>::SearchField: InputType,
+        M::Model: OutputType,
+        M::Column: From<<M as EntityFilter>::Condition>,
+    {
+        let query = Self::select_many::<M>(input.filtering, input.search, input.search_field);
+        let query = M::order(query, input.ordering, input.ordering_condition);
+
+        Self::paginate(db, query, input.pagination).await
+    }
+
+    /// Universal method for fetching entities from database page by page after the cursor
+    pub async fn find_many_by_cursor<M>(
+        db: &DbConn,
+        input: EntityCursorInput<
+            <M as EntityFilter>::Filter,
+            <M as EntityFilter>::Condition,
+            <M as EntityFilter>::SearchField,
+        >,
+    ) -> Result<EntityCursorPage<M::Model>, DbErr>
+    where
+        M: EntityTrait + EntityFilter,
+        <M as EntityFilter>::Filter: InputType,
+        <M as EntityFilter>::Condition: InputType + Default + Copy,
+        <M as EntityFilter>::SearchField: InputType,
         M::Model: OutputType,
         M::Column: From<<M as EntityFilter>::Condition>,
+    {
+        let column = M::cursor_column(input.ordering_condition).ok_or(DbErr::Custom(
+            "Cursor is not supported for this ordering condition".to_string(),
+        ))?;
+
+        let query = Self::select_many::<M>(input.filtering, input.search, input.search_field);
+        let query = M::order(query, input.ordering, input.ordering_condition);
+
+        Self::paginate_by_cursor(db, query, input.cursor, input.ordering, column).await
+    }
+
+    /// Selects the entities matching the filter and the search value
+    fn select_many<M>(
+        filtering: Option<M::Filter>,
+        search: Option<String>,
+        search_field: Option<M::SearchField>,
+    ) -> Select<M>
+    where
+        M: EntityTrait + EntityFilter,
     {
         let mut query = M::find();
 
-        if let Some(filter) = input.filtering {
+        if let Some(filter) = filtering {
             query = M::filter(query, &filter);
         }
 
-        if let Some(search) = input.search {
-            query = Self::search(query, &search);
+        if let Some(search) = search {
+            query = Self::search(query, &search, search_field);
         }
 
-        query = M::order(query, input.ordering, input.ordering_condition);
+        query
+    }
 
-        Self::paginate(db, query, input.pagination).await
+    /// Method for fetching events applied to the entity, latest first
+    pub async fn find_history(
+        db: &DbConn,
+        network_id: String,
+        entity_type: EventEntity,
+        entity_id: String,
+        pagination: Option<Paginator>,
+    ) -> Result<EntityPage<event::Model>, DbErr> {
+        let query = event::Entity::find()
+            .filter(event::Column::NetworkId.eq(network_id))
+            .filter(event::Column::EntityType.eq(entity_type))
+            .filter(event::Column::EntityId.eq(entity_id))
+            .order_by_desc(event::Column::Timestamp)
+            .order_by_desc(event::Column::TxIndex);
+
+        Self::paginate(db, query, pagination).await
+    }
+
+    /// Method for fetching reporters with their activity metrics
+    pub async fn find_reporter_stats(
+        db: &DbConn,
+        input: EntityInput<
+            <reporter::Entity as EntityFilter>::Filter,
+            ReporterStatsCondition,
+            <reporter::Entity as EntityFilter>::SearchField,
+        >,
+    ) -> Result<EntityPage<ReporterStats>, DbErr> {
+        let query = Self::select_many::<reporter::Entity>(
+            input.filtering,
+            input.search,
+            input.search_field,
+        );
+
+        let query =
+            reporter::select_reporter_stats(query, input.ordering, input.ordering_condition);
+
+        Self::paginate_models(db, query, input.pagination).await
     }
 
-    fn search<M>(query: Select<M>, value: &str) -> Select<M>
+    fn search<M>(query: Select<M>, value: &str, field: Option<M::SearchField>) -> Select<M>
     where
         M: EntityTrait + EntityFilter,
     {
-        let value = &format!("%{}%", value);
-        let columns = M::columns_for_search();
-        let mut cond = Cond::any();
-
-        for column in columns {
-            cond = cond.add(Expr::cust(column).like(value));
-        }
-
-        query.filter(cond)
+        query.filter(search_condition::<M, _>(value, field))
     }
 
     /// Method for query pagination
@@ -74,6 +212,19 @@ impl EntityQuery {
     where
         M: EntityTrait,
         M::Model: OutputType,
+    {
+        Self::paginate_models(db, query.into_model(), pagination).await
+    }
+
+    /// Method for pagination of queries into custom models
+    async fn paginate_models<S>(
+        db: &DbConn,
+        query: Selector<S>,
+        pagination: Option<Paginator>,
+    ) -> Result<EntityPage<S::Item>, DbErr>
+    where
+        S: SelectorTrait + Send + Sync,
+        S::Item: OutputType,
     {
         let page = if let Some(pagination) = pagination {
             let paginator = query.paginate(db, pagination.page_size);
@@ -101,6 +252,64 @@ impl EntityQuery {
 
         Ok(page)
     }
+
+    /// Method for keyset pagination after the cursor, ordered by the column and primary key
+    async fn paginate_by_cursor<M>(
+        db: &DbConn,
+        query: Select<M>,
+        cursor: CursorPaginator,
+        ordering: Option<Ordering>,
+        column: M::Column,
+    ) -> Result<EntityCursorPage<M::Model>, DbErr>
+    where
+        M: EntityTrait,
+        M::Model: OutputType,
+    {
+        if cursor.page_size == 0 {
+            return Err(DbErr::Custom("Page size must be positive".to_string()));
+        }
+
+        let ordering = ordering.unwrap_or_default();
+        let columns: Vec<M::Column> = std::iter::once(column)
+            .chain(M::PrimaryKey::iter().map(|key| key.into_column()))
+            .collect();
+
+        // Primary key makes the order unique, so no rows are skipped or repeated between pages
+        let mut query = M::PrimaryKey::iter().fold(query, |query, key| match ordering {
+            Ordering::Asc => query.order_by_asc(key.into_column()),
+            Ordering::Desc => query.order_by_desc(key.into_column()),
+        });
+
+        let total = if cursor.skip_total {
+            None
+        } else {
+            Some(query.clone().count(db).await?)
+        };
+
+        if let Some(after) = &cursor.after {
+            let values = decode_cursor::<M>(after, &columns)?;
+            query = query.filter(after_cursor(&columns, &values, ordering));
+        }
+
+        let mut data = query.limit(cursor.page_size + 1).all(db).await?;
+
+        let next_cursor = if data.len() as u64 > cursor.page_size {
+            data.truncate(cursor.page_size as usize);
+            data.last()
+                .map(|model| encode_cursor::<M>(model, &columns))
+                .transpose()?
+        } else {
+            None
+        };
+
+        Ok(EntityCursorPage {
+            data,
+            total,
+            page_count: total
+                .map(|total| total / cursor.page_size + u64::from(total % cursor.page_size != 0)),
+            next_cursor,
+        })
+    }
 }
 
 pub async fn get_network_id(
@@ -149,3 +358,32 @@ where
 
     Ok(count)
 }
+
+/// Counts rows created in each bucket of the given `date_trunc` unit with a single grouped query
+pub async fn count_rows_per_bucket<M>(
+    db: &DbConn,
+    query: Select<M>,
+    unit: &str,
+    buckets: &[NaiveDate],
+) -> Result<Vec<u64>, DbErr>
+where
+    M: EntityTrait,
+{
+    let bucket = Expr::cust(format!("DATE(DATE_TRUNC('{unit}', created_at))"));
+
+    let counts: HashMap<NaiveDate, i64> = query
+        .select_only()
+        .column_as(bucket.clone(), "bucket")
+        .column_as(Expr::cust("COUNT(*)"), "count")
+        .group_by(bucket)
+        .into_tuple()
+        .all(db)
+        .await?
+        .into_iter()
+        .collect();
+
+    Ok(buckets
+        .iter()
+        .map(|bucket| counts.get(bucket).copied().unwrap_or_default() as u64)
+        .collect())
+}
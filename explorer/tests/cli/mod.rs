@@ -1,12 +1,16 @@
 use {
     hapi_explorer::{
-        entity::{indexer, network, types::NetworkBackend},
+        entity::{
+            indexer, network, reporter,
+            types::{NetworkBackend, ReporterRole, ReporterStatus},
+        },
         migrations::Migrator,
     },
-    sea_orm::{Database, DatabaseConnection, EntityTrait},
+    sea_orm::{ActiveModelTrait, Database, DatabaseConnection, EntityTrait, Set},
     sea_orm_migration::MigratorTrait,
-    std::{env, process::Command},
+    std::{env, fs, process::Command},
     tokio::time::{sleep, Duration},
+    uuid::Uuid,
 };
 
 use crate::helpers::{RequestSender, MIGRATION_COUNT, WAITING_INTERVAL};
@@ -215,4 +219,134 @@ async fn indexer_command_test() {
 
     let indexer = indexers.first().unwrap();
     assert_eq!(indexer.network_id, id);
+    assert_eq!(indexer.token_generation, 0);
+
+    for subcommand in ["list", "rotate", "revoke"] {
+        let mut args = vec!["indexer", subcommand];
+        let indexer_id = indexer.id.to_string();
+
+        if subcommand != "list" {
+            args.extend(["--id", &indexer_id]);
+        }
+
+        let output = Command::new("./target/debug/hapi-explorer")
+            .args(args)
+            .output()
+            .expect("Failed to run indexer command");
+
+        assert!(output.status.success());
+        assert!(output.stderr.is_empty());
+
+        sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+    }
+
+    let indexer = indexer::Entity::find_by_id(indexer.id)
+        .one(&db)
+        .await
+        .expect("Failed to find indexer")
+        .expect("Indexer does not exist");
+
+    // Both rotation and revocation invalidate the issued tokens
+    assert_eq!(indexer.token_generation, 2);
+}
+
+#[tokio::test]
+async fn export_command_test() {
+    let db = setup().await;
+
+    let network_id = String::from("test_network");
+    let backend = NetworkBackend::Solana;
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args([
+            "network",
+            "create",
+            "--id",
+            &network_id,
+            "--name",
+            "Test Network",
+            "--backend",
+            &backend.to_string(),
+            "--authority",
+            "Test Authority",
+            "--stake-token",
+            "Test Stake Token",
+        ])
+        .output()
+        .expect("Failed to create network");
+
+    assert!(output.status.success());
+
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let now = chrono::Utc::now().naive_utc();
+    let reporter = reporter::ActiveModel {
+        network_id: Set(network_id.clone()),
+        id: Set(Uuid::new_v4()),
+        account: Set(String::from("Test Account")),
+        role: Set(ReporterRole::Publisher),
+        status: Set(ReporterStatus::Active),
+        name: Set(String::from("Reporter, with a comma")),
+        url: Set(String::from("https://reporter.blockchain")),
+        stake: Set(String::from("1000")),
+        unlock_timestamp: Set(String::from("0")),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(&db)
+    .await
+    .expect("Failed to create reporter");
+
+    let output_path = env::temp_dir().join("hapi-explorer-reporters.csv");
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args([
+            "export",
+            "--format",
+            "csv",
+            "--output",
+            output_path.to_str().unwrap(),
+            "reporters",
+            "--network-id",
+            &network_id,
+            "--role",
+            "Publisher",
+        ])
+        .output()
+        .expect("Failed to export reporters");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    let export = fs::read_to_string(&output_path).expect("Failed to read export");
+    let lines = export.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("network_id,id,account,role,status,name"));
+    assert!(lines[1].starts_with(&format!(
+        "{},{},{},Publisher,Active,\"Reporter, with a comma\"",
+        network_id, reporter.id, reporter.account
+    )));
+
+    let output_path = env::temp_dir().join("hapi-explorer-reporters.ndjson");
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args([
+            "export",
+            "--format",
+            "ndjson",
+            "--output",
+            output_path.to_str().unwrap(),
+            "reporters",
+            "--role",
+            "Validator",
+        ])
+        .output()
+        .expect("Failed to export reporters");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    let export = fs::read_to_string(&output_path).expect("Failed to read export");
+    assert!(export.is_empty());
 }
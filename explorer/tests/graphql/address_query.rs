@@ -1,5 +1,7 @@
 use super::replacer;
-use crate::helpers::{FromTestPayload, RequestSender, TestApp, TestData};
+use crate::helpers::{
+    create_confirm_data, get_test_data, FromTestPayload, RequestSender, TestApp, TestData,
+};
 
 use {
     hapi_core::client::{entities::address::Address, events::EventName},
@@ -45,6 +47,30 @@ const GET_MANY_ADDRESSES: &str = "
         }
     }
 ";
+const GET_MANY_ADDRESSES_BY_CURSOR: &str = "
+    query GetManyAddressesByCursor(
+        $input: AddressCursorInput!
+    ) {
+        getManyAddressesByCursor(
+            input: $input
+        ) {
+            data {
+                networkId
+                address
+                caseId
+                reporterId
+                risk
+                category
+                confirmations
+                createdAt
+                updatedAt
+            }
+            total
+            pageCount
+            nextCursor
+        }
+    }
+";
 
 impl FromTestPayload for TestData<Address> {
     fn from_payload(payload: &PushPayload, network_id: &str) -> TestData<Address> {
@@ -98,6 +124,56 @@ async fn get_address_test() {
         let address = &response["getAddress"];
         check_address(&payload, address);
     }
+
+    // Address invalid on the network is not found instead of failing the query
+    let network = test_app.networks.first().expect("No test networks");
+    let response = sender
+        .send_graphql(
+            GET_ADDRESS_QUERY,
+            json!({
+                "address": "scammer.near",
+                "networkId": network.model.id
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["getAddress"], Value::Null);
+}
+
+#[tokio::test]
+async fn confirm_address_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+
+        let update = test_data
+            .iter()
+            .rfind(|payload| payload.event.name == EventName::UpdateAddress)
+            .expect("No address update");
+        let confirm = create_confirm_data(update);
+        test_app.send_events(&sender, &vec![confirm.clone()]).await;
+
+        let updated = TestData::<Address>::from_payload(update, &network.model.id);
+        let confirmed = TestData::<Address>::from_payload(&confirm, &network.model.id);
+        assert_ne!(updated.data.confirmations, confirmed.data.confirmations);
+
+        let response = sender
+            .send_graphql(
+                GET_ADDRESS_QUERY,
+                json!({
+                    "address": confirmed.data.address,
+                    "networkId": confirmed.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        check_address(&confirmed, &response["getAddress"]);
+    }
 }
 
 #[tokio::test]
@@ -216,6 +292,82 @@ async fn get_paginated_addresses_test() {
     check_address(&payload, addresses.last().unwrap())
 }
 
+#[tokio::test]
+async fn get_cursor_paginated_addresses_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+
+    let page_size = 2;
+    let mut cursor = Value::Null;
+    let mut pages = vec![];
+
+    loop {
+        let response = sender
+            .send_graphql(
+                GET_MANY_ADDRESSES_BY_CURSOR,
+                json!({
+                "input":
+                {
+                    "ordering": "ASC",
+                    "cursor": {
+                        "after": cursor,
+                        "pageSize": page_size,
+                        "skipTotal": !pages.is_empty()
+                    }
+                }
+                }),
+            )
+            .await
+            .unwrap();
+
+        let addresses_response = &response["getManyAddressesByCursor"];
+        if pages.is_empty() {
+            assert_eq!(addresses_response["total"], addresses.len());
+        } else {
+            assert_eq!(addresses_response["total"], Value::Null);
+        }
+
+        pages.push(addresses_response["data"].clone());
+
+        cursor = addresses_response["nextCursor"].clone();
+        if cursor.is_null() {
+            break;
+        }
+    }
+
+    assert_eq!(pages.len(), (addresses.len() + page_size - 1) / page_size);
+
+    let data = pages
+        .iter()
+        .flat_map(|page| page.as_array().expect("Empty response").clone())
+        .collect::<Vec<Value>>();
+
+    assert_eq!(data.len(), addresses.len());
+    for (payload, address) in addresses.iter().zip(data.iter()) {
+        check_address(payload, address)
+    }
+
+    let error = sender
+        .send_graphql(
+            GET_MANY_ADDRESSES_BY_CURSOR,
+            json!({
+            "input":
+            {
+                "cursor": {
+                    "after": "invalid",
+                    "pageSize": page_size
+                }
+            }
+            }),
+        )
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Invalid cursor"));
+}
+
 #[tokio::test]
 async fn get_searched_addresses_test() {
     let test_app = TestApp::start().await;
@@ -252,3 +404,87 @@ async fn get_searched_addresses_test() {
         check_address(&payload, address)
     }
 }
+
+#[tokio::test]
+async fn get_field_searched_addresses_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+
+    let payload = addresses.first().expect("Empty test data");
+    let address = &payload.data.address;
+
+    let same_prefix = addresses
+        .iter()
+        .filter(|a| a.data.address.starts_with(&address[0..8]))
+        .count();
+
+    // Addresses are matched by a case-insensitive prefix only
+    for (search, total) in [
+        (address[0..8].to_uppercase(), same_prefix),
+        (address[2..10].to_string(), 0),
+    ] {
+        let response = sender
+            .send_graphql(
+                GET_MANY_ADDRESSES,
+                json!({
+                "input":
+                {
+                    "search": search,
+                    "searchField": "ADDRESS",
+                }
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response["getManyAddresses"]["total"], total);
+    }
+
+    // Category and risk are not searchable
+    let response = sender
+        .send_graphql(
+            GET_MANY_ADDRESSES,
+            json!({
+            "input":
+            {
+                "search": "de_fi",
+            }
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["getManyAddresses"]["total"], 0);
+
+    for payload in addresses {
+        let case_id = payload.data.case_id.to_string();
+
+        let response = sender
+            .send_graphql(
+                GET_MANY_ADDRESSES,
+                json!({
+                "input":
+                {
+                    "search": &case_id[0..13],
+                    "searchField": "CASE_ID",
+                }
+                }),
+            )
+            .await
+            .unwrap();
+
+        let addresses_response = &response["getManyAddresses"];
+        assert_eq!(addresses_response["total"], 1);
+
+        let address = addresses_response["data"]
+            .as_array()
+            .expect("Empty response")
+            .first()
+            .unwrap();
+
+        check_address(&payload, address)
+    }
+}
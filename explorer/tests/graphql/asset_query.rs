@@ -1,5 +1,7 @@
 use super::replacer;
-use crate::helpers::{FromTestPayload, RequestSender, TestApp, TestData};
+use crate::helpers::{
+    create_confirm_data, get_test_data, FromTestPayload, RequestSender, TestApp, TestData,
+};
 
 use {
     hapi_core::client::{entities::asset::Asset, events::EventName},
@@ -104,6 +106,42 @@ async fn get_asset_test() {
     }
 }
 
+#[tokio::test]
+async fn confirm_asset_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+
+        let update = test_data
+            .iter()
+            .rfind(|payload| payload.event.name == EventName::UpdateAsset)
+            .expect("No asset update");
+        let confirm = create_confirm_data(update);
+        test_app.send_events(&sender, &vec![confirm.clone()]).await;
+
+        let updated = TestData::<Asset>::from_payload(update, &network.model.id);
+        let confirmed = TestData::<Asset>::from_payload(&confirm, &network.model.id);
+        assert_ne!(updated.data.confirmations, confirmed.data.confirmations);
+
+        let response = sender
+            .send_graphql(
+                GET_ASSET_QUERY,
+                json!({
+                    "address": confirmed.data.address,
+                    "id": confirmed.data.asset_id,
+                    "networkId": confirmed.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        check_asset(&confirmed, &response["getAsset"]);
+    }
+}
+
 #[tokio::test]
 async fn get_many_assets_test() {
     let test_app = TestApp::start().await;
@@ -23,6 +23,31 @@ const GET_CASE_QUERY: &str = "
         }
     }
 ";
+const GET_CASE_RELATIONS_QUERY: &str = "
+    query GetCaseRelations($id: UUID!, $networkId: String!) {
+        getCase(id: $id, networkId: $networkId) {
+            id
+            reporter {
+                id
+                cases {
+                    id
+                }
+            }
+            addresses {
+                address
+                case {
+                    id
+                }
+            }
+            assets {
+                address
+                reporter {
+                    id
+                }
+            }
+        }
+    }
+";
 const GET_MANY_CASES: &str = "
     query GetManyCases(
         $input: CaseInput!
@@ -99,6 +124,43 @@ async fn get_case_test() {
     }
 }
 
+#[tokio::test]
+async fn get_case_relations_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let cases = test_app
+        .global_setup::<Case>(&sender, EventName::UpdateCase)
+        .await;
+
+    for payload in cases {
+        let response = sender
+            .send_graphql(
+                GET_CASE_RELATIONS_QUERY,
+                json!({
+                    "id": payload.data.id,
+                    "networkId": payload.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let case = &response["getCase"];
+        let case_id = payload.data.id.to_string();
+        let reporter_id = payload.data.reporter_id.to_string();
+
+        assert_eq!(case["reporter"]["id"], reporter_id);
+        assert_eq!(case["reporter"]["cases"][0]["id"], case_id);
+
+        let addresses = case["addresses"].as_array().expect("Empty addresses");
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses[0]["case"]["id"], case_id);
+
+        let assets = case["assets"].as_array().expect("Empty assets");
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0]["reporter"]["id"], reporter_id);
+    }
+}
+
 #[tokio::test]
 async fn get_many_cases_test() {
     let test_app = TestApp::start().await;
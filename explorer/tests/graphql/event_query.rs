@@ -0,0 +1,155 @@
+use crate::helpers::{
+    create_confirm_data, get_test_data, FromTestPayload, RequestSender, TestApp, TestData,
+};
+
+use {
+    hapi_core::client::{
+        entities::{address::Address, reporter::Reporter},
+        events::EventName,
+    },
+    serde_json::{json, Value},
+};
+
+const GET_ADDRESS_HISTORY_QUERY: &str = "
+    query GetAddressHistory($address: String!, $networkId: String!) {
+        getAddressHistory(address: $address, networkId: $networkId) {
+            data {
+                networkId
+                txHash
+                txIndex
+                name
+                timestamp
+                entityType
+                entityId
+                payload
+            }
+            total
+            pageCount
+        }
+    }
+";
+const GET_REPORTER_HISTORY_QUERY: &str = "
+    query GetReporterHistory($id: UUID!, $networkId: String!) {
+        getReporterHistory(id: $id, networkId: $networkId) {
+            data {
+                networkId
+                txHash
+                name
+                entityType
+                entityId
+                payload
+            }
+            total
+            pageCount
+        }
+    }
+";
+
+fn get_event_names(history: &Value) -> Vec<String> {
+    let mut names: Vec<String> = history["data"]
+        .as_array()
+        .expect("Empty response")
+        .iter()
+        .map(|event| event["name"].as_str().expect("Invalid name").to_string())
+        .collect();
+
+    names.sort();
+    names
+}
+
+/*
+Test cases:
+ - address history contains every applied event with its snapshot
+ - reporter history contains every applied event
+ */
+#[tokio::test]
+async fn get_address_history_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let mut addresses: Vec<TestData<Address>> = vec![];
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+
+        let update = test_data
+            .iter()
+            .rfind(|payload| payload.event.name == EventName::UpdateAddress)
+            .expect("No address update");
+        let confirm = create_confirm_data(update);
+        test_app.send_events(&sender, &vec![confirm.clone()]).await;
+
+        addresses.push(TestData::<Address>::from_payload(
+            &confirm,
+            &network.model.id,
+        ));
+    }
+
+    for payload in addresses {
+        let response = sender
+            .send_graphql(
+                GET_ADDRESS_HISTORY_QUERY,
+                json!({
+                    "address": payload.data.address,
+                    "networkId": payload.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let history = &response["getAddressHistory"];
+        assert_eq!(history["total"], 3);
+        assert_eq!(
+            get_event_names(history),
+            vec!["confirm_address", "create_address", "update_address"]
+        );
+
+        for event in history["data"].as_array().expect("Empty response") {
+            assert_eq!(event["networkId"], payload.network_id);
+            assert_eq!(event["entityType"], "ADDRESS");
+            assert_eq!(event["entityId"], payload.data.address);
+            assert_eq!(event["payload"]["address"], payload.data.address);
+            assert!(!event["txHash"].as_str().unwrap_or_default().is_empty());
+        }
+    }
+}
+
+#[tokio::test]
+async fn get_reporter_history_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let reporters: Vec<TestData<Reporter>> = test_app
+        .global_setup::<Reporter>(&sender, EventName::CreateReporter)
+        .await;
+
+    for payload in reporters {
+        let response = sender
+            .send_graphql(
+                GET_REPORTER_HISTORY_QUERY,
+                json!({
+                    "id": payload.data.id,
+                    "networkId": payload.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let history = &response["getReporterHistory"];
+        assert_eq!(history["total"], 5);
+        assert_eq!(
+            get_event_names(history),
+            vec![
+                "activate_reporter",
+                "create_reporter",
+                "deactivate_reporter",
+                "unstake",
+                "update_reporter"
+            ]
+        );
+
+        for event in history["data"].as_array().expect("Empty response") {
+            assert_eq!(event["entityType"], "REPORTER");
+            assert_eq!(event["entityId"], payload.data.id.to_string());
+        }
+    }
+}
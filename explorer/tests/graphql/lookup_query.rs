@@ -0,0 +1,109 @@
+use crate::helpers::{RequestSender, TestApp};
+
+use {
+    hapi_core::client::{
+        entities::{address::Address, asset::Asset},
+        events::EventName,
+    },
+    serde_json::json,
+};
+
+// EIP-55 form of the test EVM address
+const CHECKSUMMED_ADDRESS: &str = "0x9e833A87087EfD527b1a842742eB0F3548CD82Ab";
+
+const LOOKUP_ADDRESS_QUERY: &str = "
+    query LookupAddress($address: String!) {
+        lookupAddress(address: $address) {
+            address
+            risk
+            category
+            addresses {
+                networkId
+                address
+                risk
+            }
+            assets {
+                networkId
+                address
+                id
+            }
+        }
+    }
+";
+
+#[tokio::test]
+async fn lookup_address_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+
+    let payload = addresses.first().expect("Empty test data");
+    let same_address = addresses
+        .iter()
+        .filter(|address| address.data.address == payload.data.address)
+        .collect::<Vec<_>>();
+
+    let response = sender
+        .send_graphql(
+            LOOKUP_ADDRESS_QUERY,
+            json!({ "address": CHECKSUMMED_ADDRESS }),
+        )
+        .await
+        .unwrap();
+
+    let lookup = &response["lookupAddress"];
+    assert_eq!(lookup["address"], CHECKSUMMED_ADDRESS);
+    assert_eq!(lookup["risk"], payload.data.risk);
+    assert_eq!(lookup["category"], "SANCTIONS");
+    assert_eq!(lookup["assets"], json!([]));
+
+    let matches = lookup["addresses"].as_array().expect("Empty response");
+    assert_eq!(matches.len(), same_address.len());
+    for address in same_address {
+        assert!(matches
+            .iter()
+            .any(|value| value["networkId"] == address.network_id
+                && value["address"] == payload.data.address));
+    }
+
+    let response = sender
+        .send_graphql(LOOKUP_ADDRESS_QUERY, json!({ "address": "unknown" }))
+        .await
+        .unwrap();
+
+    assert_eq!(response["lookupAddress"]["risk"], json!(null));
+    assert_eq!(response["lookupAddress"]["addresses"], json!([]));
+}
+
+#[tokio::test]
+async fn lookup_asset_address_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let assets = test_app
+        .global_setup::<Asset>(&sender, EventName::UpdateAsset)
+        .await;
+
+    let payload = assets.first().expect("Empty test data");
+    let same_address = assets
+        .iter()
+        .filter(|asset| asset.data.address == payload.data.address)
+        .count();
+
+    let response = sender
+        .send_graphql(
+            LOOKUP_ADDRESS_QUERY,
+            json!({ "address": payload.data.address }),
+        )
+        .await
+        .unwrap();
+
+    let lookup = &response["lookupAddress"];
+    assert_eq!(lookup["risk"], payload.data.risk);
+    assert_eq!(lookup["addresses"], json!([]));
+    assert_eq!(
+        lookup["assets"].as_array().expect("Empty response").len(),
+        same_address
+    );
+}
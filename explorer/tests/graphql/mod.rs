@@ -1,9 +1,14 @@
 mod address_query;
 mod asset_query;
 mod case_query;
+mod event_query;
+mod lookup_query;
 mod network_query;
 mod reporter_query;
+mod screening_query;
 mod statistics_query;
+mod subscription_query;
+mod watchlist_query;
 
 pub use case_query::check_case;
 
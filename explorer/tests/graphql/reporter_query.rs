@@ -1,5 +1,8 @@
 use super::replacer;
-use crate::helpers::{FromTestPayload, RequestSender, TestApp, TestData};
+use crate::helpers::{
+    create_address_data, create_confirm_data, create_reporter_data, get_test_data, FromTestPayload,
+    RequestSender, TestApp, TestData,
+};
 
 use {
     hapi_core::client::{entities::reporter::Reporter, events::EventName},
@@ -49,6 +52,32 @@ const GET_MANY_REPORTERS: &str = "
         }
     }
 ";
+const GET_REPORTER_STATS: &str = "
+    query GetReporterStats(
+        $input: ReporterStatsInput!
+    ) {
+        getReporterStats(
+            input: $input
+        ) {
+            data {
+                networkId
+                id
+                account
+                name
+                status
+                stake
+                caseCount
+                addressCount
+                assetCount
+                confirmationsReceived
+                confirmationsGiven
+                lastActivity
+            }
+            total
+            pageCount
+        }
+    }
+";
 
 impl FromTestPayload for TestData<Reporter> {
     fn from_payload(payload: &PushPayload, network_id: &str) -> TestData<Reporter> {
@@ -262,3 +291,147 @@ async fn get_searched_reporters_test() {
         check_reporter(&payload, reporter)
     }
 }
+
+#[tokio::test]
+async fn get_reporter_stats_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let network_data = test_app
+        .networks
+        .iter()
+        .map(|network| get_test_data(&network.network, network.model.chain_id.clone()))
+        .collect::<Vec<_>>();
+
+    for test_data in &network_data {
+        test_app.send_events(&sender, test_data).await;
+    }
+
+    // The reporter of the first network gets two more addresses
+    let network = test_app.networks.first().expect("No test networks");
+    let test_data = network_data.first().expect("No test data");
+    let (reporter_id, case_id) = test_data
+        .iter()
+        .fold((None, None), |ids, payload| match &payload.data {
+            PushData::Reporter(reporter) => (Some(reporter.id), ids.1),
+            PushData::Case(case) => (ids.0, Some(case.id)),
+            _ => ids,
+        });
+    let (reporter_id, case_id) = (reporter_id.unwrap(), case_id.unwrap());
+
+    let address_data = (0..2)
+        .map(|_| {
+            create_address_data(
+                reporter_id,
+                case_id,
+                &network.network,
+                network.model.chain_id.clone(),
+            )
+        })
+        .collect::<Vec<PushPayload>>();
+    test_app.send_events(&sender, &address_data).await;
+
+    // Another reporter of the first network confirms one of these addresses
+    let validator = create_reporter_data(&network.network, network.model.chain_id.clone());
+    let validator_id = match &validator.data {
+        PushData::Reporter(reporter) => reporter.id,
+        _ => panic!("Invalid reporter payload"),
+    };
+
+    let mut confirm = create_confirm_data(&address_data[0]);
+    confirm.event.confirmed_by = Some(validator_id);
+    test_app
+        .send_events(&sender, &vec![validator, confirm])
+        .await;
+
+    let response = sender
+        .send_graphql(
+            GET_REPORTER_STATS,
+            json!({
+            "input":
+            {
+                "ordering": "DESC",
+                "orderingCondition": "ADDRESS_COUNT",
+                "pagination": {
+                    "pageNum": 1,
+                    "pageSize": 1
+                }
+            }
+            }),
+        )
+        .await
+        .unwrap();
+
+    let stats_response = &response["getReporterStats"];
+    assert_eq!(stats_response["total"], test_app.networks.len() + 1);
+    assert_eq!(stats_response["pageCount"], test_app.networks.len() + 1);
+
+    let stats = stats_response["data"]
+        .as_array()
+        .expect("Empty response")
+        .first()
+        .unwrap();
+
+    assert_eq!(stats["networkId"], network.model.id);
+    assert_eq!(stats["id"], reporter_id.to_string());
+    assert_eq!(stats["caseCount"], 1);
+    assert_eq!(stats["addressCount"], 3);
+    assert_eq!(stats["assetCount"], 1);
+    assert_eq!(stats["confirmationsReceived"], 1);
+    assert_eq!(stats["confirmationsGiven"], 0);
+
+    let response = sender
+        .send_graphql(
+            GET_REPORTER_STATS,
+            json!({
+            "input":
+            {
+                "filtering": {
+                    "networkId": network.model.id,
+                },
+                "ordering": "DESC",
+                "orderingCondition": "CONFIRMATIONS_GIVEN",
+            }
+            }),
+        )
+        .await
+        .unwrap();
+
+    let stats = response["getReporterStats"]["data"]
+        .as_array()
+        .expect("Empty response")
+        .first()
+        .unwrap();
+
+    assert_eq!(stats["id"], validator_id.to_string());
+    assert_eq!(stats["confirmationsGiven"], 1);
+    assert_eq!(stats["addressCount"], 0);
+
+    let response = sender
+        .send_graphql(
+            GET_REPORTER_STATS,
+            json!({
+            "input":
+            {
+                "filtering": {
+                    "networkId": test_app.networks.last().unwrap().model.id,
+                },
+                "orderingCondition": "STAKE",
+            }
+            }),
+        )
+        .await
+        .unwrap();
+
+    let stats_response = &response["getReporterStats"];
+    assert_eq!(stats_response["total"], 1);
+
+    let stats = stats_response["data"]
+        .as_array()
+        .expect("Empty response")
+        .first()
+        .unwrap();
+
+    assert_eq!(stats["addressCount"], 1);
+    assert!(stats["lastActivity"].is_string());
+}
@@ -0,0 +1,90 @@
+use crate::helpers::{RequestSender, TestApp};
+
+use {
+    hapi_core::client::{entities::address::Address, events::EventName},
+    serde_json::json,
+};
+
+const SCREEN_ADDRESSES_QUERY: &str = "
+    query ScreenAddresses($targets: [ScreeningTarget!]!, $threshold: Int!) {
+        screenAddresses(targets: $targets, threshold: $threshold) {
+            networkId
+            address
+            verdict
+            matches {
+                networkId
+                category
+                risk
+                confirmations
+                caseId
+                case {
+                    id
+                    name
+                }
+            }
+        }
+    }
+";
+
+#[tokio::test]
+async fn screen_addresses_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+
+    let payload = addresses.first().expect("Empty test data");
+    let targets = json!([
+        { "networkId": payload.network_id, "address": payload.data.address },
+        { "address": payload.data.address },
+        { "networkId": payload.network_id, "address": "unknown" },
+    ]);
+
+    let response = sender
+        .send_graphql(
+            SCREEN_ADDRESSES_QUERY,
+            json!({ "targets": targets, "threshold": payload.data.risk }),
+        )
+        .await
+        .unwrap();
+
+    let results = response["screenAddresses"]
+        .as_array()
+        .expect("Empty response");
+    assert_eq!(results.len(), 3);
+
+    assert_eq!(results[0]["verdict"], "FLAGGED");
+    assert_eq!(results[0]["networkId"], payload.network_id);
+
+    let matches = results[0]["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0]["risk"], payload.data.risk);
+    assert_eq!(matches[0]["caseId"], payload.data.case_id.to_string());
+    assert_eq!(matches[0]["case"]["id"], payload.data.case_id.to_string());
+
+    let same_address = addresses
+        .iter()
+        .filter(|address| address.data.address == payload.data.address)
+        .count();
+
+    assert_eq!(results[1]["verdict"], "FLAGGED");
+    assert_eq!(
+        results[1]["matches"].as_array().unwrap().len(),
+        same_address
+    );
+
+    assert_eq!(results[2]["verdict"], "UNKNOWN");
+    assert_eq!(results[2]["matches"], json!([]));
+
+    let response = sender
+        .send_graphql(
+            SCREEN_ADDRESSES_QUERY,
+            json!({ "targets": targets, "threshold": payload.data.risk + 1 }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["screenAddresses"][0]["verdict"], "CLEAR");
+    assert_eq!(response["screenAddresses"][1]["verdict"], "CLEAR");
+}
@@ -1,15 +1,16 @@
 use {
-    chrono::{Datelike, Utc},
+    chrono::{Datelike, Duration, Utc},
     hapi_core::client::{entities::case::Case, events::EventName},
     hapi_explorer::entity::statistics::CHART_LENGTH,
     hapi_indexer::{PushData, PushPayload},
-    serde_json::Value,
+    serde_json::{json, Value},
     web3::types::U256,
 };
 
 use super::check_case;
 use crate::helpers::{
-    create_address_data, create_asset_data, create_reporter_data, RequestSender, TestApp,
+    create_address_data, create_asset_data, create_reporter_data, get_test_data, RequestSender,
+    TestApp,
 };
 
 const GET_DASHBOARD_QUERY: &str = "
@@ -58,8 +59,8 @@ const GET_DASHBOARD_QUERY: &str = "
     }
 ";
 const GET_CHARTS_QUERY: &str = "
-    query GetCharts {
-        getCharts {
+    query GetCharts($filter: StatisticsFilter, $granularity: ChartGranularity) {
+        getCharts(filter: $filter, granularity: $granularity) {
             labels
             addresses
             assets
@@ -67,6 +68,31 @@ const GET_CHARTS_QUERY: &str = "
         }
     }
 ";
+const GET_BREAKDOWN_QUERY: &str = "
+    query GetBreakdown($filter: StatisticsFilter, $category: Category, $minRisk: Int) {
+        getBreakdown(filter: $filter, category: $category, minRisk: $minRisk) {
+            addresses {
+                networkId
+                category
+                risk
+                count
+                reporterCount
+            }
+            assets {
+                networkId
+                category
+                risk
+                count
+                reporterCount
+            }
+            reporters {
+                networkId
+                status
+                count
+            }
+        }
+    }
+";
 
 #[tokio::test]
 async fn dashboard_statistics_test() {
@@ -197,7 +223,7 @@ async fn charts_statistics_test() {
     let current_year = now.iso_week().year();
     let current_week = now.iso_week().week();
 
-    let past = now - chrono::Duration::weeks(CHART_LENGTH as i64 - 1);
+    let past = now - Duration::weeks(CHART_LENGTH as i64 - 1);
 
     let past_year = past.iso_week().year();
     let past_week = past.iso_week().week();
@@ -237,3 +263,149 @@ async fn charts_statistics_test() {
     assert_eq!(cases.first().unwrap().as_u64().unwrap(), 0);
     assert_eq!(cases.last().unwrap().as_u64().unwrap(), entities_count);
 }
+
+#[tokio::test]
+async fn charts_granularity_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let cases = test_app
+        .global_setup::<Case>(&sender, EventName::UpdateCase)
+        .await;
+
+    let network = test_app.networks.first().expect("No test networks");
+    let network_cases = cases
+        .iter()
+        .filter(|case| case.network_id == network.model.id)
+        .count() as u64;
+
+    let today = Utc::now().date_naive();
+
+    let response = sender
+        .send_graphql(
+            GET_CHARTS_QUERY,
+            json!({
+                "filter": { "networkId": network.model.id },
+                "granularity": "DAY"
+            }),
+        )
+        .await
+        .unwrap();
+
+    let charts_response = &response["getCharts"];
+    let labels = charts_response["labels"]
+        .as_array()
+        .expect("Empty response");
+
+    assert_eq!(labels.len(), CHART_LENGTH);
+    assert_eq!(
+        labels.first().unwrap().as_str().unwrap(),
+        (today - Duration::days(CHART_LENGTH as i64 - 1))
+            .format("%Y-%m-%d")
+            .to_string()
+    );
+    assert_eq!(
+        labels.last().unwrap().as_str().unwrap(),
+        today.format("%Y-%m-%d").to_string()
+    );
+
+    let chart_cases = charts_response["cases"].as_array().expect("Empty response");
+
+    assert_eq!(chart_cases.first().unwrap().as_u64().unwrap(), 0);
+    assert_eq!(chart_cases.last().unwrap().as_u64().unwrap(), network_cases);
+
+    let response = sender
+        .send_graphql(
+            GET_CHARTS_QUERY,
+            json!({
+                "filter": { "from": today.to_string(), "to": today.to_string() },
+                "granularity": "MONTH"
+            }),
+        )
+        .await
+        .unwrap();
+
+    let charts_response = &response["getCharts"];
+
+    assert_eq!(
+        charts_response["labels"],
+        json!([today.format("%Y-%m").to_string()])
+    );
+    assert_eq!(charts_response["cases"], json!([cases.len()]));
+
+    let error = sender
+        .send_graphql(
+            GET_CHARTS_QUERY,
+            json!({ "filter": { "from": today.to_string(), "to": "2000-01-01" } }),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("The start of the range is after its end"));
+}
+
+#[tokio::test]
+async fn breakdown_statistics_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+    }
+
+    let response = sender
+        .send_graphql(GET_BREAKDOWN_QUERY, Value::Null)
+        .await
+        .unwrap();
+
+    let breakdown_response = &response["getBreakdown"];
+
+    for key in ["addresses", "assets", "reporters"] {
+        let rows = breakdown_response[key].as_array().expect("Empty response");
+
+        assert_eq!(rows.len(), test_app.networks.len());
+        rows.iter().for_each(|row| assert_eq!(row["count"], 1));
+    }
+
+    for row in breakdown_response["reporters"].as_array().unwrap() {
+        assert_eq!(row["status"], "INACTIVE");
+    }
+
+    let network = test_app.networks.first().expect("No test networks");
+    let response = sender
+        .send_graphql(
+            GET_BREAKDOWN_QUERY,
+            json!({
+                "filter": { "networkId": network.model.id },
+                "category": "GAMBLING",
+                "minRisk": 8
+            }),
+        )
+        .await
+        .unwrap();
+
+    let breakdown_response = &response["getBreakdown"];
+
+    assert_eq!(
+        breakdown_response["assets"],
+        json!([{
+            "networkId": network.model.id,
+            "category": "GAMBLING",
+            "risk": 8,
+            "count": 1,
+            "reporterCount": 1
+        }])
+    );
+    assert_eq!(breakdown_response["addresses"], json!([]));
+    assert_eq!(breakdown_response["reporters"].as_array().unwrap().len(), 1);
+
+    let response = sender
+        .send_graphql(GET_BREAKDOWN_QUERY, json!({ "minRisk": 9 }))
+        .await
+        .unwrap();
+
+    assert_eq!(response["getBreakdown"]["addresses"], json!([]));
+    assert_eq!(response["getBreakdown"]["assets"], json!([]));
+}
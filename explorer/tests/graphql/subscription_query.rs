@@ -0,0 +1,142 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp, WAITING_INTERVAL};
+
+use {
+    futures_util::{SinkExt, StreamExt},
+    serde_json::{json, Value},
+    tokio::{
+        net::TcpStream,
+        time::{sleep, timeout, Duration},
+    },
+    tokio_tungstenite::{
+        connect_async,
+        tungstenite::{client::IntoClientRequest, http::HeaderValue, Message},
+        MaybeTlsStream, WebSocketStream,
+    },
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+const ADDRESS_CREATED_SUBSCRIPTION: &str = "
+    subscription AddressCreated($filter: SubscriptionFilter) {
+        addressCreated(filter: $filter) {
+            networkId
+            address
+            risk
+            category
+        }
+    }
+";
+const ADDRESS_UPDATED_SUBSCRIPTION: &str = "
+    subscription AddressUpdated($filter: SubscriptionFilter) {
+        addressUpdated(filter: $filter) {
+            networkId
+            address
+            risk
+            category
+        }
+    }
+";
+
+async fn connect(server_addr: &str) -> Socket {
+    let mut request = format!("{}/graphql", server_addr.replacen("http", "ws", 1))
+        .into_client_request()
+        .expect("Invalid websocket url");
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        HeaderValue::from_static("graphql-transport-ws"),
+    );
+
+    let (mut socket, _) = connect_async(request)
+        .await
+        .expect("Failed to connect to websocket");
+
+    send_message(&mut socket, json!({ "type": "connection_init" })).await;
+    assert_eq!(receive_message(&mut socket).await["type"], "connection_ack");
+
+    socket
+}
+
+async fn send_message(socket: &mut Socket, message: Value) {
+    socket
+        .send(Message::Text(message.to_string()))
+        .await
+        .expect("Failed to send websocket message");
+}
+
+async fn receive_message(socket: &mut Socket) -> Value {
+    loop {
+        let message = timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("Timed out waiting for websocket message")
+            .expect("Websocket closed")
+            .expect("Failed to receive websocket message");
+
+        if let Message::Text(text) = message {
+            return serde_json::from_str(&text).expect("Invalid websocket message");
+        }
+    }
+}
+
+/*
+Test cases:
+ - created address is streamed to the matching subscription
+ - updated address is streamed to the subscription filtered by category and minimum risk
+ */
+#[tokio::test]
+async fn address_subscription_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = test_app.networks.first().expect("No test networks");
+
+    let mut socket = connect(&test_app.server_addr).await;
+
+    send_message(
+        &mut socket,
+        json!({
+            "id": "created",
+            "type": "subscribe",
+            "payload": {
+                "query": ADDRESS_CREATED_SUBSCRIPTION,
+                "variables": { "filter": { "networkId": network.model.id, "minRisk": 5 } }
+            }
+        }),
+    )
+    .await;
+
+    send_message(
+        &mut socket,
+        json!({
+            "id": "updated",
+            "type": "subscribe",
+            "payload": {
+                "query": ADDRESS_UPDATED_SUBSCRIPTION,
+                "variables": { "filter": { "category": "SANCTIONS", "minRisk": 10 } }
+            }
+        }),
+    )
+    .await;
+
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &test_data).await;
+
+    let created = receive_message(&mut socket).await;
+    assert_eq!(created["type"], "next");
+    assert_eq!(created["id"], "created");
+
+    let address = &created["payload"]["data"]["addressCreated"];
+    assert_eq!(address["networkId"], network.model.id);
+    assert_eq!(address["risk"], 6);
+
+    // Both the update and the confirmation match the filter
+    for _ in 0..2 {
+        let updated = receive_message(&mut socket).await;
+        assert_eq!(updated["type"], "next");
+        assert_eq!(updated["id"], "updated");
+
+        let address = &updated["payload"]["data"]["addressUpdated"];
+        assert_eq!(address["risk"], 10);
+        assert_eq!(address["category"], "SANCTIONS");
+    }
+}
@@ -0,0 +1,304 @@
+use crate::helpers::{
+    create_jwt, generate_configuration, get_test_data, RequestSender, TestApp, WAITING_INTERVAL,
+};
+
+use {
+    axum::{
+        body::Bytes,
+        extract::State,
+        http::{header, HeaderMap, StatusCode},
+        response::IntoResponse,
+        routing::post,
+        Router, Server,
+    },
+    hapi_indexer::{verify_signature, PushData, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+    serde_json::{json, Value},
+    std::{
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        time::Duration,
+    },
+    tokio::time::sleep,
+};
+
+const WEBHOOK_SECRET: &str = "my_alert_secret";
+
+const CREATE_WATCHLIST_MUTATION: &str = "
+    mutation CreateWatchlist($input: CreateWatchlistInput!) {
+        createWatchlist(input: $input) {
+            id
+            networkId
+            address
+            webhookUrl
+        }
+    }
+";
+const UPDATE_WATCHLIST_MUTATION: &str = "
+    mutation UpdateWatchlist($id: UUID!, $input: UpdateWatchlistInput!) {
+        updateWatchlist(id: $id, input: $input) {
+            id
+            webhookUrl
+        }
+    }
+";
+const DELETE_WATCHLIST_MUTATION: &str = "
+    mutation DeleteWatchlist($id: UUID!) {
+        deleteWatchlist(id: $id)
+    }
+";
+const GET_NETWORKS_QUERY: &str = "
+    query GetManyNetworks($input: NetworkInput!) {
+        getManyNetworks(input: $input) {
+            total
+        }
+    }
+";
+const GET_WATCHLISTS_QUERY: &str = "
+    query GetWatchlists {
+        getWatchlists {
+            id
+            networkId
+            address
+            webhookUrl
+        }
+    }
+";
+
+/// Requests received by the webhook mock, the first one is answered with a server error
+#[derive(Clone, Default)]
+struct ReceivedAlerts {
+    requests: Arc<Mutex<Vec<(HeaderMap, Bytes)>>>,
+}
+
+async fn alert_handler(
+    State(alerts): State<ReceivedAlerts>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let mut requests = alerts.requests.lock().unwrap();
+    requests.push((headers, body));
+
+    if requests.len() == 1 {
+        StatusCode::INTERNAL_SERVER_ERROR
+    } else {
+        StatusCode::OK
+    }
+}
+
+async fn redirect_handler() -> impl IntoResponse {
+    (
+        StatusCode::TEMPORARY_REDIRECT,
+        [(header::LOCATION, "/alerts")],
+    )
+}
+
+fn start_webhook_mock() -> (String, ReceivedAlerts) {
+    let alerts = ReceivedAlerts::default();
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind webhook mock");
+    let url = format!(
+        "http://{}/alerts",
+        listener.local_addr().expect("Failed to get local address")
+    );
+
+    let router = Router::new()
+        .route("/alerts", post(alert_handler))
+        .route("/redirect", post(redirect_handler))
+        .with_state(alerts.clone());
+    let server = Server::from_tcp(listener)
+        .expect("Failed to start webhook mock")
+        .serve(router.into_make_service());
+    tokio::spawn(server);
+
+    (url, alerts)
+}
+
+/*
+Test cases:
+ - watchlists are managed only with a valid token, which is not required by queries
+ - local and private webhook hosts are rejected unless allowed by the configuration
+ - watched address changes are posted to the webhook with a valid signature
+ - failed alert delivery is retried
+ - redirects of the webhook are not followed
+ - deleted watchlist is not available anymore
+ */
+#[tokio::test]
+async fn watchlist_test() {
+    let mut configuration = generate_configuration();
+    configuration.alert_retry_delay = Duration::from_millis(WAITING_INTERVAL);
+    configuration.alert_allowed_hosts = vec!["127.0.0.1".to_string()];
+
+    let test_app = TestApp::start_with_configuration(configuration).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+    let (webhook_url, alerts) = start_webhook_mock();
+
+    let network = test_app.networks.first().expect("No test networks");
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    let address_events = test_data
+        .iter()
+        .filter_map(|payload| match &payload.data {
+            PushData::Address(address) => Some(address.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let address = address_events.first().expect("No address payload");
+
+    let input = json!({
+        "networkId": network.model.id,
+        "address": address.address.to_uppercase().replacen("0X", "0x", 1),
+        "webhookUrl": "https://example.com/unused",
+        "secret": "unused",
+    });
+
+    assert!(sender
+        .send_graphql(CREATE_WATCHLIST_MUTATION, json!({ "input": input }))
+        .await
+        .is_err());
+
+    let invalid_token = create_jwt("invalid_token");
+    let error = sender
+        .send_authorized_graphql(
+            CREATE_WATCHLIST_MUTATION,
+            json!({ "input": input }),
+            &invalid_token,
+        )
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Invalid token"));
+
+    let response = sender
+        .send_authorized_graphql(GET_NETWORKS_QUERY, json!({ "input": {} }), &invalid_token)
+        .await
+        .unwrap();
+    assert_eq!(
+        response["getManyNetworks"]["total"],
+        test_app.networks.len()
+    );
+
+    for webhook_url in [
+        "http://localhost/alerts",
+        "http://10.0.0.1/alerts",
+        "http://192.168.1.1/alerts",
+        "http://169.254.169.254/latest",
+        "http://[::1]/alerts",
+        "http://0.0.0.0/alerts",
+    ] {
+        let mut input = input.clone();
+        input["webhookUrl"] = json!(webhook_url);
+
+        let error = sender
+            .send_authorized_graphql(CREATE_WATCHLIST_MUTATION, json!({ "input": input }), &token)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("local or private address"));
+    }
+
+    let response = sender
+        .send_authorized_graphql(CREATE_WATCHLIST_MUTATION, json!({ "input": input }), &token)
+        .await
+        .unwrap();
+
+    let watchlist = &response["createWatchlist"];
+    let id = watchlist["id"].clone();
+    assert_eq!(watchlist["networkId"], network.model.id);
+    assert_eq!(watchlist["address"], address.address);
+
+    let response = sender
+        .send_authorized_graphql(
+            UPDATE_WATCHLIST_MUTATION,
+            json!({
+                "id": id,
+                "input": { "webhookUrl": webhook_url, "secret": WEBHOOK_SECRET }
+            }),
+            &token,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response["updateWatchlist"]["webhookUrl"], webhook_url);
+
+    let response = sender
+        .send_authorized_graphql(GET_WATCHLISTS_QUERY, json!({}), &token)
+        .await
+        .unwrap();
+    assert_eq!(response["getWatchlists"].as_array().unwrap().len(), 1);
+    assert_eq!(response["getWatchlists"][0]["id"], id);
+
+    // Alerts of this watchlist would reach the mock only by following the redirect
+    let response = sender
+        .send_authorized_graphql(
+            CREATE_WATCHLIST_MUTATION,
+            json!({
+                "input": {
+                    "networkId": network.model.id,
+                    "address": address.address,
+                    "webhookUrl": webhook_url.replace("/alerts", "/redirect"),
+                    "secret": WEBHOOK_SECRET,
+                }
+            }),
+            &token,
+        )
+        .await
+        .unwrap();
+    let redirected_id = response["createWatchlist"]["id"].clone();
+
+    test_app.send_events(&sender, &test_data).await;
+
+    // One more request is expected for the retried first alert
+    let expected_requests = address_events.len() + 1;
+    for _ in 0..50 {
+        if alerts.requests.lock().unwrap().len() >= expected_requests {
+            break;
+        }
+        sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+    }
+
+    let requests = alerts.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), expected_requests);
+
+    for (headers, body) in &requests {
+        let timestamp = headers[TIMESTAMP_HEADER]
+            .to_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+        let signature = headers[SIGNATURE_HEADER].to_str().unwrap();
+        assert!(verify_signature(
+            WEBHOOK_SECRET.as_bytes(),
+            timestamp,
+            body,
+            signature
+        ));
+
+        let payload: Value = serde_json::from_slice(body).unwrap();
+        assert_eq!(payload["watchlist_id"], id);
+        assert_eq!(payload["network_id"], network.model.id);
+        assert_eq!(payload["entity"], "address");
+        assert_eq!(payload["data"]["address"], address.address);
+    }
+
+    let kinds = requests
+        .iter()
+        .map(|(_, body)| serde_json::from_slice::<Value>(body).unwrap()["kind"].clone())
+        .collect::<Vec<_>>();
+    assert!(kinds.contains(&json!("created")));
+    assert!(kinds.contains(&json!("updated")));
+
+    for id in [&id, &redirected_id] {
+        let response = sender
+            .send_authorized_graphql(DELETE_WATCHLIST_MUTATION, json!({ "id": id }), &token)
+            .await
+            .unwrap();
+        assert_eq!(response["deleteWatchlist"], true);
+    }
+
+    assert!(sender
+        .send_authorized_graphql(DELETE_WATCHLIST_MUTATION, json!({ "id": id }), &token)
+        .await
+        .is_err());
+
+    let response = sender
+        .send_authorized_graphql(GET_WATCHLISTS_QUERY, json!({}), &token)
+        .await
+        .unwrap();
+    assert_eq!(response["getWatchlists"], json!([]));
+}
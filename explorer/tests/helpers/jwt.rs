@@ -8,6 +8,7 @@ pub(crate) fn create_jwt(secret: &str) -> String {
         id: get_jwt_id(),
         iat: 1,
         exp: 10000000000,
+        generation: 0,
     };
 
     encode(
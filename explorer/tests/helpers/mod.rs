@@ -6,8 +6,10 @@ mod test_data;
 pub(crate) use jwt::create_jwt;
 pub(crate) use request_sender::RequestSender;
 pub(crate) use test_app::{
-    FromTestPayload, TestApp, TestNetwork, METRICS_ENV_VAR, MIGRATION_COUNT, WAITING_INTERVAL,
+    generate_configuration, FromTestPayload, TestApp, TestNetwork, METRICS_ENV_VAR,
+    MIGRATION_COUNT, WAITING_INTERVAL,
 };
 pub(crate) use test_data::{
-    create_address_data, create_asset_data, create_reporter_data, get_test_data, TestData,
+    create_address_data, create_asset_data, create_confirm_data, create_reporter_data,
+    get_configuration_test_data, get_test_addresses, get_test_data, TestData,
 };
@@ -1,6 +1,6 @@
 use {
     anyhow::{bail, Result},
-    hapi_indexer::get_id_from_jwt,
+    hapi_indexer::{get_id_from_jwt, sign_payload, SIGNATURE_HEADER, TIMESTAMP_HEADER},
     reqwest::{Client, Response},
     serde::Serialize,
     serde_json::{json, Value},
@@ -20,17 +20,38 @@ impl RequestSender {
     }
 
     pub(crate) async fn send_graphql(&self, query: &str, variables: Value) -> Result<Value> {
+        self.send_graphql_request(query, variables, None).await
+    }
+
+    pub(crate) async fn send_authorized_graphql(
+        &self,
+        query: &str,
+        variables: Value,
+        token: &str,
+    ) -> Result<Value> {
+        self.send_graphql_request(query, variables, Some(token))
+            .await
+    }
+
+    async fn send_graphql_request(
+        &self,
+        query: &str,
+        variables: Value,
+        token: Option<&str>,
+    ) -> Result<Value> {
         let body = serde_json::to_string(&json!({ "query": query, "variables": variables }))
             .expect("Failed to serialize body");
 
-        let response = RequestSender::check_response(
-            self.web_client
-                .post(format!("{}/{}", &self.address, "graphql"))
-                .body(body)
-                .send()
-                .await?,
-        )
-        .await?;
+        let mut request = self
+            .web_client
+            .post(format!("{}/{}", &self.address, "graphql"))
+            .body(body);
+
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = RequestSender::check_response(request.send().await?).await?;
 
         if let Some(errors) = response.get("errors") {
             bail!("GraphQL request failed: {:?}", errors);
@@ -56,6 +77,31 @@ impl RequestSender {
         RequestSender::check_response(response).await
     }
 
+    pub(crate) async fn send_signed<T: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        body: &T,
+        token: &str,
+        secret: &str,
+        timestamp: u64,
+    ) -> Result<Value> {
+        let body = serde_json::to_vec(body)?;
+        let signature = sign_payload(secret.as_bytes(), timestamp, &body);
+
+        let response = self
+            .web_client
+            .post(format!("{}/{}", &self.address, url))
+            .bearer_auth(token)
+            .header("content-type", "application/json")
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, signature)
+            .body(body)
+            .send()
+            .await?;
+
+        RequestSender::check_response(response).await
+    }
+
     pub(crate) async fn get(&self, url: &str) -> Result<Value> {
         let response = self
             .web_client
@@ -66,8 +112,31 @@ impl RequestSender {
         RequestSender::check_response(response).await
     }
 
+    pub(crate) async fn get_text(&self, url: &str) -> Result<String> {
+        let response = self
+            .web_client
+            .get(format!("{}/{}", &self.address, url))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            bail!(
+                "Failed to send request, status: {}, error: {}",
+                response.status().as_str(),
+                response.text().await?
+            );
+        }
+
+        Ok(response.text().await?)
+    }
+
     pub(crate) async fn send_heartbeat(&self, token: &str) -> Result<Value> {
         let id = get_id_from_jwt(token)?;
+
+        self.send_heartbeat_for(&id.to_string(), token).await
+    }
+
+    pub(crate) async fn send_heartbeat_for(&self, id: &str, token: &str) -> Result<Value> {
         let heartbeat_url = format!("{}/indexer/{}/heartbeat", self.address, id);
 
         let response = self
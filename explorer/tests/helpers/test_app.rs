@@ -5,7 +5,11 @@ use {
     hapi_explorer::{
         application::Application,
         configuration::Configuration,
-        entity::{address, asset, case, network::Model as NetworkModel, reporter},
+        entity::{
+            address, asset, case,
+            network::{self, Model as NetworkModel},
+            reporter,
+        },
         observability::setup_tracing,
     },
     hapi_indexer::{PushData, PushPayload},
@@ -21,7 +25,7 @@ use {
 };
 
 pub const WAITING_INTERVAL: u64 = 100;
-pub const MIGRATION_COUNT: u32 = 10;
+pub const MIGRATION_COUNT: u32 = 20;
 pub const METRICS_ENV_VAR: &str = "ENABLE_METRICS";
 const TRACING_ENV_VAR: &str = "ENABLE_TRACING";
 
@@ -45,14 +49,16 @@ pub trait FromTestPayload {
 
 impl TestApp {
     pub async fn start() -> Self {
+        Self::start_with_configuration(generate_configuration()).await
+    }
+
+    pub async fn start_with_configuration(configuration: Configuration) -> Self {
         if env::var(TRACING_ENV_VAR).unwrap_or_default().eq("1") {
             if let Err(e) = setup_tracing("debug", false) {
                 println!("Failed to setup tracing: {}", e);
             }
         }
 
-        let configuration = generate_configuration();
-
         let mut app = Application::from_configuration(configuration.clone())
             .await
             .expect("Failed to build app");
@@ -154,6 +160,16 @@ impl TestApp {
                     chain_id,
                     authority,
                     stake_token,
+                    stake_unlock_duration: None,
+                    validator_stake: None,
+                    tracer_stake: None,
+                    publisher_stake: None,
+                    authority_stake: None,
+                    reward_token: None,
+                    address_confirmation_reward: None,
+                    address_tracer_reward: None,
+                    asset_confirmation_reward: None,
+                    asset_tracer_reward: None,
                     created_at: chrono::Utc::now().naive_utc(),
                     updated_at: chrono::Utc::now().naive_utc(),
                 },
@@ -254,6 +270,61 @@ impl TestApp {
                     reporter.unlock_timestamp.to_string()
                 );
             }
+            PushData::Configuration(configuration) => {
+                let network_model = network::Entity::find_by_id(network_id)
+                    .one(db)
+                    .await
+                    .expect("Failed to find network by id")
+                    .expect("Network does not exist");
+
+                if let Some(authority) = configuration.authority {
+                    assert_eq!(network_model.authority, authority);
+                }
+
+                if let Some(stake) = configuration.stake_configuration {
+                    assert_eq!(network_model.stake_token, stake.token);
+                    assert_eq!(
+                        network_model.stake_unlock_duration,
+                        Some(stake.unlock_duration.to_string())
+                    );
+                    assert_eq!(
+                        network_model.validator_stake,
+                        Some(stake.validator_stake.to_string())
+                    );
+                    assert_eq!(
+                        network_model.tracer_stake,
+                        Some(stake.tracer_stake.to_string())
+                    );
+                    assert_eq!(
+                        network_model.publisher_stake,
+                        Some(stake.publisher_stake.to_string())
+                    );
+                    assert_eq!(
+                        network_model.authority_stake,
+                        Some(stake.authority_stake.to_string())
+                    );
+                }
+
+                if let Some(reward) = configuration.reward_configuration {
+                    assert_eq!(network_model.reward_token, Some(reward.token));
+                    assert_eq!(
+                        network_model.address_confirmation_reward,
+                        Some(reward.address_confirmation_reward.to_string())
+                    );
+                    assert_eq!(
+                        network_model.address_tracer_reward,
+                        Some(reward.address_tracer_reward.to_string())
+                    );
+                    assert_eq!(
+                        network_model.asset_confirmation_reward,
+                        Some(reward.asset_confirmation_reward.to_string())
+                    );
+                    assert_eq!(
+                        network_model.asset_tracer_reward,
+                        Some(reward.asset_tracer_reward.to_string())
+                    );
+                }
+            }
         }
     }
 
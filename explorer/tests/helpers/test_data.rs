@@ -2,6 +2,7 @@ use {
     chrono::Utc,
     hapi_core::{
         client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
             entities::{
                 address::Address,
                 asset::{Asset, AssetId},
@@ -13,7 +14,7 @@ use {
         },
         HapiCoreNetwork,
     },
-    hapi_indexer::{NetworkData, PushData, PushEvent, PushPayload},
+    hapi_indexer::{NetworkConfiguration, NetworkData, PushData, PushEvent, PushPayload},
     rand::{distributions::Alphanumeric, thread_rng, Rng},
     std::str::FromStr,
     uuid::Uuid,
@@ -21,11 +22,33 @@ use {
 
 use super::jwt::get_jwt_id;
 
+/// Characters of the bech32 encoded Bitcoin addresses
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
 pub struct TestData<T> {
     pub data: T,
     pub network_id: String,
 }
 
+/// Addresses of the test address and asset in the format of the network
+pub(crate) fn get_test_addresses(network: &HapiCoreNetwork) -> (&'static str, &'static str) {
+    match network {
+        HapiCoreNetwork::Solana => (
+            "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+        ),
+        HapiCoreNetwork::Bitcoin => (
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+            "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+        ),
+        HapiCoreNetwork::Near => ("scammer.near", "token.near"),
+        _ => (
+            "0x9e833a87087efd527b1a842742eb0f3548cd82ab",
+            "0xe9dbfa9e9d48393d9d22de10051dcbd91267b756",
+        ),
+    }
+}
+
 pub(crate) fn get_test_data(
     network: &HapiCoreNetwork,
     chain_id: Option<String>,
@@ -51,8 +74,10 @@ pub(crate) fn get_test_data(
         reporter_id: reporter_payload.id.to_owned(),
     };
 
+    let (address, asset_address) = get_test_addresses(network);
+
     let mut address_payload = Address {
-        address: "0x9e833a87087efd527b1a842742eb0f3548cd82ab".to_string(),
+        address: address.to_string(),
         case_id: case_payload.id.to_owned(),
         reporter_id: reporter_payload.id.to_owned(),
         risk: 6,
@@ -61,7 +86,7 @@ pub(crate) fn get_test_data(
     };
 
     let mut asset_payload = Asset {
-        address: "0xe9dbfa9e9d48393d9d22de10051dcbd91267b756".to_string(),
+        address: asset_address.to_string(),
         asset_id: AssetId::from_str("12345678").expect("Failed to parse asset id"),
         case_id: case_payload.id.to_owned(),
         reporter_id: reporter_payload.id.to_owned(),
@@ -173,6 +198,73 @@ pub(crate) fn get_test_data(
     events
 }
 
+pub(crate) fn get_configuration_test_data(
+    network: &HapiCoreNetwork,
+    chain_id: Option<String>,
+) -> Vec<PushPayload> {
+    let network_data = NetworkData {
+        network: network.to_owned(),
+        chain_id,
+        indexer_id: Uuid::parse_str(&get_jwt_id()).expect("Failed to parse jwt id"),
+    };
+
+    let initial_configuration = NetworkConfiguration {
+        authority: Some(generate_random_string()),
+        ..Default::default()
+    };
+
+    let authority_configuration = NetworkConfiguration {
+        authority: Some(generate_random_string()),
+        ..Default::default()
+    };
+
+    let stake_configuration = NetworkConfiguration {
+        stake_configuration: Some(StakeConfiguration {
+            token: generate_random_string(),
+            unlock_duration: 123,
+            validator_stake: 10.into(),
+            tracer_stake: 11.into(),
+            publisher_stake: 12.into(),
+            authority_stake: 13.into(),
+        }),
+        ..Default::default()
+    };
+
+    let reward_configuration = NetworkConfiguration {
+        reward_configuration: Some(RewardConfiguration {
+            token: generate_random_string(),
+            address_confirmation_reward: 5.into(),
+            address_tracer_reward: 6.into(),
+            asset_confirmation_reward: 7.into(),
+            asset_tracer_reward: 8.into(),
+        }),
+        ..Default::default()
+    };
+
+    vec![
+        create_payload(
+            network_data.clone(),
+            EventName::Initialize,
+            PushData::Configuration(initial_configuration),
+        ),
+        create_payload(
+            network_data.clone(),
+            EventName::SetAuthority,
+            PushData::Configuration(authority_configuration),
+        ),
+        create_payload(
+            network_data.clone(),
+            EventName::UpdateStakeConfiguration,
+            PushData::Configuration(stake_configuration),
+        ),
+        create_payload(
+            network_data,
+            EventName::UpdateRewardConfiguration,
+            PushData::Configuration(reward_configuration),
+        ),
+    ]
+}
+
 pub fn create_reporter_data(network: &HapiCoreNetwork, chain_id: Option<String>) -> PushPayload {
     let payload = Reporter {
         id: Uuid::new_v4(),
@@ -205,7 +297,7 @@ pub fn create_address_data(
     chain_id: Option<String>,
 ) -> PushPayload {
     let payload = Address {
-        address: generate_random_string(),
+        address: generate_random_address(network),
         case_id,
         reporter_id,
         risk: 6,
@@ -233,7 +325,7 @@ pub fn create_asset_data(
     chain_id: Option<String>,
 ) -> PushPayload {
     let payload = Asset {
-        address: generate_random_string(),
+        address: generate_random_address(network),
         asset_id: AssetId::from_str("12345678").expect("Failed to parse asset id"),
         case_id,
         reporter_id,
@@ -255,6 +347,32 @@ pub fn create_asset_data(
     )
 }
 
+/// Confirmation of the address or asset in the payload by a random reporter, adding one more confirmation
+pub(crate) fn create_confirm_data(payload: &PushPayload) -> PushPayload {
+    let (name, data) = match &payload.data {
+        PushData::Address(address) => (
+            EventName::ConfirmAddress,
+            PushData::Address(Address {
+                confirmations: address.confirmations + 1,
+                ..address.clone()
+            }),
+        ),
+        PushData::Asset(asset) => (
+            EventName::ConfirmAsset,
+            PushData::Asset(Asset {
+                confirmations: asset.confirmations + 1,
+                ..asset.clone()
+            }),
+        ),
+        _ => panic!("Only addresses and assets can be confirmed"),
+    };
+
+    let mut confirm = create_payload(payload.network_data.clone(), name, data);
+    confirm.event.confirmed_by = Some(Uuid::new_v4());
+
+    confirm
+}
+
 fn create_payload(network_data: NetworkData, name: EventName, data: PushData) -> PushPayload {
     let tx_hash = generate_random_string();
 
@@ -263,6 +381,7 @@ fn create_payload(network_data: NetworkData, name: EventName, data: PushData) ->
         tx_hash,
         tx_index: 0,
         timestamp: Utc::now().timestamp() as u64,
+        confirmed_by: None,
     };
 
     PushPayload {
@@ -279,3 +398,27 @@ fn generate_random_string() -> String {
         .map(char::from)
         .collect()
 }
+
+/// Random address in the format of the network
+fn generate_random_address(network: &HapiCoreNetwork) -> String {
+    let bytes: [u8; 32] = thread_rng().gen();
+
+    match network {
+        HapiCoreNetwork::Solana => bs58::encode(bytes).into_string(),
+        HapiCoreNetwork::Bitcoin => format!(
+            "bc1q{}",
+            bytes
+                .iter()
+                .map(|byte| BECH32_CHARSET[usize::from(byte % 32)] as char)
+                .collect::<String>()
+        ),
+        HapiCoreNetwork::Near => format!("{}.near", generate_random_string().to_lowercase()),
+        _ => format!(
+            "0x{}",
+            bytes[..20]
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+        ),
+    }
+}
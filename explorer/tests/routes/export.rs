@@ -0,0 +1,88 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp};
+use {hapi_indexer::PushData, serde_json::Value};
+
+const ADDRESS_COLUMNS: &str =
+    "network_id,address,case_id,reporter_id,risk,category,confirmations,created_at,updated_at";
+
+/*
+Test cases:
+ - export of all addresses as NDJSON
+ - export of addresses filtered by network as CSV
+ - export of cases, assets and reporters
+ - export with an invalid filter is rejected
+ */
+#[tokio::test]
+async fn export_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let mut address = None;
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+
+        address = test_data.iter().find_map(|payload| match &payload.data {
+            PushData::Address(address) => Some(address.clone()),
+            _ => None,
+        });
+    }
+
+    let address = address.expect("No test address");
+    let network = test_app.networks.last().expect("No test networks");
+
+    let export = sender
+        .get_text("export/addresses?format=ndjson")
+        .await
+        .expect("Failed to export addresses");
+
+    let rows = export
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("Invalid NDJSON line"))
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), test_app.networks.len());
+    assert!(rows
+        .iter()
+        .any(|row| row["network_id"] == network.model.id && row["address"] == address.address));
+
+    let export = sender
+        .get_text(&format!(
+            "export/addresses?format=csv&network_id={}",
+            network.model.id
+        ))
+        .await
+        .expect("Failed to export network addresses");
+
+    let lines = export.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], ADDRESS_COLUMNS);
+    assert!(lines[1].starts_with(&format!("{},{},", network.model.id, address.address)));
+    assert!(lines[1].contains(&format!(",{},", address.risk)));
+
+    // CSV is the default format, every entity has a header and a row per network
+    for entity in ["assets", "cases", "reporters"] {
+        let export = sender
+            .get_text(&format!("export/{entity}"))
+            .await
+            .expect("Failed to export entities");
+
+        assert_eq!(export.lines().count(), test_app.networks.len() + 1);
+    }
+
+    let export = sender
+        .get_text("export/cases?format=ndjson&network_id=unknown")
+        .await
+        .expect("Failed to export cases");
+    assert!(export.is_empty());
+
+    let error = sender
+        .get_text("export/addresses?risk=high")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 400"));
+
+    let error = sender
+        .get_text("export/addresses?format=xml")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 400"));
+}
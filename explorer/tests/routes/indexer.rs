@@ -1,9 +1,28 @@
 use crate::helpers::{create_jwt, RequestSender, TestApp};
+use {
+    hapi_explorer::{entity::indexer, server::TokenClaims},
+    hapi_indexer::get_id_from_jwt,
+    jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation},
+    sea_orm::{ActiveModelTrait, Set},
+};
+
+fn get_claims(token: &str) -> TokenClaims {
+    decode::<TokenClaims>(
+        token,
+        &DecodingKey::from_secret("my_ultra_secure_secret".as_ref()),
+        &Validation::default(),
+    )
+    .unwrap()
+    .claims
+}
 
 /*
 Test cases:
  - heartbeat indexer
  - heartbeat indexer with wrong token
+ - heartbeat indexer with token of another indexer
+ - heartbeat indexer with revoked token
+ - heartbeat indexer with token of the current generation
  - get indexers
  - check count of indexers
  */
@@ -23,6 +42,48 @@ async fn indexer_processing_test() {
         .await
         .is_err());
 
+    // heartbeat indexer with token of another indexer
+    let [first, second, ..] = test_app.networks.as_slice() else {
+        panic!("Not enough test networks");
+    };
+    let second_id = get_id_from_jwt(&second.token).unwrap();
+
+    let error = indexer_mock
+        .send_heartbeat_for(&second_id.to_string(), &first.token)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 403"));
+
+    // heartbeat indexer with revoked token
+    indexer::ActiveModel {
+        id: Set(second_id),
+        token_generation: Set(1),
+        ..Default::default()
+    }
+    .update(&test_app.db_connection)
+    .await
+    .expect("Failed to revoke indexer");
+
+    let error = indexer_mock
+        .send_heartbeat(&second.token)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 401"));
+
+    // heartbeat indexer with the token of the current generation, issued in the same second
+    let claims = get_claims(&second.token);
+    let token = encode(
+        &Header::default(),
+        &TokenClaims {
+            generation: 1,
+            ..claims
+        },
+        &EncodingKey::from_secret("my_ultra_secure_secret".as_ref()),
+    )
+    .unwrap();
+
+    indexer_mock.send_heartbeat(&token).await.unwrap();
+
     // get indexers
     let response = indexer_mock.get("indexer").await.unwrap();
 
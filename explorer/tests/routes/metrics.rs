@@ -1,4 +1,4 @@
-use crate::helpers::{RequestSender, TestApp, METRICS_ENV_VAR, WAITING_INTERVAL};
+use crate::helpers::{get_test_data, RequestSender, TestApp, METRICS_ENV_VAR, WAITING_INTERVAL};
 use {
     hapi_core::client::{entities::address::Address, events::EventName},
     tokio::time::{sleep, Duration},
@@ -14,6 +14,12 @@ async fn metrics_test() {
         .global_setup::<Address>(&sender, EventName::UpdateAddress)
         .await;
 
+    // Redelivered events are already applied, so they don't change the gauges
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+    }
+
     sleep(Duration::from_millis(WAITING_INTERVAL)).await;
 
     let metrics = sender
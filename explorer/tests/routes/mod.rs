@@ -1,4 +1,7 @@
+mod export;
 mod health_check;
 mod indexer;
 mod metrics;
+mod screening;
+mod stats;
 mod webhook_processing;
@@ -0,0 +1,71 @@
+use crate::helpers::{get_test_addresses, get_test_data, RequestSender, TestApp};
+use {
+    hapi_explorer::entity::screening::MAX_SCREENING_TARGETS, hapi_indexer::PushData,
+    serde_json::json,
+};
+
+/*
+Test cases:
+ - screening of reported and unknown addresses
+ - screening of an address on every network
+ - screening of too many addresses is rejected
+ */
+#[tokio::test]
+async fn screening_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let mut address = None;
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+
+        address = test_data.iter().find_map(|payload| match &payload.data {
+            PushData::Address(address) => Some(address.clone()),
+            _ => None,
+        });
+    }
+
+    let address = address.expect("No test address");
+    let network = test_app.networks.last().expect("No test networks");
+
+    let results = sender
+        .send(
+            "screening",
+            &json!({
+                "targets": [
+                    { "network_id": network.model.id, "address": address.address },
+                    { "address": address.address },
+                    { "address": "unknown" },
+                ],
+                "threshold": address.risk
+            }),
+            "",
+        )
+        .await
+        .expect("Failed to screen addresses");
+
+    assert_eq!(results[0]["verdict"], "flagged");
+    assert_eq!(results[0]["matches"][0]["network_id"], network.model.id);
+    assert_eq!(results[0]["matches"][0]["risk"], address.risk);
+    assert_eq!(
+        results[1]["matches"].as_array().unwrap().len(),
+        test_app
+            .networks
+            .iter()
+            .filter(|network| get_test_addresses(&network.network).0 == address.address)
+            .count()
+    );
+    assert_eq!(results[2]["verdict"], "unknown");
+
+    let targets = vec![json!({ "address": address.address }); MAX_SCREENING_TARGETS + 1];
+    let error = sender
+        .send(
+            "screening",
+            &json!({ "targets": targets, "threshold": address.risk }),
+            "",
+        )
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 400"));
+}
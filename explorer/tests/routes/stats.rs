@@ -0,0 +1,84 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp};
+use hapi_explorer::entity::statistics::CHART_LENGTH;
+
+/*
+Test cases:
+ - stats of an empty explorer
+ - stats are cached for the same parameters
+ - stats filtered by network
+ - stats filtered by date range
+ - stats with an inverted date range are rejected
+ - stats charts by day and by month
+ - stats with too many chart buckets are rejected
+ */
+#[tokio::test]
+async fn stats_test() {
+    let test_app = TestApp::start().await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let stats = sender.get("stats").await.expect("Failed to get stats");
+    assert_eq!(stats["dashboard"]["total_addresses_count"], 0);
+    assert_eq!(
+        stats["charts"]["labels"].as_array().unwrap().len(),
+        CHART_LENGTH
+    );
+
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+    }
+
+    // The previous response is served from the cache
+    let stats = sender.get("stats").await.expect("Failed to get stats");
+    assert_eq!(stats["dashboard"]["total_addresses_count"], 0);
+
+    let network = test_app.networks.first().expect("No test networks");
+    let stats = sender
+        .get(&format!("stats?network_id={}", network.model.id))
+        .await
+        .expect("Failed to get network stats");
+
+    assert_eq!(stats["dashboard"]["total_addresses_count"], 1);
+    assert_eq!(stats["dashboard"]["total_asset_count"], 1);
+    assert_eq!(stats["dashboard"]["total_case_count"], 1);
+    assert_eq!(stats["dashboard"]["total_reporters_count"], 1);
+
+    let stats = sender
+        .get("stats?from=2000-01-01&to=2000-01-31")
+        .await
+        .expect("Failed to get ranged stats");
+
+    assert_eq!(stats["dashboard"]["total_addresses_count"], 0);
+    assert_eq!(stats["charts"]["labels"].as_array().unwrap().len(), 6);
+
+    let error = sender
+        .get("stats?from=2000-01-31&to=2000-01-01")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 400"));
+
+    let stats = sender
+        .get("stats?from=2000-01-01&to=2000-01-31&granularity=day")
+        .await
+        .expect("Failed to get daily stats");
+
+    let labels = stats["charts"]["labels"].as_array().unwrap();
+    assert_eq!(labels.len(), 31);
+    assert_eq!(labels.first().unwrap(), "2000-01-01");
+    assert_eq!(labels.last().unwrap(), "2000-01-31");
+
+    let stats = sender
+        .get("stats?from=2000-01-01&to=2000-12-31&granularity=month")
+        .await
+        .expect("Failed to get monthly stats");
+
+    let labels = stats["charts"]["labels"].as_array().unwrap();
+    assert_eq!(labels.len(), 12);
+    assert_eq!(labels.first().unwrap(), "2000-01");
+
+    let error = sender
+        .get("stats?from=2000-01-01&to=2010-01-01&granularity=day")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 400"));
+}
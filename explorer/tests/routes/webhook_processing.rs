@@ -1,5 +1,21 @@
-use crate::helpers::{create_jwt, get_test_data, RequestSender, TestApp, WAITING_INTERVAL};
-use tokio::time::{sleep, Duration};
+use crate::helpers::{
+    create_confirm_data, create_jwt, generate_configuration, get_configuration_test_data,
+    get_test_data, RequestSender, TestApp, WAITING_INTERVAL,
+};
+use {
+    hapi_core::{client::events::EventName, HapiCoreNetwork},
+    hapi_explorer::entity::event,
+    hapi_indexer::PushData,
+    sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder},
+    secrecy::SecretString,
+    std::time::{SystemTime, UNIX_EPOCH},
+    tokio::time::{sleep, Duration},
+};
+
+const WEBHOOK_SECRET: &str = "my_webhook_secret";
+// EIP-55 forms of the test EVM address
+const CHECKSUMMED_ADDRESS: &str = "0x9e833A87087EfD527b1a842742eB0F3548CD82Ab";
+const INVALID_CHECKSUM_ADDRESS: &str = "0x9E833A87087EfD527b1a842742eB0F3548CD82Ab";
 
 #[tokio::test]
 async fn webhook_processing_test() {
@@ -23,3 +39,301 @@ async fn webhook_processing_test() {
         }
     }
 }
+
+#[tokio::test]
+async fn network_configuration_processing_test() {
+    let test_app = TestApp::start().await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    for network in &test_app.networks {
+        let test_data =
+            get_configuration_test_data(&network.network, network.model.chain_id.clone());
+
+        for payload in test_data {
+            indexer_mock
+                .send("events", &payload, &token)
+                .await
+                .expect("Failed to send event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+            test_app
+                .check_entity(payload.data, network.model.id.clone())
+                .await;
+        }
+    }
+}
+
+/*
+Test cases:
+ - signed event is accepted
+ - unsigned event is rejected
+ - event signed with another secret is rejected
+ - event with stale timestamp is rejected
+ */
+#[tokio::test]
+async fn signed_webhook_processing_test() {
+    let mut configuration = generate_configuration();
+    configuration.webhook_secret = Some(SecretString::new(WEBHOOK_SECRET.to_string()));
+
+    let test_app = TestApp::start_with_configuration(configuration).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let network = test_app.networks.first().expect("No test networks");
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    let payload = test_data.first().expect("No test data");
+
+    indexer_mock
+        .send_signed("events", payload, &token, WEBHOOK_SECRET, now)
+        .await
+        .expect("Failed to send signed event");
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    test_app
+        .check_entity(payload.data.clone(), network.model.id.clone())
+        .await;
+
+    assert!(indexer_mock.send("events", payload, &token).await.is_err());
+
+    assert!(indexer_mock
+        .send_signed("events", payload, &token, "other_secret", now)
+        .await
+        .is_err());
+
+    assert!(indexer_mock
+        .send_signed("events", payload, &token, WEBHOOK_SECRET, now - 3600)
+        .await
+        .is_err());
+}
+
+/*
+Test cases:
+ - redelivered events are accepted and ignored
+ - update with an older timestamp is ignored and not recorded
+ - network configuration update with an older timestamp is ignored
+ */
+#[tokio::test]
+async fn idempotent_webhook_processing_test() {
+    let test_app = TestApp::start().await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    let network = test_app.networks.first().expect("No test networks");
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+
+    test_app.send_events(&indexer_mock, &test_data).await;
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    let last_address = test_data
+        .iter()
+        .rev()
+        .find(|payload| matches!(payload.data, PushData::Address(_)))
+        .expect("No address payload");
+
+    test_app
+        .check_entity(last_address.data.clone(), network.model.id.clone())
+        .await;
+
+    let mut stale_update = last_address.clone();
+    stale_update.event.tx_hash = String::from("stale_tx_hash");
+    stale_update.event.timestamp -= 3600;
+
+    if let PushData::Address(address) = &mut stale_update.data {
+        address.risk = 1;
+    }
+
+    indexer_mock
+        .send("events", &stale_update, &token)
+        .await
+        .expect("Failed to send stale event");
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    test_app
+        .check_entity(last_address.data.clone(), network.model.id.clone())
+        .await;
+
+    // The ignored update is not recorded in the history
+    let stale_event = event::Entity::find()
+        .filter(event::Column::TxHash.eq(&stale_update.event.tx_hash))
+        .one(&test_app.db_connection)
+        .await
+        .expect("Failed to get events");
+    assert!(stale_event.is_none());
+
+    let configuration_data =
+        get_configuration_test_data(&network.network, network.model.chain_id.clone());
+    test_app
+        .send_events(&indexer_mock, &configuration_data)
+        .await;
+
+    let authority_update = configuration_data
+        .iter()
+        .find(|payload| payload.event.name == EventName::SetAuthority)
+        .expect("No authority payload");
+
+    let mut stale_configuration = authority_update.clone();
+    stale_configuration.event.tx_hash = String::from("stale_configuration_tx_hash");
+    stale_configuration.event.timestamp -= 3600;
+
+    if let PushData::Configuration(configuration) = &mut stale_configuration.data {
+        configuration.authority = Some(String::from("stale_authority"));
+    }
+
+    indexer_mock
+        .send("events", &stale_configuration, &token)
+        .await
+        .expect("Failed to send stale configuration event");
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    test_app
+        .check_entity(authority_update.data.clone(), network.model.id.clone())
+        .await;
+}
+
+/*
+Test cases:
+ - events of the same transaction with different indexes are all applied
+ */
+#[tokio::test]
+async fn multiple_events_in_transaction_test() {
+    let test_app = TestApp::start().await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+
+    let network = test_app.networks.first().expect("No test networks");
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    let mut update = test_data
+        .iter()
+        .rev()
+        .find(|payload| matches!(payload.data, PushData::Address(_)))
+        .expect("No address payload")
+        .clone();
+    update.event.tx_hash = String::from("multiple_events_tx_hash");
+    update.event.timestamp += 1;
+
+    if let PushData::Address(address) = &mut update.data {
+        address.risk = 9;
+    }
+
+    let mut confirm = create_confirm_data(&update);
+    confirm.event.tx_hash = update.event.tx_hash.clone();
+    confirm.event.tx_index = update.event.tx_index + 1;
+    confirm.event.timestamp = update.event.timestamp;
+
+    test_app
+        .send_events(&indexer_mock, &vec![update, confirm.clone()])
+        .await;
+
+    test_app
+        .check_entity(confirm.data, network.model.id.clone())
+        .await;
+
+    let events = event::Entity::find()
+        .filter(event::Column::TxHash.eq(&confirm.event.tx_hash))
+        .order_by_asc(event::Column::TxIndex)
+        .all(&test_app.db_connection)
+        .await
+        .expect("Failed to get events");
+
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| (event.tx_index, event.name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(0, "update_address"), (1, "confirm_address")]
+    );
+}
+
+/*
+Test cases:
+ - checksummed EVM address is stored in lowercase
+ - EVM address with an invalid checksum is rejected
+ - address invalid on the network backend is rejected
+ - Bitcoin address is stored as is on the Solana backend
+ - Bitcoin address indexed on Solana is rejected
+ */
+#[tokio::test]
+async fn address_normalization_test() {
+    let test_app = TestApp::start().await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    let network = test_app.networks.first().expect("No test networks");
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+
+    let checksummed_data = test_data
+        .iter()
+        .cloned()
+        .map(|mut payload| {
+            if let PushData::Address(address) = &mut payload.data {
+                address.address = CHECKSUMMED_ADDRESS.to_string();
+            }
+            payload
+        })
+        .collect();
+
+    test_app.send_events(&indexer_mock, &checksummed_data).await;
+
+    let last_address = test_data
+        .iter()
+        .rev()
+        .find(|payload| matches!(payload.data, PushData::Address(_)))
+        .expect("No address payload");
+
+    test_app
+        .check_entity(last_address.data.clone(), network.model.id.clone())
+        .await;
+
+    for address in [INVALID_CHECKSUM_ADDRESS, "scammer.near"] {
+        let mut invalid_address = last_address.clone();
+        invalid_address.event.tx_hash = format!("invalid_{address}");
+
+        if let PushData::Address(payload) = &mut invalid_address.data {
+            payload.address = address.to_string();
+        }
+
+        let error = indexer_mock
+            .send("events", &invalid_address, &token)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("status: 400"));
+    }
+
+    // Solana backend also serves Bitcoin, so its addresses are stored as they are sent
+    let network = test_app
+        .networks
+        .iter()
+        .find(|network| network.network == HapiCoreNetwork::Solana)
+        .expect("No Solana network");
+    let test_data = get_test_data(&HapiCoreNetwork::Bitcoin, network.model.chain_id.clone());
+
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    let last_address = test_data
+        .iter()
+        .rev()
+        .find(|payload| matches!(payload.data, PushData::Address(_)))
+        .expect("No address payload");
+
+    test_app
+        .check_entity(last_address.data.clone(), network.model.id.clone())
+        .await;
+
+    // Only Solana addresses are checked to be public keys
+    let mut solana_address = last_address.clone();
+    solana_address.network_data.network = HapiCoreNetwork::Solana;
+    solana_address.event.tx_hash = String::from("bitcoin_address_on_solana");
+
+    let error = indexer_mock
+        .send("events", &solana_address, &token)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 400"));
+}
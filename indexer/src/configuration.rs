@@ -3,7 +3,8 @@ use {
     hapi_core::HapiCoreNetwork,
     serde::Deserialize,
     serde_with::{serde_as, DurationMilliSeconds},
-    std::{env, time::Duration},
+    sha2::{Digest, Sha256},
+    std::{collections::HashSet, env, time::Duration},
 };
 
 pub const CONFIG_PATH: &str = "configuration.toml";
@@ -38,8 +39,12 @@ pub struct IndexerConfiguration {
     /// The RPC node URL
     pub rpc_node_url: String,
 
-    /// URL to send webhooks to
-    pub webhook_url: String,
+    /// URL to send webhooks and heartbeats to (required for the webhook sink)
+    pub webhook_url: Option<String>,
+
+    /// Destinations of the indexed events
+    #[serde(default = "default_sinks")]
+    pub sinks: Vec<SinkConfiguration>,
 
     /// The HAPI Core contract address
     pub contract_address: String,
@@ -60,6 +65,80 @@ pub struct IndexerConfiguration {
 
     /// JWT token to use for the webhook
     pub jwt_token: String,
+
+    /// Shared secret to sign webhook payloads with HMAC-SHA256 (optional)
+    pub webhook_secret: Option<String>,
+
+    /// The file to keep undelivered payloads in
+    #[serde(default = "default_outbox_file")]
+    pub outbox_file: String,
+
+    /// The file to append payloads permanently rejected by a sink to
+    #[serde(default = "default_dead_letter_file")]
+    pub dead_letter_file: String,
+
+    /// The number of milliseconds before the first delivery retry, doubled after each failure
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_retry_delay")]
+    pub retry_delay: Duration,
+
+    /// The maximum number of milliseconds between delivery retries
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_max_retry_delay")]
+    pub max_retry_delay: Duration,
+
+    /// The number of blocks behind the chain head that are considered final (EVM only)
+    #[serde(default = "default_confirmations")]
+    pub confirmations: u64,
+}
+
+/// Destination of the indexed events
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SinkConfiguration {
+    /// Pushes events to the explorer webhook
+    Webhook,
+    /// Appends events to the file as newline-delimited JSON
+    File { path: String },
+    /// Prints events to the standard output as newline-delimited JSON
+    Stdout,
+}
+
+impl IndexerConfiguration {
+    /// Stable identity of the sink: its kind and a short hash of its destination
+    pub fn sink_id(&self, sink: &SinkConfiguration) -> String {
+        match sink {
+            SinkConfiguration::Webhook => format!(
+                "webhook-{}",
+                short_hash(self.webhook_url.as_deref().unwrap_or_default())
+            ),
+            SinkConfiguration::File { path } => format!("file-{}", short_hash(path)),
+            SinkConfiguration::Stdout => String::from("stdout"),
+        }
+    }
+
+    /// Sinks with the same identity would share the outbox file, so they are rejected
+    pub fn validate_sinks(&self) -> Result<(), ConfigError> {
+        let mut ids = HashSet::new();
+
+        for sink in &self.sinks {
+            if !ids.insert(self.sink_id(sink)) {
+                return Err(ConfigError::Message(format!(
+                    "Sink {sink:?} is configured more than once"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn short_hash(value: &str) -> String {
+    hex::encode(&Sha256::digest(value.as_bytes())[..4])
+}
+
+fn default_sinks() -> Vec<SinkConfiguration> {
+    vec![SinkConfiguration::Webhook]
 }
 
 fn default_is_json_logging() -> bool {
@@ -82,6 +161,26 @@ fn default_delay() -> Duration {
     Duration::from_millis(100)
 }
 
+fn default_outbox_file() -> String {
+    String::from("data/outbox.ndjson")
+}
+
+fn default_dead_letter_file() -> String {
+    String::from("data/dead_letter.ndjson")
+}
+
+fn default_retry_delay() -> Duration {
+    Duration::from_millis(500)
+}
+
+fn default_max_retry_delay() -> Duration {
+    Duration::from_millis(60_000)
+}
+
+fn default_confirmations() -> u64 {
+    0
+}
+
 fn default_state_file() -> String {
     String::from("data/state.json")
 }
@@ -97,5 +196,8 @@ pub fn get_configuration() -> Result<Configuration, ConfigError> {
         )
         .build()?;
 
-    settings.try_deserialize::<Configuration>()
+    let configuration = settings.try_deserialize::<Configuration>()?;
+    configuration.indexer.validate_sinks()?;
+
+    Ok(configuration)
 }
@@ -1,68 +1,234 @@
 use {
     anyhow::{bail, Result},
-    ethers::{abi::Token, providers::Middleware, types::Filter},
-    hapi_core::{client::events::EventName, HapiCore, HapiCoreEvm},
-    std::{cmp::min, str::FromStr},
+    ethers::{
+        abi::{Detokenize, Token},
+        contract::ContractCall,
+        providers::Middleware,
+        types::{Filter, H256, U256},
+        utils::to_checksum,
+    },
+    hapi_core::{
+        client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
+            entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
+            events::EventName,
+        },
+        HapiCore, HapiCoreEvm,
+    },
+    std::{
+        cmp::{max, min},
+        str::FromStr,
+    },
     uuid::Uuid,
 };
 
 use crate::{
     indexer::{
         client::indexer_client::PAGE_SIZE,
-        push::{NetworkData, PushData, PushEvent, PushPayload},
-        IndexerJob,
+        push::{NetworkConfiguration, NetworkData, PushData, PushEvent, PushPayload},
+        BlockHashes, IndexerJob,
     },
     IndexingCursor,
 };
 
-use super::indexer_client::FetchingArtifacts;
+use super::indexer_client::{get_configuration_payload, FetchingArtifacts};
+
+/// The number of recently indexed block hashes kept to detect chain reorganizations
+const MAX_TRACKED_BLOCKS: usize = 256;
+
+/// Error messages of the RPC nodes that refuse to return logs for a too wide block range.
+/// Generic messages like "limit exceeded" are also used for rate limiting, so they are not matched
+const TOO_MANY_RESULTS_ERRORS: [&str; 3] = [
+    "query returned more than",
+    "response size exceeded",
+    "block range is too large",
+];
+
+fn is_too_many_results(error: &str) -> bool {
+    let error = error.to_lowercase();
+
+    TOO_MANY_RESULTS_ERRORS
+        .iter()
+        .any(|pattern| error.contains(pattern))
+}
 
+/// Fetches logs of a single page starting from the block.
+/// The page size is halved while the RPC node refuses to return that many logs
+/// and grows back to the configured size after successful requests.
+/// Returns the jobs and the last block of the fetched page
 async fn get_event_list(
     client: &HapiCoreEvm,
     from_block: u64,
     latest_block: u64,
-) -> Result<Vec<IndexerJob>> {
-    let mut event_list = vec![];
+    page_size: &mut u64,
+) -> Result<(Vec<IndexerJob>, u64)> {
     let filter = Filter::default().address(client.contract.address());
 
-    // Substracting 1 from page size because the result will include filter limits
-    let to_block = min(PAGE_SIZE.to_owned() - 1 + from_block, latest_block);
+    loop {
+        // Substracting 1 from page size because the result will include filter limits
+        let to_block = min(*page_size - 1 + from_block, latest_block);
+
+        match client
+            .contract
+            .client()
+            .get_logs(&filter.clone().from_block(from_block).to_block(to_block))
+            .await
+        {
+            Ok(logs) => {
+                *page_size = min(*page_size * 2, PAGE_SIZE.to_owned());
+
+                return Ok((logs.into_iter().map(IndexerJob::Log).collect(), to_block));
+            }
+            Err(e) if *page_size > 1 && is_too_many_results(&e.to_string()) => {
+                *page_size = max(*page_size / 2, 1);
+
+                tracing::warn!(page_size, error = %e, "Too many logs, reducing page size");
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
 
-    let logs = client
-        .contract
-        .client()
-        .get_logs(&filter.clone().from_block(from_block).to_block(to_block))
-        .await?;
+async fn get_block_hash(client: &HapiCoreEvm, block_number: u64) -> Result<Option<String>> {
+    Ok(client
+        .provider
+        .get_block(block_number)
+        .await?
+        .and_then(|block| block.hash)
+        .map(|hash| format!("{hash:#x}")))
+}
 
-    logs.into_iter().for_each(|log| {
-        event_list.push(IndexerJob::Log(log));
-    });
+async fn track_block_hash(
+    client: &HapiCoreEvm,
+    block_hashes: &mut BlockHashes,
+    block_number: u64,
+) -> Result<()> {
+    let hash = get_block_hash(client, block_number)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Unable to get block hash"))?;
+
+    insert_block_hash(block_hashes, block_number, hash);
+
+    Ok(())
+}
+
+/// Tracks the blocks of the fetched logs, so that a reorganization of any block
+/// that produced events rolls the cursor back before these events
+fn track_log_blocks(block_hashes: &mut BlockHashes, jobs: &[IndexerJob]) {
+    for job in jobs {
+        if let IndexerJob::Log(log) = job {
+            if let (Some(number), Some(hash)) = (log.block_number, log.block_hash) {
+                insert_block_hash(block_hashes, number.as_u64(), format!("{hash:#x}"));
+            }
+        }
+    }
+}
 
-    Ok(event_list)
+fn insert_block_hash(block_hashes: &mut BlockHashes, block_number: u64, hash: String) {
+    block_hashes.insert(block_number, hash);
+
+    while block_hashes.len() > MAX_TRACKED_BLOCKS {
+        block_hashes.pop_first();
+    }
 }
 
-#[tracing::instrument(skip(client))]
+/// Drops the tracked blocks that are no longer on the canonical chain.
+/// Returns the latest remaining block if a chain reorganization is found.
+/// A reorganization deeper than the tracked history is fatal: the history is kept as is,
+/// so the indexer doesn't carry on from the orphaned blocks after a restart either
+async fn find_reorg_ancestor(
+    client: &HapiCoreEvm,
+    block_hashes: &mut BlockHashes,
+) -> Result<Option<u64>> {
+    let mut ancestor = None;
+    let mut orphaned_blocks = vec![];
+
+    for (&block_number, hash) in block_hashes.iter().rev() {
+        if get_block_hash(client, block_number).await?.as_ref() == Some(hash) {
+            ancestor = Some(block_number);
+            break;
+        }
+
+        tracing::warn!(block_number, %hash, "Block is no longer on the canonical chain");
+
+        orphaned_blocks.push(block_number);
+    }
+
+    if orphaned_blocks.is_empty() {
+        return Ok(None);
+    }
+
+    let Some(ancestor) = ancestor else {
+        bail!(
+            "Chain reorganization is deeper than the {MAX_TRACKED_BLOCKS} tracked blocks, \
+            the network has to be indexed again"
+        );
+    };
+
+    for block_number in orphaned_blocks {
+        block_hashes.remove(&block_number);
+    }
+
+    Ok(Some(ancestor))
+}
+
+#[tracing::instrument(skip(client, block_hashes, page_size))]
 pub(super) async fn fetch_evm_jobs(
     client: &HapiCoreEvm,
     current_cursor: &IndexingCursor,
+    confirmations: u64,
+    block_hashes: &mut BlockHashes,
+    page_size: &mut u64,
 ) -> Result<FetchingArtifacts> {
-    let current_block = match current_cursor {
+    let mut cursor = current_cursor.clone();
+
+    if let (Some(ancestor), IndexingCursor::Block(block)) =
+        (find_reorg_ancestor(client, block_hashes).await?, &cursor)
+    {
+        // Only the events of the canonical chain are pushed, the orphaned ones are not reverted
+        tracing::warn!(
+            ancestor,
+            block,
+            "Chain reorganization detected, rolling back the cursor"
+        );
+        cursor = IndexingCursor::Block(min(*block, ancestor));
+    }
+
+    let current_block = match &cursor {
         IndexingCursor::None => 0,
         IndexingCursor::Block(block) => *block + 1,
         _ => bail!("Evm network must have a block cursor"),
     };
 
-    let latest_block = client.provider.get_block_number().await?.as_u64();
+    // Blocks that are not deep enough yet can still be reorganized
+    let latest_block = client
+        .provider
+        .get_block_number()
+        .await?
+        .as_u64()
+        .saturating_sub(confirmations);
 
-    if current_block < latest_block {
+    if current_block <= latest_block {
         tracing::info!(current_block, "Fetching evm jobs from");
 
-        let event_list = get_event_list(client, current_block, latest_block).await?;
-        tracing::info!(count = event_list.len(), "Found jobs");
+        let (event_list, to_block) =
+            get_event_list(client, current_block, latest_block, page_size).await?;
+
+        tracing::info!(
+            count = event_list.len(),
+            to_block,
+            latest_block,
+            remaining_blocks = latest_block - to_block,
+            "Found jobs"
+        );
+
+        track_log_blocks(block_hashes, &event_list);
+        track_block_hash(client, block_hashes, to_block).await?;
 
         return Ok(FetchingArtifacts {
             jobs: event_list,
-            cursor: IndexingCursor::Block(latest_block),
+            cursor: IndexingCursor::Block(to_block),
+            is_synced: to_block == latest_block,
         });
     }
 
@@ -70,7 +236,8 @@ pub(super) async fn fetch_evm_jobs(
 
     Ok(FetchingArtifacts {
         jobs: vec![],
-        cursor: current_cursor.clone(),
+        cursor,
+        is_synced: true,
     })
 }
 
@@ -87,11 +254,10 @@ pub(super) async fn process_evm_job(
         return Ok(None);
     };
 
-    let tx_hash = format!(
-        "{:#?}",
-        log.transaction_hash
-            .ok_or_else(|| anyhow::anyhow!("Unable to parse transaction hash"))?
-    );
+    let transaction_hash = log
+        .transaction_hash
+        .ok_or_else(|| anyhow::anyhow!("Unable to parse transaction hash"))?;
+    let tx_hash = format!("{transaction_hash:#?}");
 
     let block_number = log
         .block_number
@@ -119,20 +285,31 @@ pub(super) async fn process_evm_job(
             | "ReporterActivated"
             | "ReporterDeactivated"
             | "ReporterStakeWithdrawn",
-            [reporter_id, ..],
-        ) => get_evm_reporter_payload(client, reporter_id).await?,
+            [reporter_id, tokens @ ..],
+        ) => get_evm_reporter_payload(client, reporter_id, tokens, block_number).await?,
         ("CaseCreated" | "CaseUpdated", [case_id, ..]) => {
-            get_evm_case_payload(client, case_id).await?
+            get_evm_case_payload(client, case_id, block_number).await?
         }
-        ("AddressCreated" | "AddressUpdated", [addr, ..]) => {
-            get_evm_address_payload(client, addr).await?
+        ("AddressCreated" | "AddressUpdated" | "AddressConfirmed", [addr, tokens @ ..]) => {
+            get_evm_address_payload(client, addr, tokens, block_number).await?
         }
-        ("AssetCreated" | "AssetUpdated", [addr, id, ..]) => {
-            get_evm_asset_payload(client, addr, id).await?
+        ("AssetCreated" | "AssetUpdated" | "AssetConfirmed", [addr, id, tokens @ ..]) => {
+            get_evm_asset_payload(client, addr, id, tokens, block_number).await?
         }
-        ("AuthorityChanged" | "StakeConfigurationChanged" | "RewardConfigurationChanged", [..]) => {
-            tracing::info!("Configuration is changed");
-            None
+        (
+            "Initialized"
+            | "AuthorityChanged"
+            | "StakeConfigurationChanged"
+            | "RewardConfigurationChanged",
+            _,
+        ) => {
+            get_evm_configuration_payload(
+                client,
+                &log_header.name,
+                &log_header.tokens,
+                block_number,
+            )
+            .await?
         }
         _ => {
             tracing::warn!(name = log_header.name, tokens = ?log_header.tokens, "Ignoring event");
@@ -141,13 +318,24 @@ pub(super) async fn process_evm_job(
     };
 
     if let Some(data) = data {
+        let name = EventName::from_str(&log_header.name)?;
+
+        let confirmed_by = match name {
+            EventName::ConfirmAddress | EventName::ConfirmAsset => {
+                Some(get_evm_confirming_reporter(client, transaction_hash, block_number).await?)
+            }
+            _ => None,
+        };
+
         Ok(Some(vec![PushPayload {
             network_data,
             event: PushEvent {
-                name: EventName::from_str(&log_header.name)?,
+                name,
                 tx_hash,
-                tx_index: 0,
+                // A transaction can emit several events, the log index tells them apart
+                tx_index: log.log_index.unwrap_or_default().as_u64(),
                 timestamp: block.timestamp.as_u64(),
+                confirmed_by,
             },
             data,
         }]))
@@ -156,15 +344,83 @@ pub(super) async fn process_evm_job(
     }
 }
 
+/// Calls the contract getter against the state at the event block.
+/// Returns `None` if the node is unable to serve historical state.
+async fn call_at_block<M: Middleware, D: Detokenize>(
+    call: ContractCall<M, D>,
+    block_number: u64,
+) -> Option<D> {
+    match call.block(block_number).call().await {
+        Ok(value) => Some(value),
+        Err(error) => {
+            tracing::warn!(
+                block_number,
+                %error,
+                "Historical state is unavailable, falling back to the current state"
+            );
+            None
+        }
+    }
+}
+
+/// Confirmation events don't carry the reporter, so it is resolved from the transaction sender
+async fn get_evm_confirming_reporter(
+    client: &HapiCoreEvm,
+    tx_hash: H256,
+    block_number: u64,
+) -> Result<Uuid> {
+    let sender = client
+        .provider
+        .get_transaction(tx_hash)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Unable to get transaction"))?
+        .from;
+
+    let reporter_id = match call_at_block(
+        client.contract.get_my_reporter_id().from(sender),
+        block_number,
+    )
+    .await
+    {
+        Some(reporter_id) => reporter_id,
+        None => {
+            client
+                .contract
+                .get_my_reporter_id()
+                .from(sender)
+                .call()
+                .await?
+        }
+    };
+
+    Ok(Uuid::from_u128(reporter_id))
+}
+
 async fn get_evm_reporter_payload(
     client: &HapiCoreEvm,
     reporter_id: &Token,
+    tokens: &[Token],
+    block_number: u64,
 ) -> Result<Option<PushData>> {
     if let Some(reporter_id) = reporter_id.clone().into_uint() {
         let reporter_id = Uuid::from_u128(reporter_id.as_u128());
         tracing::info!(?reporter_id, "Reporter is created or modified");
 
-        let reporter = client.get_reporter(&reporter_id.to_string()).await?;
+        let mut reporter: Reporter = match call_at_block(
+            client.contract.get_reporter(reporter_id.as_u128()),
+            block_number,
+        )
+        .await
+        {
+            Some(reporter) => reporter.try_into()?,
+            None => client.get_reporter(&reporter_id.to_string()).await?,
+        };
+
+        // ReporterCreated and ReporterUpdated events carry the reporter account and role
+        if let [Token::Address(account), Token::Uint(role)] = tokens {
+            reporter.account = to_checksum(account, None);
+            reporter.role = (role.as_u64() as u8).try_into()?;
+        }
 
         Ok(Some(reporter.into()))
     } else {
@@ -173,12 +429,20 @@ async fn get_evm_reporter_payload(
     }
 }
 
-async fn get_evm_case_payload(client: &HapiCoreEvm, case_id: &Token) -> Result<Option<PushData>> {
+async fn get_evm_case_payload(
+    client: &HapiCoreEvm,
+    case_id: &Token,
+    block_number: u64,
+) -> Result<Option<PushData>> {
     if let Some(case_id) = case_id.clone().into_uint() {
         let case_id = Uuid::from_u128(case_id.as_u128());
         tracing::info!(?case_id, "Case is created or modified");
 
-        let case = client.get_case(&case_id.to_string()).await?;
+        let case: Case =
+            match call_at_block(client.contract.get_case(case_id.as_u128()), block_number).await {
+                Some(case) => case.try_into()?,
+                None => client.get_case(&case_id.to_string()).await?,
+            };
 
         Ok(Some(case.into()))
     } else {
@@ -187,11 +451,26 @@ async fn get_evm_case_payload(client: &HapiCoreEvm, case_id: &Token) -> Result<O
     }
 }
 
-async fn get_evm_address_payload(client: &HapiCoreEvm, addr: &Token) -> Result<Option<PushData>> {
+async fn get_evm_address_payload(
+    client: &HapiCoreEvm,
+    addr: &Token,
+    tokens: &[Token],
+    block_number: u64,
+) -> Result<Option<PushData>> {
     if let Some(addr) = addr.clone().into_address() {
-        tracing::info!(?addr, "Address is created or modified");
-
-        let address = client.get_address(&format!("{addr:?}")).await?;
+        tracing::info!(?addr, "Address is created, modified or confirmed");
+
+        let mut address: Address =
+            match call_at_block(client.contract.get_address(addr), block_number).await {
+                Some(address) => address.try_into()?,
+                None => client.get_address(&format!("{addr:?}")).await?,
+            };
+
+        // AddressCreated and AddressUpdated events carry the risk and category
+        if let [Token::Uint(risk), Token::Uint(category)] = tokens {
+            address.risk = risk.as_u64() as u8;
+            address.category = (category.as_u64() as u8).try_into()?;
+        }
 
         Ok(Some(address.into()))
     } else {
@@ -204,11 +483,23 @@ async fn get_evm_asset_payload(
     client: &HapiCoreEvm,
     addr: &Token,
     id: &Token,
+    tokens: &[Token],
+    block_number: u64,
 ) -> Result<Option<PushData>> {
     if let (Some(addr), Some(id)) = (addr.clone().into_address(), id.clone().into_uint()) {
-        tracing::info!(?addr, ?id, "Asset is created or modified");
-
-        let asset = client.get_asset(&format!("{addr:?}"), &id.into()).await?;
+        tracing::info!(?addr, ?id, "Asset is created, modified or confirmed");
+
+        let mut asset: Asset =
+            match call_at_block(client.contract.get_asset(addr, id), block_number).await {
+                Some(asset) => asset.try_into()?,
+                None => client.get_asset(&format!("{addr:?}"), &id.into()).await?,
+            };
+
+        // AssetCreated and AssetUpdated events carry the risk and category
+        if let [Token::Uint(risk), Token::Uint(category)] = tokens {
+            asset.risk = risk.as_u64() as u8;
+            asset.category = (category.as_u64() as u8).try_into()?;
+        }
 
         Ok(Some(asset.into()))
     } else {
@@ -216,3 +507,364 @@ async fn get_evm_asset_payload(
         Ok(None)
     }
 }
+
+async fn get_evm_configuration_payload(
+    client: &HapiCoreEvm,
+    name: &str,
+    tokens: &[Token],
+    block_number: u64,
+) -> Result<Option<PushData>> {
+    let configuration = match (name, tokens) {
+        ("StakeConfigurationChanged", [Token::Address(token), tokens @ ..]) => {
+            let [unlock_duration, validator_stake, tracer_stake, publisher_stake, authority_stake] =
+                get_uints(tokens)?;
+
+            NetworkConfiguration {
+                stake_configuration: Some(StakeConfiguration {
+                    token: to_checksum(token, None),
+                    unlock_duration: unlock_duration.as_u64(),
+                    validator_stake: validator_stake.into(),
+                    tracer_stake: tracer_stake.into(),
+                    publisher_stake: publisher_stake.into(),
+                    authority_stake: authority_stake.into(),
+                }),
+                ..Default::default()
+            }
+        }
+        ("RewardConfigurationChanged", [Token::Address(token), tokens @ ..]) => {
+            let [address_confirmation, address_tracer, asset_confirmation, asset_tracer] =
+                get_uints(tokens)?;
+
+            NetworkConfiguration {
+                reward_configuration: Some(RewardConfiguration {
+                    token: to_checksum(token, None),
+                    address_confirmation_reward: address_confirmation.into(),
+                    address_tracer_reward: address_tracer.into(),
+                    asset_confirmation_reward: asset_confirmation.into(),
+                    asset_tracer_reward: asset_tracer.into(),
+                }),
+                ..Default::default()
+            }
+        }
+        ("AuthorityChanged", [Token::Address(authority), ..]) => NetworkConfiguration {
+            authority: Some(format!("{authority:?}")),
+            ..Default::default()
+        },
+        // Initialized event does not carry the authority
+        ("Initialized", _) => {
+            let authority = match call_at_block(client.contract.authority(), block_number).await {
+                Some(authority) => format!("{authority:?}"),
+                None => client.get_authority().await?,
+            };
+
+            NetworkConfiguration {
+                authority: Some(authority),
+                ..Default::default()
+            }
+        }
+        (name, tokens) => {
+            tracing::warn!(name, ?tokens, "Unable to parse configuration event data");
+            return get_configuration_payload(client, &EventName::from_str(name)?).await;
+        }
+    };
+
+    tracing::info!(?configuration, "Configuration is changed");
+
+    Ok(Some(configuration.into()))
+}
+
+fn get_uints<const N: usize>(tokens: &[Token]) -> Result<[U256; N]> {
+    tokens
+        .iter()
+        .map(|token| {
+            token
+                .clone()
+                .into_uint()
+                .ok_or_else(|| anyhow::anyhow!("Unable to parse uint from {:?}", token))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Expected {} uint arguments, got {:?}", N, tokens))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        ethers::types::{Address, Block, Log, H256},
+        hapi_core::{HapiCoreNetwork, HapiCoreOptions},
+        mockito::{Matcher, Server, ServerGuard},
+        serde_json::{json, Value},
+    };
+
+    use super::*;
+
+    const CONTRACT_ADDRESS: &str = "0x2947F98C42597966a0ec25e92843c09ac18Fbab7";
+
+    fn hash(value: u64) -> String {
+        format!("{:#x}", H256::from_low_u64_be(value))
+    }
+
+    fn create_client(server: &ServerGuard) -> HapiCoreEvm {
+        HapiCoreEvm::new(HapiCoreOptions {
+            provider_url: server.url(),
+            contract_address: CONTRACT_ADDRESS.to_string(),
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network: HapiCoreNetwork::Ethereum,
+        })
+        .unwrap()
+    }
+
+    async fn mock_request(server: &mut ServerGuard, request: Value, response: Value) {
+        let mut body = json!({ "jsonrpc": "2.0", "id": 1 });
+        body.as_object_mut()
+            .unwrap()
+            .extend(response.as_object().unwrap().clone());
+
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .match_body(Matcher::PartialJson(request))
+            .create_async()
+            .await;
+    }
+
+    async fn mock_logs(
+        server: &mut ServerGuard,
+        from_block: u64,
+        to_block: u64,
+        error: Option<&str>,
+    ) {
+        let filter = Filter::default()
+            .address(CONTRACT_ADDRESS.parse::<Address>().unwrap())
+            .from_block(from_block)
+            .to_block(to_block);
+
+        let response = match error {
+            Some(message) => json!({ "error": { "code": -32005, "message": message } }),
+            None => json!({ "result": [] }),
+        };
+
+        mock_request(
+            server,
+            json!({ "method": "eth_getLogs", "params": [ filter ] }),
+            response,
+        )
+        .await;
+    }
+
+    async fn mock_block(server: &mut ServerGuard, number: u64, hash: u64) {
+        let block = Block::<H256> {
+            hash: Some(H256::from_low_u64_be(hash)),
+            ..Default::default()
+        };
+
+        mock_request(
+            server,
+            json!({
+                "method": "eth_getBlockByNumber",
+                "params": [ format!("{number:#x}"), false ]
+            }),
+            json!({ "result": block }),
+        )
+        .await;
+    }
+
+    #[test]
+    fn test_too_many_results_errors() {
+        assert!(is_too_many_results(
+            "(code: -32005, message: query returned more than 10000 results, data: None)"
+        ));
+        assert!(is_too_many_results("Response size exceeded"));
+        assert!(is_too_many_results(
+            "eth_getLogs block range is too large, max is 1k blocks"
+        ));
+
+        assert!(!is_too_many_results("daily request count exceeded"));
+        assert!(!is_too_many_results("limit exceeded"));
+        assert!(!is_too_many_results("rate limit exceeded, retry later"));
+    }
+
+    #[tokio::test]
+    async fn test_page_size_adjustment() {
+        let mut server = Server::new_async().await;
+        let client = create_client(&server);
+
+        // The page is halved until the node returns the logs, then grows back
+        mock_logs(
+            &mut server,
+            0,
+            7,
+            Some("query returned more than 10000 results"),
+        )
+        .await;
+        mock_logs(&mut server, 0, 3, None).await;
+
+        let mut page_size = 8;
+        let (jobs, to_block) = get_event_list(&client, 0, 100, &mut page_size)
+            .await
+            .unwrap();
+
+        assert!(jobs.is_empty());
+        assert_eq!(to_block, 3);
+        assert_eq!(page_size, 8);
+
+        // Rate limiting is not a reason to reduce the page
+        mock_logs(&mut server, 4, 11, Some("limit exceeded")).await;
+
+        assert!(get_event_list(&client, 4, 100, &mut page_size)
+            .await
+            .is_err());
+        assert_eq!(page_size, 8);
+
+        // The page can't be reduced below a single block
+        mock_logs(&mut server, 20, 20, Some("response size exceeded")).await;
+
+        let mut page_size = 1;
+        assert!(get_event_list(&client, 20, 100, &mut page_size)
+            .await
+            .is_err());
+        assert_eq!(page_size, 1);
+
+        // The page doesn't exceed the latest block
+        mock_logs(&mut server, 98, 100, None).await;
+
+        let mut page_size = 8;
+        let (_, to_block) = get_event_list(&client, 98, 100, &mut page_size)
+            .await
+            .unwrap();
+        assert_eq!(to_block, 100);
+    }
+
+    #[tokio::test]
+    async fn test_cursor_moves_by_page() {
+        let mut server = Server::new_async().await;
+        let client = create_client(&server);
+
+        mock_request(
+            &mut server,
+            json!({ "method": "eth_blockNumber" }),
+            json!({ "result": "0x64" }),
+        )
+        .await;
+        mock_logs(&mut server, 10, 14, None).await;
+        mock_logs(&mut server, 15, 24, None).await;
+        mock_block(&mut server, 14, 14).await;
+        mock_block(&mut server, 24, 24).await;
+
+        let mut block_hashes = BlockHashes::new();
+        let mut page_size = 5;
+
+        let artifacts = fetch_evm_jobs(
+            &client,
+            &IndexingCursor::Block(9),
+            0,
+            &mut block_hashes,
+            &mut page_size,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(artifacts.cursor, IndexingCursor::Block(14));
+        assert!(!artifacts.is_synced);
+        assert_eq!(page_size, 10);
+
+        let artifacts = fetch_evm_jobs(
+            &client,
+            &artifacts.cursor,
+            0,
+            &mut block_hashes,
+            &mut page_size,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(artifacts.cursor, IndexingCursor::Block(24));
+        assert!(!artifacts.is_synced);
+        assert_eq!(
+            block_hashes,
+            BlockHashes::from([(14, hash(14)), (24, hash(24))])
+        );
+
+        // The blocks within the confirmation depth are not indexed yet
+        let artifacts = fetch_evm_jobs(
+            &client,
+            &artifacts.cursor,
+            76,
+            &mut block_hashes,
+            &mut page_size,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(artifacts.cursor, IndexingCursor::Block(24));
+        assert!(artifacts.is_synced);
+        assert!(artifacts.jobs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_reorg_ancestor() {
+        let mut server = Server::new_async().await;
+
+        // Block 3 is replaced by the reorganization, blocks 1 and 2 are still canonical
+        mock_block(&mut server, 1, 1).await;
+        mock_block(&mut server, 2, 2).await;
+        mock_block(&mut server, 3, 30).await;
+
+        let client = create_client(&server);
+        let mut block_hashes = BlockHashes::from_iter((1..=3).map(|block| (block, hash(block))));
+
+        assert_eq!(
+            find_reorg_ancestor(&client, &mut block_hashes)
+                .await
+                .unwrap(),
+            Some(2)
+        );
+        assert_eq!(block_hashes.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+        // The remaining blocks are canonical
+        assert_eq!(
+            find_reorg_ancestor(&client, &mut block_hashes)
+                .await
+                .unwrap(),
+            None
+        );
+
+        // None of the tracked blocks is canonical
+        let mut block_hashes = BlockHashes::from([(3, hash(3))]);
+        assert!(find_reorg_ancestor(&client, &mut block_hashes)
+            .await
+            .is_err());
+        assert_eq!(block_hashes, BlockHashes::from([(3, hash(3))]));
+    }
+
+    #[test]
+    fn test_track_log_blocks() {
+        let log = |block: u64| {
+            IndexerJob::Log(Log {
+                block_number: Some(block.into()),
+                block_hash: Some(H256::from_low_u64_be(block)),
+                ..Default::default()
+            })
+        };
+
+        let mut block_hashes = BlockHashes::new();
+        track_log_blocks(&mut block_hashes, &[log(5), log(7), log(7)]);
+
+        assert_eq!(
+            block_hashes,
+            BlockHashes::from([(5, hash(5)), (7, hash(7))])
+        );
+
+        let jobs = (0..MAX_TRACKED_BLOCKS as u64 + 10)
+            .map(log)
+            .collect::<Vec<_>>();
+        track_log_blocks(&mut block_hashes, &jobs);
+
+        assert_eq!(block_hashes.len(), MAX_TRACKED_BLOCKS);
+        assert_eq!(block_hashes.first_key_value(), Some((&10, &hash(10))));
+    }
+}
@@ -1,6 +1,9 @@
 use {
     anyhow::Result,
-    hapi_core::{HapiCoreEvm, HapiCoreNear, HapiCoreNetwork, HapiCoreOptions, HapiCoreSolana},
+    hapi_core::{
+        client::events::EventName, HapiCore, HapiCoreEvm, HapiCoreNear, HapiCoreNetwork,
+        HapiCoreOptions, HapiCoreSolana,
+    },
     std::time::Duration,
     tokio::time::sleep,
     uuid::Uuid,
@@ -13,8 +16,8 @@ use super::{
 };
 
 use crate::indexer::{
-    push::{NetworkData, PushPayload},
-    IndexerJob, IndexingCursor,
+    push::{NetworkConfiguration, NetworkData, PushData, PushPayload},
+    BlockHashes, IndexerJob, IndexingCursor,
 };
 
 pub const DEFAULT_PAGE_SIZE: u64 = 500;
@@ -31,11 +34,15 @@ pub(crate) enum HapiClient {
 pub(crate) struct FetchingArtifacts {
     pub jobs: Vec<IndexerJob>,
     pub cursor: IndexingCursor,
+    /// Whether the cursor has reached the latest block available for indexing
+    pub is_synced: bool,
 }
 
 pub(crate) struct IndexerClient {
     client: HapiClient,
     fetching_delay: Duration,
+    confirmations: u64,
+    page_size: u64,
     network_data: NetworkData,
 }
 
@@ -45,6 +52,7 @@ impl IndexerClient {
         rpc_node_url: &str,
         contract_address: &str,
         fetching_delay: Duration,
+        confirmations: u64,
     ) -> Result<Self> {
         let options = HapiCoreOptions {
             provider_url: rpc_node_url.to_string(),
@@ -69,12 +77,27 @@ impl IndexerClient {
             client,
             network_data,
             fetching_delay,
+            confirmations,
+            page_size: PAGE_SIZE.to_owned(),
         })
     }
 
-    pub(crate) async fn fetch_jobs(&self, cursor: &IndexingCursor) -> Result<FetchingArtifacts> {
+    pub(crate) async fn fetch_jobs(
+        &mut self,
+        cursor: &IndexingCursor,
+        block_hashes: &mut BlockHashes,
+    ) -> Result<FetchingArtifacts> {
         let artifacts = match &self.client {
-            HapiClient::Evm(client) => fetch_evm_jobs(client, cursor).await?,
+            HapiClient::Evm(client) => {
+                fetch_evm_jobs(
+                    client,
+                    cursor,
+                    self.confirmations,
+                    block_hashes,
+                    &mut self.page_size,
+                )
+                .await?
+            }
             HapiClient::Solana(client) => {
                 fetch_solana_jobs(client, cursor, self.fetching_delay).await?
             }
@@ -108,3 +131,30 @@ impl IndexerClient {
         self.network_data.indexer_id
     }
 }
+
+/// Returns the part of network configuration that is changed by the event.
+/// Reads the current contract state, so it is only used when the event data is unavailable
+pub(super) async fn get_configuration_payload<C: HapiCore + Sync>(
+    client: &C,
+    event_name: &EventName,
+) -> Result<Option<PushData>> {
+    let configuration = match event_name {
+        EventName::Initialize | EventName::SetAuthority => NetworkConfiguration {
+            authority: Some(client.get_authority().await?),
+            ..Default::default()
+        },
+        EventName::UpdateStakeConfiguration => NetworkConfiguration {
+            stake_configuration: Some(client.get_stake_configuration().await?),
+            ..Default::default()
+        },
+        EventName::UpdateRewardConfiguration => NetworkConfiguration {
+            reward_configuration: Some(client.get_reward_configuration().await?),
+            ..Default::default()
+        },
+        _ => return Ok(None),
+    };
+
+    tracing::info!(?configuration, "Configuration is changed");
+
+    Ok(Some(configuration.into()))
+}
@@ -1,8 +1,22 @@
 use {
     anyhow::{bail, Result},
     hapi_core::{
-        client::{entities::asset::AssetId, events::EventName},
-        HapiCore, HapiCoreNear,
+        client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
+            entities::{
+                address::Address,
+                asset::Asset,
+                case::{Case, CaseStatus},
+                category::Category,
+                reporter::{Reporter, ReporterRole},
+            },
+            events::EventName,
+        },
+        HapiCoreNear,
+    },
+    hapi_core_near::{
+        AddressView as NearAddress, AssetView as NearAsset, Case as NearCase,
+        Reporter as NearReporter,
     },
     near_jsonrpc_client::methods::{
         EXPERIMENTAL_changes::RpcStateChangesInBlockByTypeRequest,
@@ -16,6 +30,8 @@ use {
             ActionView, ReceiptEnumView, ReceiptView, StateChangeCauseView, StateChangesRequestView,
         },
     },
+    serde::Deserialize,
+    serde_json::{json, Value},
     std::{cmp::min, collections::HashSet},
     uuid::Uuid,
 };
@@ -23,7 +39,7 @@ use {
 use crate::{
     indexer::{
         client::indexer_client::PAGE_SIZE,
-        push::{NetworkData, PushEvent, PushPayload},
+        push::{NetworkConfiguration, NetworkData, PushData, PushEvent, PushPayload},
         IndexerJob,
     },
     IndexingCursor,
@@ -38,6 +54,31 @@ pub struct NearReceipt {
     pub timestamp: u64,
 }
 
+/// Arguments of `create_reporter` and `update_reporter` methods
+#[derive(Deserialize)]
+struct ReporterArgs {
+    account_id: String,
+    name: String,
+    role: ReporterRole,
+    url: String,
+}
+
+/// Arguments of `create_case` and `update_case` methods
+#[derive(Deserialize)]
+struct CaseArgs {
+    name: String,
+    url: String,
+    status: Option<CaseStatus>,
+}
+
+/// Arguments of address and asset creation and update methods
+#[derive(Deserialize)]
+struct RiskArgs {
+    case_id: String,
+    category: Category,
+    risk_score: u8,
+}
+
 async fn get_receipts_list(
     client: &HapiCoreNear,
     start_block: u64,
@@ -128,6 +169,7 @@ pub(super) async fn fetch_near_jobs(
         return Ok(FetchingArtifacts {
             jobs: event_list,
             cursor: IndexingCursor::Block(final_block),
+            is_synced: final_block == latest_block,
         });
     }
 
@@ -136,6 +178,7 @@ pub(super) async fn fetch_near_jobs(
     Ok(FetchingArtifacts {
         jobs: vec![],
         cursor: current_cursor.clone(),
+        is_synced: true,
     })
 }
 
@@ -154,7 +197,7 @@ pub(super) async fn process_near_job(
         })
         .await?;
 
-    if let Some((method, args)) = get_method_from_receipt(&receipt_view) {
+    if let Some((action_index, method, args)) = get_method_from_receipt(&receipt_view) {
         let event_name: EventName = {
             if method == "ft_on_transfer" {
                 // because activation in NEAR is done by ft_transfer_call
@@ -170,58 +213,162 @@ pub(super) async fn process_near_job(
             }
         };
 
-        let data = match event_name {
-            EventName::CreateReporter
-            | EventName::UpdateReporter
-            | EventName::DeactivateReporter
-            | EventName::Unstake => {
+        let block_height = receipt.block_height;
+
+        let data: PushData = match event_name {
+            EventName::CreateReporter | EventName::UpdateReporter => {
                 tracing::info!("Reporter updated");
 
                 let id = get_id_from_args(&args).await?;
-                client.get_reporter(&id.to_string()).await?.into()
+                let mut reporter: Reporter = view_at_block::<NearReporter>(
+                    client,
+                    "get_reporter",
+                    json!({ "id": id.as_u128().to_string() }),
+                    block_height,
+                )
+                .await?
+                .try_into()?;
+
+                let args: ReporterArgs = serde_json::from_slice(&args)?;
+                reporter.account = args.account_id;
+                reporter.name = args.name;
+                reporter.role = args.role;
+                reporter.url = args.url;
+
+                reporter.into()
+            }
+            EventName::DeactivateReporter | EventName::Unstake => {
+                tracing::info!("Reporter updated");
+
+                // Methods are called by the reporter account itself
+                let account_id = receipt_view.predecessor_id.to_string();
+                get_reporter_by_account(client, &account_id, block_height)
+                    .await?
+                    .into()
             }
             EventName::ActivateReporter => {
                 tracing::info!("Reporter activated");
 
                 let account_id = get_field_from_args(&args, "sender_id")?;
-                client.get_reporter_by_account(&account_id).await?.into()
+                get_reporter_by_account(client, &account_id, block_height)
+                    .await?
+                    .into()
             }
             EventName::CreateCase | EventName::UpdateCase => {
                 tracing::info!("Case is created or modified");
 
                 let id = get_id_from_args(&args).await?;
-                client.get_case(&id.to_string()).await?.into()
-            }
-            EventName::CreateAddress | EventName::UpdateAddress => {
-                tracing::info!("Address is created or modified");
+                let mut case: Case = view_at_block::<NearCase>(
+                    client,
+                    "get_case",
+                    json!({ "id": id.as_u128().to_string() }),
+                    block_height,
+                )
+                .await?
+                .try_into()?;
+
+                let args: CaseArgs = serde_json::from_slice(&args)?;
+                case.name = args.name;
+                case.url = args.url;
+                if let Some(status) = args.status {
+                    case.status = status;
+                }
 
-                let address = get_field_from_args(&args, "address")?;
-                client.get_address(&address).await?.into()
+                case.into()
             }
-            EventName::ConfirmAddress | EventName::ConfirmAsset => {
-                tracing::info!("Confirmation is received");
-                return Ok(None);
+            EventName::CreateAddress | EventName::UpdateAddress | EventName::ConfirmAddress => {
+                tracing::info!("Address is created, modified or confirmed");
+
+                let addr = get_field_from_args(&args, "address")?;
+                let mut address: Address = view_at_block::<NearAddress>(
+                    client,
+                    "get_address",
+                    json!({ "address": addr }),
+                    block_height,
+                )
+                .await?
+                .try_into()?;
+
+                if event_name != EventName::ConfirmAddress {
+                    let args: RiskArgs = serde_json::from_slice(&args)?;
+                    address.case_id = Uuid::from_u128(args.case_id.parse()?);
+                    address.category = args.category;
+                    address.risk = args.risk_score;
+                }
+
+                address.into()
             }
-            EventName::CreateAsset | EventName::UpdateAsset => {
-                tracing::info!("Asset is created or modified");
+            EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset => {
+                tracing::info!("Asset is created, modified or confirmed");
+
                 let addr = get_field_from_args(&args, "address")?;
                 let asset_id = get_field_from_args(&args, "id")?;
-                client
-                    .get_asset(&addr, &asset_id.parse::<AssetId>()?)
-                    .await?
-                    .into()
+                let mut asset: Asset = view_at_block::<NearAsset>(
+                    client,
+                    "get_asset",
+                    json!({ "address": addr, "id": asset_id }),
+                    block_height,
+                )
+                .await?
+                .try_into()?;
+
+                if event_name != EventName::ConfirmAsset {
+                    let args: RiskArgs = serde_json::from_slice(&args)?;
+                    asset.case_id = Uuid::from_u128(args.case_id.parse()?);
+                    asset.category = args.category;
+                    asset.risk = args.risk_score;
+                }
+
+                asset.into()
             }
 
-            EventName::UpdateStakeConfiguration
+            EventName::Initialize
+            | EventName::UpdateStakeConfiguration
             | EventName::UpdateRewardConfiguration
             | EventName::SetAuthority => {
-                tracing::info!("Configuration is changed");
-                return Ok(None);
+                let configuration = match event_name {
+                    // Contract authority is set to the initializer account
+                    EventName::Initialize => NetworkConfiguration {
+                        authority: Some(receipt_view.predecessor_id.to_string()),
+                        ..Default::default()
+                    },
+                    EventName::SetAuthority => NetworkConfiguration {
+                        authority: Some(get_field_from_args(&args, "authority")?),
+                        ..Default::default()
+                    },
+                    EventName::UpdateStakeConfiguration => NetworkConfiguration {
+                        stake_configuration: Some(get_config_from_args::<StakeConfiguration>(
+                            &args,
+                            "stake_configuration",
+                        )?),
+                        ..Default::default()
+                    },
+                    _ => NetworkConfiguration {
+                        reward_configuration: Some(get_config_from_args::<RewardConfiguration>(
+                            &args,
+                            "reward_configuration",
+                        )?),
+                        ..Default::default()
+                    },
+                };
+
+                tracing::info!(?configuration, "Configuration is changed");
+
+                configuration.into()
             }
-            EventName::Initialize => {
-                tracing::info!("Contract initialized");
-                return Ok(None);
+        };
+
+        // Confirmations are sent by the reporter account itself
+        let confirmed_by = match event_name {
+            EventName::ConfirmAddress | EventName::ConfirmAsset => {
+                let account_id = receipt_view.predecessor_id.to_string();
+                Some(
+                    get_reporter_by_account(client, &account_id, block_height)
+                        .await?
+                        .id,
+                )
             }
+            _ => None,
         };
 
         return Ok(Some(vec![PushPayload {
@@ -229,8 +376,9 @@ pub(super) async fn process_near_job(
             event: PushEvent {
                 name: event_name,
                 tx_hash: receipt.hash.to_string(),
-                tx_index: 0,
+                tx_index: action_index as u64,
                 timestamp: receipt.timestamp,
+                confirmed_by,
             },
             data,
         }]));
@@ -246,7 +394,8 @@ fn get_hash_from_cause(cause: &StateChangeCauseView) -> CryptoHash {
     }
 }
 
-fn get_method_from_receipt(receipt: &ReceiptView) -> Option<(String, FunctionArgs)> {
+/// Returns the index of the function call action in the receipt with its method and arguments
+fn get_method_from_receipt(receipt: &ReceiptView) -> Option<(usize, String, FunctionArgs)> {
     match &receipt.receipt {
         ReceiptEnumView::Action {
             signer_id: _,
@@ -255,19 +404,64 @@ fn get_method_from_receipt(receipt: &ReceiptView) -> Option<(String, FunctionArg
             output_data_receivers: _,
             input_data_ids: _,
             actions,
-        } => match &actions[0] {
-            ActionView::FunctionCall {
-                method_name,
-                args,
-                gas: _,
-                deposit: _,
-            } => Some((method_name.clone(), args.clone())),
-            _ => None,
-        },
+        } => actions
+            .iter()
+            .enumerate()
+            .find_map(|(index, action)| match action {
+                ActionView::FunctionCall {
+                    method_name,
+                    args,
+                    gas: _,
+                    deposit: _,
+                } => Some((index, method_name.clone(), args.clone())),
+                _ => None,
+            }),
         _ => None,
     }
 }
 
+/// Calls the contract view method against the state at the receipt block,
+/// falling back to the current state if the node is unable to serve it
+async fn view_at_block<T: for<'a> Deserialize<'a>>(
+    client: &HapiCoreNear,
+    method: &str,
+    args: Value,
+    block_height: u64,
+) -> Result<T> {
+    let mut request = client.view_request(method, Some(args.clone()));
+    request.block_reference = BlockReference::BlockId(BlockId::Height(block_height));
+
+    match client.get_response::<T>(request).await {
+        Ok(value) => Ok(value),
+        Err(error) => {
+            tracing::warn!(
+                block_height,
+                %error,
+                "Historical state is unavailable, falling back to the current state"
+            );
+
+            Ok(client
+                .get_response::<T>(client.view_request(method, Some(args)))
+                .await?)
+        }
+    }
+}
+
+async fn get_reporter_by_account(
+    client: &HapiCoreNear,
+    account_id: &str,
+    block_height: u64,
+) -> Result<Reporter> {
+    Ok(view_at_block::<NearReporter>(
+        client,
+        "get_reporter_by_account",
+        json!({ "account_id": account_id }),
+        block_height,
+    )
+    .await?
+    .try_into()?)
+}
+
 fn get_field_from_args(args: &FunctionArgs, field: &str) -> Result<String> {
     let json: serde_json::Value = serde_json::from_slice(args)?;
 
@@ -287,3 +481,9 @@ async fn get_id_from_args(args: &FunctionArgs) -> Result<Uuid> {
         Err(anyhow::anyhow!("Failed to parse id from {:?}", json))
     }
 }
+
+fn get_config_from_args<T: for<'a> Deserialize<'a>>(args: &FunctionArgs, field: &str) -> Result<T> {
+    let json: Value = serde_json::from_slice(args)?;
+
+    Ok(serde_json::from_value(json[field].clone())?)
+}
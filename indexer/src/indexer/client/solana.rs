@@ -1,31 +1,48 @@
 use {
-    anyhow::{bail, Result},
+    anyhow::{anyhow, bail, Result},
     hapi_core::HapiCoreSolana,
     hapi_core::{
         client::{
-            entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
+            configuration::{RewardConfiguration, StakeConfiguration},
+            entities::{
+                address::Address,
+                asset::{Asset, AssetId},
+                case::{Case, CaseStatus},
+                reporter::{Reporter, ReporterStatus},
+            },
             events::EventName,
-            solana::DecodedInstruction,
+            result::ClientError,
+            solana::{DecodedInstruction, DecodedInstructionData, InstructionData},
         },
         get_solana_account,
     },
+    hapi_core_solana::{
+        bytes_to_string, RewardConfiguration as SolanaRewardConfiguration,
+        StakeConfiguration as SolanaStakeConfiguration,
+    },
     solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config,
     solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature},
     std::time::Duration,
     std::{collections::VecDeque, str::FromStr},
     tokio::time::sleep,
+    uuid::Uuid,
 };
 
 use crate::indexer::{
-    client::indexer_client::{FetchingArtifacts, PAGE_SIZE},
-    push::{NetworkData, PushData, PushEvent, PushPayload},
+    client::indexer_client::{get_configuration_payload, FetchingArtifacts, PAGE_SIZE},
+    push::{NetworkConfiguration, NetworkData, PushData, PushEvent, PushPayload},
     IndexerJob, IndexingCursor,
 };
 
+const AUTHORITY_ACCOUNT_INDEX: usize = 0;
 const REPORTER_ACCOUNT_INDEX: usize = 2;
 const CASE_ACCOUNT_INDEX: usize = 3;
 const ADDRESS_ACCOUNT_INDEX: usize = 4;
 const ASSET_ACCOUNT_INDEX: usize = 4;
+const NEW_AUTHORITY_ACCOUNT_INDEX: usize = 2;
+const CONFIGURATION_MINT_ACCOUNT_INDEX: usize = 2;
+const NETWORK_REWARD_MINT_ACCOUNT_INDEX: usize = 2;
+const NETWORK_STAKE_MINT_ACCOUNT_INDEX: usize = 3;
 
 async fn get_signature_list(
     client: &HapiCoreSolana,
@@ -101,6 +118,7 @@ pub(super) async fn fetch_solana_jobs(
     Ok(FetchingArtifacts {
         jobs: signature_list,
         cursor: new_cursor,
+        is_synced: true,
     })
 }
 
@@ -124,6 +142,8 @@ pub(super) async fn process_solana_job(
 
     for instruction in instructions {
         if let Some(data) = get_instruction_data(client, &instruction).await? {
+            let confirmed_by = get_confirming_reporter(client, &instruction).await?;
+
             tracing::info!(
                 name = instruction.name.to_string(),
                 signature,
@@ -140,6 +160,7 @@ pub(super) async fn process_solana_job(
                     tx_hash: signature.to_string(),
                     tx_index: instruction.id.into(),
                     timestamp: instruction.blocktime,
+                    confirmed_by,
                 },
                 data,
             });
@@ -149,64 +170,357 @@ pub(super) async fn process_solana_job(
     Ok(Some(payloads))
 }
 
+// Confirmations are signed by the reporter account, which is passed to the instruction
+async fn get_confirming_reporter(
+    client: &HapiCoreSolana,
+    instruction: &DecodedInstruction,
+) -> Result<Option<Uuid>> {
+    if !matches!(
+        instruction.name,
+        EventName::ConfirmAddress | EventName::ConfirmAsset
+    ) {
+        return Ok(None);
+    }
+
+    let account = get_pubkey(&instruction.account_keys, REPORTER_ACCOUNT_INDEX)?;
+    let reporter = get_solana_account!(client, &account, Reporter)?;
+
+    Ok(Some(reporter.id))
+}
+
+// Reads the current state of the instruction account. Accounts can't be used to restore
+// the state at the moment of the instruction, so they are only read for the immutable
+// identifiers and for the fields that are absent in the instruction. The instruction
+// is skipped only if the account does not exist anymore, other errors are returned,
+// so that the cursor stays on the transaction and it's processed again
+macro_rules! fetch_account {
+    ($client:expr, $instruction:expr, $index:expr, $account:ident) => {{
+        let account = get_pubkey(&$instruction.account_keys, $index)?;
+        let result: Result<$account> =
+            async { Ok(get_solana_account!($client, &account, $account)?) }.await;
+
+        match result {
+            Ok(data) => data,
+            Err(e) if is_account_not_found(&e) => {
+                tracing::warn!(
+                    name = $instruction.name.to_string(),
+                    %account,
+                    "Instruction account does not exist, skipping instruction",
+                );
+
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        }
+    }};
+}
+
+fn is_account_not_found(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<ClientError>(),
+        Some(ClientError::AccountNotFound)
+    )
+}
+
+// Payloads are built from the instruction arguments when the instruction is decoded
+// (the `decode` feature of the client), otherwise the account state is used
 async fn get_instruction_data(
     client: &HapiCoreSolana,
     instruction: &DecodedInstruction,
 ) -> Result<Option<PushData>> {
-    match instruction.name {
-        EventName::CreateReporter
-        | EventName::UpdateReporter
-        | EventName::ActivateReporter
-        | EventName::DeactivateReporter
-        | EventName::Unstake => {
-            let account = get_pubkey(&instruction.account_keys, REPORTER_ACCOUNT_INDEX)?;
-            let reporter = get_solana_account!(client, &account, Reporter)?;
+    let decoded = match &instruction.data {
+        InstructionData::Decoded(data) => data,
+        InstructionData::Raw(_) => {
+            tracing::debug!(
+                name = instruction.name.to_string(),
+                "Instruction is not decoded, using the account state"
+            );
 
-            tracing::info!(?reporter.id, "Reporter is created or modified");
+            return get_account_data(client, instruction).await;
+        }
+    };
 
-            return Ok(Some(reporter.into()));
+    let data = match decoded {
+        DecodedInstructionData::CreateReporter(data) => {
+            let reporter = Reporter {
+                id: Uuid::from_u128(data.reporter_id),
+                account: data.account.to_string(),
+                role: (data.role.clone() as u8).try_into()?,
+                status: ReporterStatus::Inactive,
+                name: data.name.clone(),
+                url: data.url.clone(),
+                stake: 0_u64.into(),
+                unlock_timestamp: 0,
+            };
+
+            tracing::info!(?reporter.id, "Reporter is created");
+
+            reporter.into()
         }
+        DecodedInstructionData::UpdateReporter(_)
+        | DecodedInstructionData::ActivateReporter
+        | DecodedInstructionData::DeactivateReporter
+        | DecodedInstructionData::Unstake => {
+            let mut reporter =
+                fetch_account!(client, instruction, REPORTER_ACCOUNT_INDEX, Reporter);
+
+            match decoded {
+                DecodedInstructionData::UpdateReporter(data) => {
+                    reporter.account = data.account.to_string();
+                    reporter.name = data.name.clone();
+                    reporter.role = (data.role.clone() as u8).try_into()?;
+                    reporter.url = data.url.clone();
+
+                    log_account_fallback(instruction, "status, stake, unlock_timestamp");
+                }
+                DecodedInstructionData::ActivateReporter => {
+                    reporter.status = ReporterStatus::Active;
+
+                    log_account_fallback(instruction, "stake");
+                }
+                DecodedInstructionData::DeactivateReporter => {
+                    reporter.status = ReporterStatus::Unstaking;
+
+                    log_account_fallback(instruction, "unlock_timestamp");
+                }
+                _ => {
+                    reporter.status = ReporterStatus::Inactive;
+                    reporter.stake = 0_u64.into();
+                    reporter.unlock_timestamp = 0;
+                }
+            }
 
-        EventName::CreateCase | EventName::UpdateCase => {
-            let account = get_pubkey(&instruction.account_keys, CASE_ACCOUNT_INDEX)?;
-            let case = get_solana_account!(client, &account, Case)?;
+            tracing::info!(?reporter.id, "Reporter is modified");
+
+            reporter.into()
+        }
+
+        DecodedInstructionData::CreateCase(data) => {
+            let reporter = fetch_account!(client, instruction, REPORTER_ACCOUNT_INDEX, Reporter);
+
+            let case = Case {
+                id: Uuid::from_u128(data.case_id),
+                name: data.name.clone(),
+                url: data.url.clone(),
+                status: CaseStatus::Open,
+                reporter_id: reporter.id,
+            };
+
+            tracing::info!(?case.id, "Case is created");
+
+            case.into()
+        }
+        DecodedInstructionData::UpdateCase(data) => {
+            let mut case = fetch_account!(client, instruction, CASE_ACCOUNT_INDEX, Case);
 
-            tracing::info!(?case.id, "Case is created or modified");
+            case.name = data.name.clone();
+            case.url = data.url.clone();
+            case.status = (data.status.clone() as u8).try_into()?;
 
-            return Ok(Some(case.into()));
+            tracing::info!(?case.id, "Case is modified");
+
+            case.into()
+        }
+
+        DecodedInstructionData::CreateAddress(data) => {
+            let reporter = fetch_account!(client, instruction, REPORTER_ACCOUNT_INDEX, Reporter);
+            let case = fetch_account!(client, instruction, CASE_ACCOUNT_INDEX, Case);
+
+            let address = Address {
+                address: decode_string(&data.address)?,
+                case_id: case.id,
+                reporter_id: reporter.id,
+                risk: data.risk,
+                category: (data.category.clone() as u8).try_into()?,
+                confirmations: 0,
+            };
+
+            tracing::info!(address.address, "Address is created");
+
+            address.into()
         }
+        DecodedInstructionData::UpdateAddress(data) => {
+            let case = fetch_account!(client, instruction, CASE_ACCOUNT_INDEX, Case);
+            let mut address = fetch_account!(client, instruction, ADDRESS_ACCOUNT_INDEX, Address);
 
-        EventName::CreateAddress | EventName::UpdateAddress => {
-            let account = get_pubkey(&instruction.account_keys, ADDRESS_ACCOUNT_INDEX)?;
-            let address = get_solana_account!(client, &account, Address)?;
+            address.case_id = case.id;
+            address.category = (data.category.clone() as u8).try_into()?;
+            address.risk = data.risk;
 
-            tracing::info!(address.address, "Address is created or modified");
+            log_account_fallback(instruction, "confirmations");
+            tracing::info!(address.address, "Address is modified");
 
-            return Ok(Some(address.into()));
+            address.into()
         }
-        EventName::CreateAsset | EventName::UpdateAsset => {
-            let account = get_pubkey(&instruction.account_keys, ASSET_ACCOUNT_INDEX)?;
-            let asset = get_solana_account!(client, &account, Asset)?;
+        DecodedInstructionData::ConfirmAddress(_) => {
+            let address = fetch_account!(client, instruction, ADDRESS_ACCOUNT_INDEX, Address);
 
-            tracing::info!(asset.address, ?asset.asset_id, "Asset is created or modified");
+            log_account_fallback(instruction, "confirmations, case_id, risk, category");
+            tracing::info!(address.address, "Address is confirmed");
 
-            return Ok(Some(asset.into()));
+            address.into()
         }
 
-        EventName::Initialize => {
-            tracing::info!("Network created");
+        DecodedInstructionData::CreateAsset(data) => {
+            let reporter = fetch_account!(client, instruction, REPORTER_ACCOUNT_INDEX, Reporter);
+            let case = fetch_account!(client, instruction, CASE_ACCOUNT_INDEX, Case);
+
+            let asset = Asset {
+                address: decode_string(&data.addr)?,
+                asset_id: AssetId::from_str(&decode_string(&data.asset_id)?)?,
+                case_id: case.id,
+                reporter_id: reporter.id,
+                risk: data.risk_score,
+                category: (data.category.clone() as u8).try_into()?,
+                confirmations: 0,
+            };
+
+            tracing::info!(asset.address, ?asset.asset_id, "Asset is created");
+
+            asset.into()
         }
-        EventName::UpdateStakeConfiguration
+        DecodedInstructionData::UpdateAsset(data) => {
+            let case = fetch_account!(client, instruction, CASE_ACCOUNT_INDEX, Case);
+            let mut asset = fetch_account!(client, instruction, ASSET_ACCOUNT_INDEX, Asset);
+
+            asset.case_id = case.id;
+            asset.category = (data.category.clone() as u8).try_into()?;
+            asset.risk = data.risk_score;
+
+            log_account_fallback(instruction, "confirmations");
+            tracing::info!(asset.address, ?asset.asset_id, "Asset is modified");
+
+            asset.into()
+        }
+        DecodedInstructionData::ConfirmAsset(_) => {
+            let asset = fetch_account!(client, instruction, ASSET_ACCOUNT_INDEX, Asset);
+
+            log_account_fallback(instruction, "confirmations, case_id, risk, category");
+            tracing::info!(asset.address, ?asset.asset_id, "Asset is confirmed");
+
+            asset.into()
+        }
+
+        DecodedInstructionData::CreateNetwork(_)
+        | DecodedInstructionData::UpdateStakeConfiguration(_)
+        | DecodedInstructionData::UpdateRewardConfiguration(_)
+        | DecodedInstructionData::SetAuthority => {
+            return get_configuration_from_instruction(&instruction.account_keys, decoded);
+        }
+    };
+
+    Ok(Some(data))
+}
+
+// Builds the payload from the current account state, used for raw instructions only
+async fn get_account_data(
+    client: &HapiCoreSolana,
+    instruction: &DecodedInstruction,
+) -> Result<Option<PushData>> {
+    let data = match instruction.name {
+        EventName::CreateReporter
+        | EventName::UpdateReporter
+        | EventName::ActivateReporter
+        | EventName::DeactivateReporter
+        | EventName::Unstake => {
+            fetch_account!(client, instruction, REPORTER_ACCOUNT_INDEX, Reporter).into()
+        }
+        EventName::CreateCase | EventName::UpdateCase => {
+            fetch_account!(client, instruction, CASE_ACCOUNT_INDEX, Case).into()
+        }
+        EventName::CreateAddress | EventName::UpdateAddress | EventName::ConfirmAddress => {
+            fetch_account!(client, instruction, ADDRESS_ACCOUNT_INDEX, Address).into()
+        }
+        EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset => {
+            fetch_account!(client, instruction, ASSET_ACCOUNT_INDEX, Asset).into()
+        }
+        EventName::Initialize
+        | EventName::UpdateStakeConfiguration
         | EventName::UpdateRewardConfiguration
         | EventName::SetAuthority => {
-            tracing::info!("Configuration is changed");
-        }
-        EventName::ConfirmAddress | EventName::ConfirmAsset => {
-            tracing::info!("Confirmation is received");
+            return get_configuration_payload(client, &instruction.name).await;
         }
+    };
+
+    Ok(Some(data))
+}
+
+fn log_account_fallback(instruction: &DecodedInstruction, fields: &str) {
+    tracing::debug!(
+        name = instruction.name.to_string(),
+        fields,
+        "Fields are absent in the instruction, using the current account state",
+    );
+}
+
+fn decode_string(bytes: &[u8]) -> Result<String> {
+    bytes_to_string(bytes).map_err(|e| anyhow!("Invalid string in instruction data: {e}"))
+}
+
+fn get_configuration_from_instruction(
+    accounts: &[String],
+    data: &DecodedInstructionData,
+) -> Result<Option<PushData>> {
+    let configuration = match data {
+        DecodedInstructionData::CreateNetwork(data) => NetworkConfiguration {
+            authority: Some(get_pubkey(accounts, AUTHORITY_ACCOUNT_INDEX)?.to_string()),
+            stake_configuration: Some(get_stake_configuration(
+                &data.stake_info,
+                &get_pubkey(accounts, NETWORK_STAKE_MINT_ACCOUNT_INDEX)?,
+            )),
+            reward_configuration: Some(get_reward_configuration(
+                &data.reward_info,
+                &get_pubkey(accounts, NETWORK_REWARD_MINT_ACCOUNT_INDEX)?,
+            )),
+        },
+        DecodedInstructionData::SetAuthority => NetworkConfiguration {
+            authority: Some(get_pubkey(accounts, NEW_AUTHORITY_ACCOUNT_INDEX)?.to_string()),
+            ..Default::default()
+        },
+        DecodedInstructionData::UpdateStakeConfiguration(data) => NetworkConfiguration {
+            stake_configuration: Some(get_stake_configuration(
+                data,
+                &get_pubkey(accounts, CONFIGURATION_MINT_ACCOUNT_INDEX)?,
+            )),
+            ..Default::default()
+        },
+        DecodedInstructionData::UpdateRewardConfiguration(data) => NetworkConfiguration {
+            reward_configuration: Some(get_reward_configuration(
+                data,
+                &get_pubkey(accounts, CONFIGURATION_MINT_ACCOUNT_INDEX)?,
+            )),
+            ..Default::default()
+        },
+        _ => return Ok(None),
+    };
+
+    tracing::info!(?configuration, "Configuration is changed");
+
+    Ok(Some(configuration.into()))
+}
+
+fn get_stake_configuration(data: &SolanaStakeConfiguration, mint: &Pubkey) -> StakeConfiguration {
+    StakeConfiguration {
+        token: mint.to_string(),
+        unlock_duration: data.unlock_duration,
+        validator_stake: data.validator_stake.into(),
+        tracer_stake: data.tracer_stake.into(),
+        publisher_stake: data.publisher_stake.into(),
+        authority_stake: data.authority_stake.into(),
     }
+}
 
-    Ok(None)
+fn get_reward_configuration(
+    data: &SolanaRewardConfiguration,
+    mint: &Pubkey,
+) -> RewardConfiguration {
+    RewardConfiguration {
+        token: mint.to_string(),
+        address_confirmation_reward: data.address_confirmation_reward.into(),
+        address_tracer_reward: data.address_tracer_reward.into(),
+        asset_confirmation_reward: data.asset_confirmation_reward.into(),
+        asset_tracer_reward: data.asset_tracer_reward.into(),
+    }
 }
 
 fn get_pubkey(accounts: &[String], index: usize) -> Result<Pubkey> {
@@ -4,11 +4,12 @@ use crate::{Indexer, IndexingCursor};
 
 impl Indexer {
     pub(crate) async fn send_heartbeat(&self, cursor: &IndexingCursor) -> Result<()> {
-        let url = format!(
-            "{}/indexer/{}/heartbeat",
-            self.webhook_url,
-            self.client.get_id()
-        );
+        // Heartbeats are only sent to the explorer webhook
+        let Some(webhook_url) = &self.webhook_url else {
+            return Ok(());
+        };
+
+        let url = format!("{}/indexer/{}/heartbeat", webhook_url, self.client.get_id());
 
         let response = self
             .web_client
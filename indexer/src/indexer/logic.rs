@@ -6,11 +6,12 @@ use {
 
 use crate::{
     configuration::IndexerConfiguration,
-    indexer::{jwt::get_id_from_jwt, push::NetworkData},
+    indexer::{jwt::get_id_from_jwt, push::NetworkData, sink::create_sinks},
 };
 
 use super::{
-    now, Indexer, IndexerClient, IndexerJob, IndexerState, IndexingCursor, PersistedState,
+    now, BlockHashes, Indexer, IndexerClient, IndexerJob, IndexerState, IndexingCursor,
+    PersistedState,
 };
 
 impl Indexer {
@@ -26,16 +27,21 @@ impl Indexer {
             &cfg.rpc_node_url,
             &cfg.contract_address,
             cfg.fetching_delay,
+            cfg.confirmations,
         )?;
+        let web_client = reqwest::Client::new();
+        let sinks = create_sinks(&cfg, &web_client)?;
 
         Ok(Self {
             wait_interval_ms: cfg.wait_interval_ms,
             state: Arc::new(Mutex::new(IndexerState::Init)),
             jobs: VecDeque::new(),
+            block_hashes: BlockHashes::new(),
             client,
             state_file: PathBuf::from(cfg.state_file),
-            web_client: reqwest::Client::new(),
+            web_client,
             webhook_url: cfg.webhook_url,
+            sinks,
             jwt_token: cfg.jwt_token,
         })
     }
@@ -74,6 +80,7 @@ impl Indexer {
     async fn handle_init(&mut self) -> Result<IndexerState> {
         if let Ok(state) = PersistedState::from_file(&self.state_file) {
             tracing::info!("Found persisted state");
+            self.block_hashes = state.block_hashes;
 
             if state.cursor != IndexingCursor::None {
                 tracing::info!(cursor = ?state.cursor, "Found cursor");
@@ -89,16 +96,31 @@ impl Indexer {
         })
     }
 
+    fn persist_state(&self, cursor: &IndexingCursor) -> Result<()> {
+        PersistedState {
+            cursor: cursor.clone(),
+            block_hashes: self.block_hashes.clone(),
+        }
+        .to_file(&self.state_file)
+    }
+
     fn get_updated_state(
         &self,
         jobs: &[IndexerJob],
         old_cursor: IndexingCursor,
         new_cursor: IndexingCursor,
+        is_synced: bool,
     ) -> Result<IndexerState> {
         if !jobs.is_empty() {
             tracing::info!(%new_cursor, "Earliest cursor found");
 
             Ok(IndexerState::Processing { cursor: new_cursor })
+        } else if !is_synced {
+            tracing::info!(%new_cursor, "No jobs in the page, fetching the next one");
+
+            self.persist_state(&new_cursor)?;
+
+            Ok(IndexerState::CheckForUpdates { cursor: new_cursor })
         } else if old_cursor == IndexingCursor::None {
             Ok(IndexerState::Stopped {
                 message: "No valid transactions found on the contract address".to_string(),
@@ -107,10 +129,7 @@ impl Indexer {
             let timestamp = now()? + self.wait_interval_ms.as_secs();
             tracing::info!(timestamp, %new_cursor, "New jobs not found, waiting until next check");
 
-            PersistedState {
-                cursor: new_cursor.clone(),
-            }
-            .to_file(&self.state_file)?;
+            self.persist_state(&new_cursor)?;
 
             Ok(IndexerState::Waiting {
                 until: timestamp,
@@ -121,8 +140,16 @@ impl Indexer {
 
     #[tracing::instrument(name = "check_for_updates", skip(self))]
     async fn handle_check_for_updates(&mut self, cursor: IndexingCursor) -> Result<IndexerState> {
-        let artifacts = self.client.fetch_jobs(&cursor).await?;
-        let state = self.get_updated_state(&artifacts.jobs, cursor, artifacts.cursor.clone())?;
+        let artifacts = self
+            .client
+            .fetch_jobs(&cursor, &mut self.block_hashes)
+            .await?;
+        let state = self.get_updated_state(
+            &artifacts.jobs,
+            cursor,
+            artifacts.cursor.clone(),
+            artifacts.is_synced,
+        )?;
 
         self.jobs.extend(artifacts.jobs);
 
@@ -132,26 +159,19 @@ impl Indexer {
     #[tracing::instrument(name = "process", skip(self))]
     async fn handle_process(&mut self, cursor: IndexingCursor) -> Result<IndexerState> {
         if let Some(job) = self.jobs.pop_front() {
+            // Payloads are persisted before the cursor moves, so they are not lost on restart
             if let Some(payload) = self.client.handle_process(&job).await? {
-                for event in payload {
-                    self.send_webhook(&event).await?;
-                }
+                self.push_payloads(payload)?;
             }
 
             let new_cursor = IndexingCursor::try_from(job.clone())?;
-
-            PersistedState {
-                cursor: new_cursor.clone(),
-            }
-            .to_file(&self.state_file)?;
+            self.persist_state(&new_cursor)?;
+            self.flush_sinks().await?;
 
             return Ok(IndexerState::Processing { cursor });
         };
 
-        PersistedState {
-            cursor: cursor.clone(),
-        }
-        .to_file(&self.state_file)?;
+        self.persist_state(&cursor)?;
 
         tracing::trace!("No more jobs in the queue");
 
@@ -160,7 +180,11 @@ impl Indexer {
 
     #[tracing::instrument(name = "waiting", skip(self))]
     async fn handle_waiting(&mut self, until: u64, cursor: IndexingCursor) -> Result<IndexerState> {
-        self.send_heartbeat(&cursor).await?;
+        self.flush_sinks().await?;
+
+        if let Err(e) = self.send_heartbeat(&cursor).await {
+            tracing::warn!(error = %e, "Failed to send heartbeat");
+        }
 
         if now()? > until {
             Ok(IndexerState::CheckForUpdates { cursor })
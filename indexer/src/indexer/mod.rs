@@ -14,15 +14,19 @@ pub(crate) mod heartbeat;
 pub(crate) mod jobs;
 pub(crate) mod jwt;
 pub(crate) mod logic;
+pub(crate) mod outbox;
 pub(crate) mod persistence;
 pub(crate) mod push;
 pub(crate) mod server;
+pub(crate) mod signature;
+pub(crate) mod sink;
 pub(crate) mod state;
 
 pub(crate) use {
     client::IndexerClient,
     jobs::IndexerJob,
-    persistence::PersistedState,
+    persistence::{BlockHashes, PersistedState},
+    sink::SinkDelivery,
     state::{IndexerState, IndexingCursor},
 };
 
@@ -37,6 +41,9 @@ pub struct Indexer {
     /// Stack of transactions to index
     jobs: VecDeque<IndexerJob>,
 
+    /// Hashes of the recently indexed blocks to detect chain reorganizations
+    block_hashes: BlockHashes,
+
     /// The number of milliseconds between wait checks
     wait_interval_ms: Duration,
 
@@ -50,7 +57,10 @@ pub struct Indexer {
     web_client: reqwest::Client,
 
     /// The URL to send webhooks to
-    webhook_url: String,
+    webhook_url: Option<String>,
+
+    /// Destinations of the indexed events
+    sinks: Vec<SinkDelivery>,
 
     /// JWT token to use for webhooks
     jwt_token: String,
@@ -0,0 +1,490 @@
+use {
+    anyhow::{anyhow, Result},
+    serde::Serialize,
+    std::{
+        cmp::min,
+        collections::VecDeque,
+        fs::{self, File, OpenOptions},
+        io::{Seek, SeekFrom, Write},
+        path::{Path, PathBuf},
+        time::{Duration, Instant},
+    },
+};
+
+use super::push::PushPayload;
+
+/// Size of the delivered part of the outbox file that is worth compacting
+const COMPACTION_THRESHOLD: u64 = 1024 * 1024;
+
+/// Payload that was permanently rejected by the webhook receiver
+#[derive(Serialize)]
+struct DeadLetter<'a> {
+    reason: &'a str,
+    payload: &'a PushPayload,
+}
+
+/// Persistent queue of payloads that are not delivered yet
+///
+/// Payloads are appended to the outbox file and the position of the first undelivered one
+/// is kept in the offset file, the delivered part of the outbox file is removed in batches
+pub(crate) struct Outbox {
+    /// Undelivered payloads in the order they were indexed
+    payloads: VecDeque<PushPayload>,
+
+    /// Position in the outbox file after the line of each undelivered payload
+    ends: VecDeque<u64>,
+
+    /// Position of the first undelivered payload in the outbox file
+    offset: u64,
+
+    /// Length of the outbox file
+    len: u64,
+
+    /// The file to persist undelivered payloads in
+    file: PathBuf,
+
+    /// The file to append permanently rejected payloads to
+    dead_letter_file: PathBuf,
+
+    /// Delay before the first retry, doubled after each failed attempt
+    retry_delay: Duration,
+
+    /// Upper bound of the retry delay
+    max_retry_delay: Duration,
+
+    /// The number of failed attempts to deliver the first payload
+    attempts: u32,
+
+    /// The moment when the next delivery attempt is allowed
+    next_attempt: Option<Instant>,
+}
+
+impl Outbox {
+    /// Restores undelivered payloads of the previous run
+    pub fn load(
+        file: PathBuf,
+        dead_letter_file: PathBuf,
+        retry_delay: Duration,
+        max_retry_delay: Duration,
+    ) -> Result<Self> {
+        let content = if file.exists() {
+            fs::read_to_string(&file)?
+        } else {
+            String::new()
+        };
+        let len = content.len() as u64;
+
+        let mut offset = read_offset(&offset_file(&file));
+        let at_line_start =
+            offset == 0 || content.as_bytes().get(offset as usize - 1) == Some(&b'\n');
+        if !at_line_start {
+            tracing::warn!(
+                offset,
+                file = %file.display(),
+                "Outbox offset doesn't match the outbox file, delivering all its payloads again"
+            );
+            offset = 0;
+        }
+
+        let mut payloads = VecDeque::new();
+        let mut ends = VecDeque::new();
+        let mut end = 0;
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            end += line.len() as u64;
+            if end <= offset || line.trim().is_empty() {
+                continue;
+            }
+
+            let payload = serde_json::from_str(line).map_err(|e| {
+                anyhow!(
+                    "Invalid payload at line {} of the outbox file {}: {e}. \
+                    Fix or remove the line to resume the delivery",
+                    index + 1,
+                    file.display()
+                )
+            })?;
+
+            payloads.push_back(payload);
+            ends.push_back(end);
+        }
+
+        if !payloads.is_empty() {
+            tracing::info!(count = payloads.len(), "Found undelivered payloads");
+        }
+
+        Ok(Self {
+            payloads,
+            ends,
+            offset,
+            len,
+            file,
+            dead_letter_file,
+            retry_delay,
+            max_retry_delay,
+            attempts: 0,
+            next_attempt: None,
+        })
+    }
+
+    pub fn front(&self) -> Option<&PushPayload> {
+        self.payloads.front()
+    }
+
+    /// Whether the retry delay after the last failed attempt has passed
+    pub fn is_ready(&self) -> bool {
+        self.next_attempt
+            .map_or(true, |instant| Instant::now() >= instant)
+    }
+
+    /// Appends the payloads to the outbox file
+    pub fn extend(&mut self, payloads: Vec<PushPayload>) -> Result<()> {
+        if payloads.is_empty() {
+            return Ok(());
+        }
+
+        let mut content = String::new();
+        let mut ends = Vec::with_capacity(payloads.len());
+        for payload in &payloads {
+            content.push_str(&serde_json::to_string(payload)?);
+            content.push('\n');
+            ends.push(self.len + content.len() as u64);
+        }
+
+        create_parent_dir(&self.file)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_data()?;
+
+        self.len += content.len() as u64;
+        self.payloads.extend(payloads);
+        self.ends.extend(ends);
+
+        Ok(())
+    }
+
+    /// Removes the delivered payload
+    pub fn pop_front(&mut self) -> Result<()> {
+        self.payloads.pop_front();
+        if let Some(end) = self.ends.pop_front() {
+            self.offset = end;
+        }
+        self.attempts = 0;
+        self.next_attempt = None;
+
+        if self.payloads.is_empty() {
+            self.truncate()
+        } else if self.offset >= COMPACTION_THRESHOLD && self.offset >= self.len - self.offset {
+            self.compact()
+        } else {
+            // A lost offset update only makes the payloads to be delivered again
+            self.save_offset(false)
+        }
+    }
+
+    /// Moves the first payload to the dead-letter file
+    pub fn dead_letter(&mut self, reason: &str) -> Result<()> {
+        if let Some(payload) = self.payloads.front() {
+            let mut line = serde_json::to_string(&DeadLetter { reason, payload })?;
+            line.push('\n');
+
+            create_parent_dir(&self.dead_letter_file)?;
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.dead_letter_file)?
+                .write_all(line.as_bytes())?;
+        }
+
+        self.pop_front()
+    }
+
+    /// Schedules the next attempt with exponential backoff and returns the delay
+    pub fn postpone(&mut self) -> Duration {
+        let delay = min(
+            self.retry_delay
+                .saturating_mul(2u32.saturating_pow(self.attempts)),
+            self.max_retry_delay,
+        );
+
+        self.attempts = self.attempts.saturating_add(1);
+        self.next_attempt = Some(Instant::now() + delay);
+
+        delay
+    }
+
+    /// Empties the outbox file once all its payloads are delivered
+    fn truncate(&mut self) -> Result<()> {
+        if self.len > 0 {
+            let file = OpenOptions::new().write(true).open(&self.file)?;
+            file.set_len(0)?;
+            file.sync_all()?;
+        }
+
+        // An offset beyond the end of the file is reset on load
+        self.offset = 0;
+        self.len = 0;
+        self.save_offset(false)
+    }
+
+    /// Replaces the outbox file with a fully written temporary one without the delivered payloads,
+    /// so that a crash in the middle of the write doesn't corrupt it
+    fn compact(&mut self) -> Result<()> {
+        let mut content = String::new();
+        let mut ends = VecDeque::with_capacity(self.payloads.len());
+        for payload in &self.payloads {
+            content.push_str(&serde_json::to_string(payload)?);
+            content.push('\n');
+            ends.push_back(content.len() as u64);
+        }
+
+        let temp_file = self.temp_file();
+        let mut file = File::create(&temp_file)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+
+        // The offset is reset before the file is replaced: a crash in between
+        // makes the delivered payloads to be delivered again instead of skipping undelivered ones
+        self.offset = 0;
+        self.save_offset(true)?;
+
+        fs::rename(&temp_file, &self.file)?;
+
+        self.len = content.len() as u64;
+        self.ends = ends;
+
+        Ok(())
+    }
+
+    /// Overwrites the fixed-width offset in place, so that the offset file is never left empty
+    fn save_offset(&self, sync: bool) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(offset_file(&self.file))?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(format!("{:020}\n", self.offset).as_bytes())?;
+
+        if sync {
+            file.sync_data()?;
+        }
+
+        Ok(())
+    }
+
+    fn temp_file(&self) -> PathBuf {
+        let mut name = self.file.clone().into_os_string();
+        name.push(".tmp");
+
+        name.into()
+    }
+}
+
+fn offset_file(file: &Path) -> PathBuf {
+    let mut name = file.to_path_buf().into_os_string();
+    name.push(".offset");
+
+    name.into()
+}
+
+/// Reads the position of the first undelivered payload, a missing or invalid offset file
+/// makes all payloads of the outbox file to be delivered again
+fn read_offset(file: &Path) -> u64 {
+    if !file.exists() {
+        return 0;
+    }
+
+    match fs::read_to_string(file).map(|content| content.trim().parse()) {
+        Ok(Ok(offset)) => offset,
+        _ => {
+            tracing::warn!(file = %file.display(), "Invalid outbox offset file, ignoring it");
+            0
+        }
+    }
+}
+
+fn create_parent_dir(path: &Path) -> Result<()> {
+    if let Some(dir) = path.ancestors().nth(1) {
+        if !dir.as_os_str().is_empty() && !dir.exists() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        hapi_core::{client::events::EventName, HapiCoreNetwork},
+        std::env,
+    };
+
+    use super::*;
+    use crate::indexer::push::{NetworkConfiguration, NetworkData, PushEvent};
+
+    fn payload(tx_hash: &str) -> PushPayload {
+        PushPayload {
+            network_data: NetworkData {
+                indexer_id: uuid::uuid!("f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e"),
+                network: HapiCoreNetwork::Ethereum,
+                chain_id: None,
+            },
+            event: PushEvent {
+                name: EventName::SetAuthority,
+                tx_hash: tx_hash.to_string(),
+                tx_index: 0,
+                timestamp: 1690888679,
+                confirmed_by: None,
+            },
+            data: NetworkConfiguration::default().into(),
+        }
+    }
+
+    #[test]
+    fn test_outbox_persistence() {
+        let dir = env::temp_dir().join(format!("hapi_outbox_{}", uuid::Uuid::new_v4()));
+        let file = dir.join("outbox.ndjson");
+        let dead_letter_file = dir.join("dead_letter.ndjson");
+        let load = || {
+            Outbox::load(
+                file.clone(),
+                dead_letter_file.clone(),
+                Duration::from_millis(100),
+                Duration::from_millis(300),
+            )
+            .unwrap()
+        };
+
+        let mut outbox = load();
+        outbox
+            .extend(vec![payload("first"), payload("second"), payload("third")])
+            .unwrap();
+        outbox.pop_front().unwrap();
+        outbox.dead_letter("Bad request").unwrap();
+
+        // Undelivered payloads are restored in order
+        let outbox = load();
+        assert_eq!(outbox.payloads, vec![payload("third")]);
+
+        let dead_letters = fs::read_to_string(&dead_letter_file).unwrap();
+        assert_eq!(dead_letters.lines().count(), 1);
+        assert!(dead_letters.contains(r#""reason":"Bad request""#));
+        assert!(dead_letters.contains(r#""tx_hash":"second""#));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_outbox_corrupted_file() {
+        let dir = env::temp_dir().join(format!("hapi_outbox_{}", uuid::Uuid::new_v4()));
+        let file = dir.join("outbox.ndjson");
+        let load = || {
+            Outbox::load(
+                file.clone(),
+                dir.join("dead_letter.ndjson"),
+                Duration::from_millis(100),
+                Duration::from_millis(300),
+            )
+        };
+
+        let mut outbox = load().unwrap();
+        outbox
+            .extend(vec![payload("first"), payload("second")])
+            .unwrap();
+
+        // Payloads are appended to the file without a temporary one
+        assert!(!outbox.temp_file().exists());
+        assert_eq!(load().unwrap().payloads.len(), 2);
+
+        // A partially written payload is reported with its location
+        let content = fs::read_to_string(&file).unwrap();
+        fs::write(&file, &content[..content.len() - 10]).unwrap();
+
+        let error = load().err().unwrap().to_string();
+        assert!(error.contains("line 2 of the outbox file"));
+        assert!(error.contains(&file.display().to_string()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_outbox_large_backlog() {
+        let dir = env::temp_dir().join(format!("hapi_outbox_{}", uuid::Uuid::new_v4()));
+        let file = dir.join("outbox.ndjson");
+        let load = || {
+            Outbox::load(
+                file.clone(),
+                dir.join("dead_letter.ndjson"),
+                Duration::from_millis(100),
+                Duration::from_millis(300),
+            )
+            .unwrap()
+        };
+
+        let tx_hash = |index: usize| format!("{index:064x}");
+
+        let mut outbox = load();
+        for batch in 0..100 {
+            outbox
+                .extend(
+                    (0..100)
+                        .map(|i| payload(&tx_hash(batch * 100 + i)))
+                        .collect(),
+                )
+                .unwrap();
+        }
+        let backlog_len = fs::metadata(&file).unwrap().len();
+        assert!(backlog_len > 2 * COMPACTION_THRESHOLD);
+
+        for _ in 0..9000 {
+            outbox.pop_front().unwrap();
+        }
+
+        // The delivered payloads are removed from the file in batches
+        assert!(fs::metadata(&file).unwrap().len() < backlog_len / 2);
+        assert!(!outbox.temp_file().exists());
+
+        // Delivery resumes from the first undelivered payload
+        let mut outbox = load();
+        assert_eq!(outbox.payloads.len(), 1000);
+        assert_eq!(outbox.front(), Some(&payload(&tx_hash(9000))));
+
+        while outbox.front().is_some() {
+            outbox.pop_front().unwrap();
+        }
+
+        // The file is emptied once everything is delivered
+        assert_eq!(fs::metadata(&file).unwrap().len(), 0);
+        assert!(load().payloads.is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_outbox_backoff() {
+        let dir = env::temp_dir().join(format!("hapi_outbox_{}", uuid::Uuid::new_v4()));
+        let mut outbox = Outbox::load(
+            dir.join("outbox.ndjson"),
+            dir.join("dead_letter.ndjson"),
+            Duration::from_millis(100),
+            Duration::from_millis(300),
+        )
+        .unwrap();
+
+        assert!(outbox.is_ready());
+        assert_eq!(outbox.postpone(), Duration::from_millis(100));
+        assert!(!outbox.is_ready());
+        assert_eq!(outbox.postpone(), Duration::from_millis(200));
+        assert_eq!(outbox.postpone(), Duration::from_millis(300));
+        assert_eq!(outbox.postpone(), Duration::from_millis(300));
+
+        outbox.extend(vec![payload("first")]).unwrap();
+        outbox.pop_front().unwrap();
+        assert!(outbox.is_ready());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -2,6 +2,7 @@ use {
     anyhow::Result,
     serde::{Deserialize, Serialize},
     std::{
+        collections::BTreeMap,
         fs,
         path::{Path, PathBuf},
     },
@@ -9,9 +10,16 @@ use {
 
 use super::IndexingCursor;
 
+/// Hashes of the recently indexed blocks by block number
+pub type BlockHashes = BTreeMap<u64, String>;
+
 #[derive(Serialize, Deserialize)]
 pub struct PersistedState {
     pub cursor: IndexingCursor,
+
+    /// Used to detect chain reorganizations on EVM networks
+    #[serde(default)]
+    pub block_hashes: BlockHashes,
 }
 
 impl PersistedState {
@@ -1,7 +1,8 @@
 use {
-    anyhow::{bail, Result},
+    anyhow::Result,
     hapi_core::{
         client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
             entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
             events::EventName,
         },
@@ -14,7 +15,7 @@ use {
 use super::Indexer;
 
 /// Webhook payload
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct PushPayload {
     pub network_data: NetworkData,
     pub event: PushEvent,
@@ -43,6 +44,20 @@ pub struct PushEvent {
     pub tx_index: u64,
     /// Timestamp of the transaction block
     pub timestamp: u64,
+    /// Reporter that confirmed the address or asset (only for confirmation events)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmed_by: Option<Uuid>,
+}
+
+/// Network configuration data (only changed fields are present)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct NetworkConfiguration {
+    /// Network authority
+    pub authority: Option<String>,
+    /// Stake configuration
+    pub stake_configuration: Option<StakeConfiguration>,
+    /// Reward configuration
+    pub reward_configuration: Option<RewardConfiguration>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -51,6 +66,7 @@ pub enum PushData {
     Asset(Asset),
     Case(Case),
     Reporter(Reporter),
+    Configuration(NetworkConfiguration),
 }
 
 impl From<Address> for PushData {
@@ -77,20 +93,25 @@ impl From<Reporter> for PushData {
     }
 }
 
+impl From<NetworkConfiguration> for PushData {
+    fn from(configuration: NetworkConfiguration) -> Self {
+        Self::Configuration(configuration)
+    }
+}
+
 impl Indexer {
-    pub(crate) async fn send_webhook(&self, payload: &PushPayload) -> Result<()> {
-        let url = format!("{}/events", self.webhook_url,);
-
-        let response = self
-            .web_client
-            .post(url)
-            .bearer_auth(self.jwt_token.as_str())
-            .json(payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            bail!("Webhook request failed: {:?}", response.text().await?);
+    /// Queues payloads for delivery to every configured sink
+    pub(crate) fn push_payloads(&mut self, payloads: Vec<PushPayload>) -> Result<()> {
+        for sink in &mut self.sinks {
+            sink.push(payloads.clone())?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn flush_sinks(&mut self) -> Result<()> {
+        for sink in &mut self.sinks {
+            sink.flush().await?;
         }
 
         Ok(())
@@ -118,6 +139,7 @@ mod tests {
                     .to_string(),
                 tx_index: 0,
                 timestamp: 1690888679,
+                confirmed_by: None,
             },
             data: PushData::Address(Address {
                 address: "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3".to_string(),
@@ -0,0 +1,59 @@
+use {
+    hmac::{Hmac, Mac},
+    sha2::Sha256,
+};
+
+/// Header with the hex encoded HMAC-SHA256 signature of the request
+pub const SIGNATURE_HEADER: &str = "x-hapi-signature";
+
+/// Header with the unix timestamp (in seconds) included in the signature
+pub const TIMESTAMP_HEADER: &str = "x-hapi-timestamp";
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn get_mac(secret: &[u8], timestamp: u64, body: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any size");
+
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    mac
+}
+
+/// Signs `{timestamp}.{body}` with the shared secret
+pub fn sign_payload(secret: &[u8], timestamp: u64, body: &[u8]) -> String {
+    hex::encode(get_mac(secret, timestamp, body).finalize().into_bytes())
+}
+
+/// Checks the signature in constant time
+pub fn verify_signature(secret: &[u8], timestamp: u64, body: &[u8], signature: &str) -> bool {
+    hex::decode(signature).map_or(false, |signature| {
+        get_mac(secret, timestamp, body)
+            .verify_slice(&signature)
+            .is_ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        let secret = b"my_webhook_secret";
+        let body = br#"{"event":"create_address"}"#;
+        let signature = sign_payload(secret, 1690888679, body);
+
+        assert!(verify_signature(secret, 1690888679, body, &signature));
+        assert!(!verify_signature(secret, 1690888680, body, &signature));
+        assert!(!verify_signature(
+            b"other_secret",
+            1690888679,
+            body,
+            &signature
+        ));
+        assert!(!verify_signature(secret, 1690888679, b"{}", &signature));
+        assert!(!verify_signature(secret, 1690888679, body, "not a hex"));
+    }
+}
@@ -0,0 +1,56 @@
+use {
+    anyhow::Result,
+    async_trait::async_trait,
+    std::{
+        fs::{self, OpenOptions},
+        io::Write,
+        path::PathBuf,
+    },
+};
+
+use super::{DeliveryStatus, EventSink};
+use crate::indexer::push::PushPayload;
+
+/// Appends events to a file as newline-delimited JSON
+pub(crate) struct FileSink {
+    path: PathBuf,
+}
+
+impl FileSink {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    fn append(&self, payload: &PushPayload) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            if !dir.as_os_str().is_empty() && !dir.exists() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        let mut line = serde_json::to_string(payload)?;
+        line.push('\n');
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(line.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventSink for FileSink {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn send(&self, payload: &PushPayload) -> DeliveryStatus {
+        match self.append(payload) {
+            Ok(()) => DeliveryStatus::Delivered,
+            Err(e) => DeliveryStatus::Failed(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use {
+    anyhow::{bail, Result},
+    async_trait::async_trait,
+    std::{
+        fs,
+        path::{Path, PathBuf},
+    },
+};
+
+mod file;
+mod stdout;
+mod webhook;
+
+pub(crate) use {file::FileSink, stdout::StdoutSink, webhook::WebhookSink};
+
+use crate::configuration::{IndexerConfiguration, SinkConfiguration};
+
+use super::{outbox::Outbox, push::PushPayload};
+
+/// Result of a single delivery attempt
+pub(crate) enum DeliveryStatus {
+    Delivered,
+    /// The sink will never accept the payload
+    Rejected(String),
+    /// The payload can be delivered later
+    Failed(String),
+}
+
+/// Destination of the indexed events
+#[async_trait]
+pub(crate) trait EventSink: Send + Sync {
+    /// Sink name to use in logs
+    fn name(&self) -> &'static str;
+
+    async fn send(&self, payload: &PushPayload) -> DeliveryStatus;
+}
+
+/// Sink with its own outbox, so a failing sink does not delay the others
+pub(crate) struct SinkDelivery {
+    sink: Box<dyn EventSink>,
+    outbox: Outbox,
+}
+
+impl SinkDelivery {
+    pub fn push(&mut self, payloads: Vec<PushPayload>) -> Result<()> {
+        self.outbox.extend(payloads)
+    }
+
+    /// Delivers the outbox payloads in order until the first failed attempt
+    pub async fn flush(&mut self) -> Result<()> {
+        let sink = self.sink.name();
+
+        while let Some(payload) = self.outbox.front() {
+            if !self.outbox.is_ready() {
+                break;
+            }
+
+            match self.sink.send(payload).await {
+                DeliveryStatus::Delivered => self.outbox.pop_front()?,
+                DeliveryStatus::Rejected(reason) => {
+                    tracing::error!(sink, %reason, "Payload is rejected, moving to dead letters");
+                    self.outbox.dead_letter(&format!("{sink}: {reason}"))?;
+                }
+                DeliveryStatus::Failed(reason) => {
+                    let delay = self.outbox.postpone();
+                    tracing::warn!(
+                        sink,
+                        %reason,
+                        retry_in_ms = delay.as_millis() as u64,
+                        "Payload delivery failed"
+                    );
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Each sink gets its identity in the outbox file name, so that reordering sinks in the
+/// configuration doesn't mix up their undelivered payloads
+fn get_outbox_file(outbox_file: &str, sink_id: &str) -> PathBuf {
+    let path = Path::new(outbox_file);
+
+    let stem = path
+        .file_stem()
+        .map_or(String::new(), |s| s.to_string_lossy().to_string());
+
+    match path.extension() {
+        Some(extension) => {
+            path.with_file_name(format!("{stem}.{sink_id}.{}", extension.to_string_lossy()))
+        }
+        None => path.with_file_name(format!("{stem}.{sink_id}")),
+    }
+}
+
+/// Previous versions kept the outbox of the first sink in the configured file,
+/// its payloads are moved to the outbox of the first sink
+fn migrate_outbox_file(outbox_file: &str, sink_outbox_file: &Path) -> Result<()> {
+    let legacy_file = Path::new(outbox_file);
+
+    if legacy_file.exists() && !sink_outbox_file.exists() {
+        tracing::info!(
+            from = %legacy_file.display(),
+            to = %sink_outbox_file.display(),
+            "Moving undelivered payloads to the sink outbox"
+        );
+
+        fs::rename(legacy_file, sink_outbox_file)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn create_sinks(
+    cfg: &IndexerConfiguration,
+    web_client: &reqwest::Client,
+) -> Result<Vec<SinkDelivery>> {
+    if cfg.sinks.is_empty() {
+        bail!("At least one sink must be configured");
+    }
+
+    cfg.validate_sinks()?;
+
+    cfg.sinks
+        .iter()
+        .enumerate()
+        .map(|(index, sink_cfg)| {
+            let sink: Box<dyn EventSink> = match sink_cfg {
+                SinkConfiguration::Webhook => {
+                    let Some(webhook_url) = &cfg.webhook_url else {
+                        bail!("Webhook sink requires webhook_url to be configured");
+                    };
+
+                    Box::new(WebhookSink::new(
+                        web_client.clone(),
+                        webhook_url,
+                        &cfg.jwt_token,
+                        cfg.webhook_secret.clone(),
+                    ))
+                }
+                SinkConfiguration::File { path } => Box::new(FileSink::new(PathBuf::from(path))),
+                SinkConfiguration::Stdout => Box::new(StdoutSink),
+            };
+
+            let outbox_file = get_outbox_file(&cfg.outbox_file, &cfg.sink_id(sink_cfg));
+            if index == 0 {
+                migrate_outbox_file(&cfg.outbox_file, &outbox_file)?;
+            }
+
+            let outbox = Outbox::load(
+                outbox_file,
+                PathBuf::from(&cfg.dead_letter_file),
+                cfg.retry_delay,
+                cfg.max_retry_delay,
+            )?;
+
+            tracing::info!(sink = sink.name(), "Sink is configured");
+
+            Ok(SinkDelivery { sink, outbox })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {hapi_core::HapiCoreNetwork, std::time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn test_outbox_file() {
+        assert_eq!(
+            get_outbox_file("data/outbox.ndjson", "stdout"),
+            PathBuf::from("data/outbox.stdout.ndjson")
+        );
+        assert_eq!(
+            get_outbox_file("outbox", "file-1a2b3c4d"),
+            PathBuf::from("outbox.file-1a2b3c4d")
+        );
+    }
+
+    #[test]
+    fn test_sink_identity() {
+        let mut cfg = IndexerConfiguration {
+            network: HapiCoreNetwork::Ethereum,
+            chain_id: None,
+            rpc_node_url: String::from("http://localhost:8545"),
+            webhook_url: Some(String::from("http://localhost:3000")),
+            sinks: vec![
+                SinkConfiguration::Webhook,
+                SinkConfiguration::File {
+                    path: String::from("data/events.ndjson"),
+                },
+                SinkConfiguration::File {
+                    path: String::from("data/archive.ndjson"),
+                },
+                SinkConfiguration::Stdout,
+            ],
+            contract_address: String::default(),
+            wait_interval_ms: Duration::from_millis(100),
+            state_file: String::from("data/state.json"),
+            fetching_delay: Duration::from_millis(100),
+            jwt_token: String::default(),
+            webhook_secret: None,
+            outbox_file: String::from("data/outbox.ndjson"),
+            dead_letter_file: String::from("data/dead_letter.ndjson"),
+            retry_delay: Duration::from_millis(100),
+            max_retry_delay: Duration::from_millis(100),
+            confirmations: 0,
+        };
+
+        let ids = cfg
+            .sinks
+            .iter()
+            .map(|sink| cfg.sink_id(sink))
+            .collect::<Vec<_>>();
+
+        // The identity doesn't depend on the sink position
+        assert_eq!(ids[0], cfg.sink_id(&SinkConfiguration::Webhook));
+        assert!(ids[0].starts_with("webhook-"));
+        assert!(ids[1].starts_with("file-"));
+        assert_ne!(ids[1], ids[2]);
+        assert_eq!(ids[3], "stdout");
+        assert!(cfg.validate_sinks().is_ok());
+
+        cfg.sinks.push(SinkConfiguration::File {
+            path: String::from("data/events.ndjson"),
+        });
+        assert!(cfg.validate_sinks().is_err());
+    }
+}
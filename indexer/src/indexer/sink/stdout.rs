@@ -0,0 +1,29 @@
+use {
+    async_trait::async_trait,
+    std::io::{self, Write},
+};
+
+use super::{DeliveryStatus, EventSink};
+use crate::indexer::push::PushPayload;
+
+/// Prints events to the standard output as newline-delimited JSON
+pub(crate) struct StdoutSink;
+
+#[async_trait]
+impl EventSink for StdoutSink {
+    fn name(&self) -> &'static str {
+        "stdout"
+    }
+
+    async fn send(&self, payload: &PushPayload) -> DeliveryStatus {
+        let line = match serde_json::to_string(payload) {
+            Ok(line) => line,
+            Err(e) => return DeliveryStatus::Rejected(e.to_string()),
+        };
+
+        match writeln!(io::stdout().lock(), "{line}") {
+            Ok(()) => DeliveryStatus::Delivered,
+            Err(e) => DeliveryStatus::Failed(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use {
+    async_trait::async_trait,
+    reqwest::{header::CONTENT_TYPE, StatusCode},
+};
+
+use super::{DeliveryStatus, EventSink};
+use crate::indexer::{
+    now,
+    push::PushPayload,
+    signature::{sign_payload, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+};
+
+/// Pushes events to the explorer webhook
+pub(crate) struct WebhookSink {
+    web_client: reqwest::Client,
+    url: String,
+    jwt_token: String,
+    secret: Option<String>,
+}
+
+impl WebhookSink {
+    pub fn new(
+        web_client: reqwest::Client,
+        webhook_url: &str,
+        jwt_token: &str,
+        secret: Option<String>,
+    ) -> Self {
+        Self {
+            web_client,
+            url: format!("{webhook_url}/events"),
+            jwt_token: jwt_token.to_string(),
+            secret,
+        }
+    }
+}
+
+/// Client errors that are caused by the payload itself rather than by the receiver state
+fn is_permanent_rejection(status: StatusCode) -> bool {
+    status.is_client_error()
+        && !matches!(
+            status,
+            StatusCode::UNAUTHORIZED
+                | StatusCode::FORBIDDEN
+                | StatusCode::NOT_FOUND
+                | StatusCode::REQUEST_TIMEOUT
+                | StatusCode::TOO_MANY_REQUESTS
+        )
+}
+
+#[async_trait]
+impl EventSink for WebhookSink {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn send(&self, payload: &PushPayload) -> DeliveryStatus {
+        let body = match serde_json::to_vec(payload) {
+            Ok(body) => body,
+            Err(e) => return DeliveryStatus::Rejected(e.to_string()),
+        };
+
+        let mut request = self
+            .web_client
+            .post(&self.url)
+            .bearer_auth(self.jwt_token.as_str())
+            .header(CONTENT_TYPE, "application/json");
+
+        // The signature is renewed on every attempt, so retries are not rejected as stale
+        if let Some(secret) = &self.secret {
+            let timestamp = match now() {
+                Ok(timestamp) => timestamp,
+                Err(e) => return DeliveryStatus::Failed(e.to_string()),
+            };
+
+            request = request.header(TIMESTAMP_HEADER, timestamp).header(
+                SIGNATURE_HEADER,
+                sign_payload(secret.as_bytes(), timestamp, &body),
+            );
+        }
+
+        let response = match request.body(body).send().await {
+            Ok(response) => response,
+            Err(e) => return DeliveryStatus::Failed(e.to_string()),
+        };
+
+        let status = response.status();
+        if status.is_success() {
+            return DeliveryStatus::Delivered;
+        }
+
+        let message = format!("{status}: {}", response.text().await.unwrap_or_default());
+
+        if is_permanent_rejection(status) {
+            DeliveryStatus::Rejected(message)
+        } else {
+            DeliveryStatus::Failed(message)
+        }
+    }
+}
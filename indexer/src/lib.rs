@@ -5,7 +5,8 @@ pub mod observability;
 pub use indexer::{
     jwt::get_id_from_jwt,
     persistence::PersistedState,
-    push::{NetworkData, PushData, PushEvent, PushPayload},
+    push::{NetworkConfiguration, NetworkData, PushData, PushEvent, PushPayload},
+    signature::{sign_payload, verify_signature, SIGNATURE_HEADER, TIMESTAMP_HEADER},
     state::IndexingCursor,
     Indexer,
 };
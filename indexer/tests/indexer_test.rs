@@ -1,7 +1,9 @@
 use {
+    hapi_core::client::events::EventName,
     hapi_indexer::{
-        configuration::IndexerConfiguration, observability::setup_tracing, Indexer, IndexingCursor,
-        PersistedState, PushData,
+        configuration::{IndexerConfiguration, SinkConfiguration},
+        observability::setup_tracing,
+        Indexer, IndexingCursor, PersistedState, PushData,
     },
     std::{env, path::PathBuf, time::Duration},
     tokio::time::sleep,
@@ -78,12 +80,19 @@ impl<T: RpcMock> IndexerTest<T> {
             network: T::get_network(),
             chain_id: None,
             rpc_node_url: self.rpc_mock.get_mock_url(),
-            webhook_url: self.webhook_mock.server.url(),
+            webhook_url: Some(self.webhook_mock.server.url()),
+            sinks: vec![SinkConfiguration::Webhook],
             contract_address: T::get_contract_address(),
             wait_interval_ms: FETCHING_DELAY,
             state_file: T::STATE_FILE.to_string(),
             fetching_delay: FETCHING_DELAY,
             jwt_token: get_jwt(),
+            webhook_secret: None,
+            outbox_file: T::STATE_FILE.replace("state", "outbox"),
+            dead_letter_file: T::STATE_FILE.replace("state", "dead_letter"),
+            retry_delay: FETCHING_DELAY,
+            max_retry_delay: FETCHING_DELAY,
+            confirmations: 0,
         };
 
         let mut indexer = Indexer::new(cfg).expect("Failed to initialize indexer");
@@ -142,6 +151,41 @@ impl<T: RpcMock> IndexerTest<T> {
         assert!(self.indexing_iteration().await.is_ok());
     }
 
+    pub async fn failed_fetch_test(&mut self) {
+        println!("\nFailed fetch test");
+
+        let pushdata = create_pushdata::<T>();
+        let events = create_test_batches::<T>(&pushdata).remove(0);
+        let index = events
+            .iter()
+            .position(|event| event.name == EventName::ActivateReporter)
+            .expect("No reporter activation");
+        let batch = vec![events[index].clone()];
+
+        // The cursor is left on the previous transaction
+        self.cursor = T::get_cursor(&[vec![events[index - 1].clone()]]);
+        PersistedState {
+            cursor: self.cursor.clone(),
+            block_hashes: Default::default(),
+        }
+        .to_file(&PathBuf::from(T::STATE_FILE))
+        .expect("Failed to persist state");
+
+        // Entity getters are not mocked on the new server, so fetching the account fails
+        self.rpc_mock = T::initialize();
+        self.rpc_mock
+            .fetching_jobs_mock(&[batch.clone()], &self.cursor);
+        self.rpc_mock.processing_jobs_mock(&batch);
+
+        assert!(self.indexing_iteration().await.is_err());
+
+        let state =
+            PersistedState::from_file(&PathBuf::from(T::STATE_FILE)).expect("Failed to get state");
+        assert_eq!(state.cursor, self.cursor);
+
+        println!("==> Success: failed transaction is left to be processed again\n");
+    }
+
     pub async fn run_test(&mut self) {
         println!("Starting test for {} network\n", T::get_network());
 
@@ -167,14 +211,35 @@ impl<T: RpcMock> Drop for IndexerTest<T> {
 }
 
 fn drop_state_file(file: &'static str) {
-    if PathBuf::from(file).exists() {
-        std::fs::remove_file(file).expect("Failed to remove state file");
+    for file in [file.to_string(), file.replace("state", "dead_letter")] {
+        if PathBuf::from(&file).exists() {
+            std::fs::remove_file(file).expect("Failed to remove state file");
+        }
+    }
+
+    // Outbox files of the sinks are named after the configured outbox file
+    let outbox = PathBuf::from(file.replace("state", "outbox"));
+    let prefix = outbox
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .expect("Invalid outbox file");
+
+    if let Ok(entries) = std::fs::read_dir(outbox.parent().expect("Invalid outbox file")) {
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                std::fs::remove_file(entry.path()).expect("Failed to remove outbox file");
+            }
+        }
     }
 }
 
 #[tokio::test(flavor = "multi_thread")]
 async fn solana_indexer_test() {
-    IndexerTest::<SolanaMock>::new().run_test().await;
+    let mut test = IndexerTest::<SolanaMock>::new();
+    test.run_test().await;
+
+    // Transient RPC errors are not mistaken for missing accounts
+    test.failed_fetch_test().await;
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -5,11 +5,17 @@ use {
         prelude::{abigen, SignerMiddleware},
         providers::{Http, Provider},
         signers::{LocalWallet, Signer},
-        types::{Address, Block, Bytes, Filter, Log, H256, U256},
+        types::{
+            transaction::eip2718::TypedTransaction, Address, Block, Bytes, Filter, Log,
+            Transaction, H256, U256,
+        },
         utils::keccak256,
     },
-    hapi_core::{client::events::EventName, HapiCoreNetwork},
-    hapi_indexer::{IndexingCursor, PushData},
+    hapi_core::{
+        client::{entities::reporter::Reporter, events::EventName},
+        HapiCoreNetwork,
+    },
+    hapi_indexer::{IndexingCursor, NetworkConfiguration, PushData},
     mockito::{Matcher, Server, ServerGuard},
     rand::RngCore,
     serde_json::json,
@@ -55,6 +61,10 @@ impl RpcMock for EvmMock {
         ethers::utils::to_checksum(&LocalWallet::new(&mut rand::thread_rng()).address(), None)
     }
 
+    fn generate_authority() -> String {
+        format!("{:?}", LocalWallet::new(&mut rand::thread_rng()).address())
+    }
+
     fn get_delay_multiplier() -> u32 {
         4
     }
@@ -89,8 +99,8 @@ impl RpcMock for EvmMock {
             .unwrap_or(IndexingCursor::None)
     }
 
-    fn entity_getters_mock(&mut self, data: Vec<PushData>) {
-        data.iter().for_each(|data| self.processing_data_mock(data));
+    fn entity_getters_mock(&mut self, _data: Vec<PushData>) {
+        // Entity getters are called at the event block, so they are mocked with processing jobs
     }
 
     fn fetching_jobs_mock(&mut self, batches: &[TestBatch], cursor: &IndexingCursor) {
@@ -112,9 +122,17 @@ impl RpcMock for EvmMock {
     }
 
     fn processing_jobs_mock(&mut self, batch: &TestBatch) {
-        batch
-            .iter()
-            .for_each(|event| self.block_request_mock(event.block));
+        batch.iter().for_each(|event| {
+            self.block_request_mock(event.block);
+
+            if let Some(data) = &event.data {
+                self.processing_data_mock(data, event.block);
+            }
+
+            if let Some(reporter) = &event.confirmed_by {
+                self.confirming_reporter_mock(&event.hash, reporter, event.block);
+            }
+        });
     }
 }
 
@@ -177,47 +195,69 @@ impl EvmMock {
                     log.data = ethers::abi::encode(&[version_token]).into();
                 }
                 EventName::SetAuthority => {
-                    let address = Self::generate_address()
+                    let_extract!(
+                        PushData::Configuration(data),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let authority = data
+                        .authority
+                        .as_ref()
+                        .expect("Empty authority")
                         .parse::<Address>()
                         .expect("Invalid address");
 
-                    log.data = ethers::abi::encode(&[Token::Address(address)]).into();
+                    log.data = ethers::abi::encode(&[Token::Address(authority)]).into();
                 }
                 EventName::UpdateStakeConfiguration => {
-                    let token = Self::generate_address()
+                    let_extract!(
+                        PushData::Configuration(data),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let configuration = data
+                        .stake_configuration
+                        .clone()
+                        .expect("Empty stake configuration");
+                    let token = configuration
+                        .token
                         .parse::<Address>()
                         .expect("Invalid address");
-                    let unlock_duration: U256 = 100.into();
-                    let validator_stake: U256 = 100.into();
-                    let tracer_stake: U256 = 100.into();
-                    let publisher_stake: U256 = 100.into();
-                    let authority_stake: U256 = 100.into();
 
                     log.data = ethers::abi::encode(&[
                         Token::Address(token),
-                        Token::Uint(unlock_duration),
-                        Token::Uint(validator_stake),
-                        Token::Uint(tracer_stake),
-                        Token::Uint(publisher_stake),
-                        Token::Uint(authority_stake),
+                        Token::Uint(U256::from(configuration.unlock_duration)),
+                        Token::Uint(configuration.validator_stake.into()),
+                        Token::Uint(configuration.tracer_stake.into()),
+                        Token::Uint(configuration.publisher_stake.into()),
+                        Token::Uint(configuration.authority_stake.into()),
                     ])
                     .into();
                 }
                 EventName::UpdateRewardConfiguration => {
-                    let token = Self::generate_address()
+                    let_extract!(
+                        PushData::Configuration(data),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let configuration = data
+                        .reward_configuration
+                        .clone()
+                        .expect("Empty reward configuration");
+                    let token = configuration
+                        .token
                         .parse::<Address>()
                         .expect("Invalid address");
-                    let address_confirmation_reward: U256 = 100.into();
-                    let address_tracer_reward: U256 = 100.into();
-                    let asset_confirmation_reward: U256 = 100.into();
-                    let asset_tracer_reward: U256 = 100.into();
 
                     log.data = ethers::abi::encode(&[
                         Token::Address(token),
-                        Token::Uint(address_confirmation_reward),
-                        Token::Uint(address_tracer_reward),
-                        Token::Uint(asset_confirmation_reward),
-                        Token::Uint(asset_tracer_reward),
+                        Token::Uint(configuration.address_confirmation_reward.into()),
+                        Token::Uint(configuration.address_tracer_reward.into()),
+                        Token::Uint(configuration.asset_confirmation_reward.into()),
+                        Token::Uint(configuration.asset_tracer_reward.into()),
                     ])
                     .into();
                 }
@@ -254,7 +294,7 @@ impl EvmMock {
 
                     log.topics.append(&mut vec![id_topic]);
                 }
-                EventName::CreateAddress | EventName::UpdateAddress | EventName::ConfirmAddress => {
+                EventName::CreateAddress | EventName::UpdateAddress => {
                     let_extract!(
                         PushData::Address(data),
                         event.data.as_ref().expect("Empty data"),
@@ -273,7 +313,19 @@ impl EvmMock {
                     ])
                     .into();
                 }
-                EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset => {
+                EventName::ConfirmAddress => {
+                    let_extract!(
+                        PushData::Address(data),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let addr: Address = data.address.parse().expect("Invalid address");
+                    let addr_topic = H256::from(addr);
+
+                    log.topics.append(&mut vec![addr_topic]);
+                }
+                EventName::CreateAsset | EventName::UpdateAsset => {
                     let_extract!(
                         PushData::Asset(data),
                         event.data.as_ref().expect("Empty data"),
@@ -294,6 +346,20 @@ impl EvmMock {
                     ])
                     .into();
                 }
+                EventName::ConfirmAsset => {
+                    let_extract!(
+                        PushData::Asset(data),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let addr: Address = data.address.parse().expect("Invalid address");
+                    let asset_id: U256 = data.asset_id.clone().into();
+                    let addr_topic = H256::from(addr);
+
+                    log.topics.append(&mut vec![addr_topic]);
+                    log.data = ethers::abi::encode(&[Token::Uint(asset_id)]).into();
+                }
             }
 
             res.push(log);
@@ -333,6 +399,7 @@ impl EvmMock {
     fn block_request_mock(&mut self, num: u64) {
         let mut block: Block<H256> = Block::default();
         block.timestamp = 123.into();
+        block.hash = Some(H256::from_low_u64_be(num));
 
         let response = json!({
            "jsonrpc": "2.0",
@@ -352,7 +419,7 @@ impl EvmMock {
             .create();
     }
 
-    fn processing_data_mock(&mut self, data: &PushData) {
+    fn processing_data_mock(&mut self, data: &PushData, block: u64) {
         let (raw_tx, result) = match data {
             PushData::Address(address) => {
                 let addr = address
@@ -453,8 +520,111 @@ impl EvmMock {
 
                 (raw_tx, format!("0x{}", responce))
             }
+            PushData::Configuration(configuration) => {
+                return self.configuration_data_mock(configuration, block);
+            }
         };
 
+        self.eth_call_mock(raw_tx, result, block);
+    }
+
+    // The confirming reporter is resolved from the sender of the confirmation transaction
+    fn confirming_reporter_mock(&mut self, hash: &str, reporter: &Reporter, block: u64) {
+        let sender = reporter
+            .account
+            .parse::<Address>()
+            .expect("Failed to parse address");
+
+        let transaction = Transaction {
+            hash: H256::from_str(hash).expect("Failed to parse transaction hash"),
+            from: sender,
+            ..Default::default()
+        };
+
+        let response = json!({
+           "jsonrpc": "2.0",
+           "result": transaction,
+           "id": 1
+        });
+
+        self.server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&response.to_string())
+            .match_body(Matcher::PartialJson(json!({
+                "method": "eth_getTransactionByHash",
+                "params": [ hash ]
+            })))
+            .create();
+
+        let raw_tx = self.contract.get_my_reporter_id().from(sender).tx;
+        let responce = hex::encode(ethers::abi::encode(&[Token::Uint(U256::from(
+            reporter.id.as_u128(),
+        ))]));
+
+        self.eth_call_mock(raw_tx, format!("0x{}", responce), block);
+    }
+
+    fn configuration_data_mock(&mut self, configuration: &NetworkConfiguration, block: u64) {
+        if let Some(authority) = &configuration.authority {
+            let authority = authority
+                .parse::<Address>()
+                .expect("Failed to parse address");
+
+            let raw_tx = self.contract.authority().tx;
+            let responce = hex::encode(ethers::abi::encode(&[Token::Address(authority)]));
+
+            self.eth_call_mock(raw_tx, format!("0x{}", responce), block);
+        }
+
+        if let Some(stake_configuration) = &configuration.stake_configuration {
+            let token = stake_configuration
+                .token
+                .parse::<Address>()
+                .expect("Failed to parse address");
+
+            let raw_tx = self.contract.stake_configuration().tx;
+            let responce = hex::encode(ethers::abi::encode(&[Token::Tuple(vec![
+                Token::Address(token),
+                Token::Uint(U256::from(stake_configuration.unlock_duration)),
+                Token::Uint(U256::from(stake_configuration.validator_stake.to_owned())),
+                Token::Uint(U256::from(stake_configuration.tracer_stake.to_owned())),
+                Token::Uint(U256::from(stake_configuration.publisher_stake.to_owned())),
+                Token::Uint(U256::from(stake_configuration.authority_stake.to_owned())),
+            ])]));
+
+            self.eth_call_mock(raw_tx, format!("0x{}", responce), block);
+        }
+
+        if let Some(reward_configuration) = &configuration.reward_configuration {
+            let token = reward_configuration
+                .token
+                .parse::<Address>()
+                .expect("Failed to parse address");
+
+            let raw_tx = self.contract.reward_configuration().tx;
+            let responce = hex::encode(ethers::abi::encode(&[Token::Tuple(vec![
+                Token::Address(token),
+                Token::Uint(U256::from(
+                    reward_configuration.address_confirmation_reward.to_owned(),
+                )),
+                Token::Uint(U256::from(
+                    reward_configuration.address_tracer_reward.to_owned(),
+                )),
+                Token::Uint(U256::from(
+                    reward_configuration.asset_confirmation_reward.to_owned(),
+                )),
+                Token::Uint(U256::from(
+                    reward_configuration.asset_tracer_reward.to_owned(),
+                )),
+            ])]));
+
+            self.eth_call_mock(raw_tx, format!("0x{}", responce), block);
+        }
+    }
+
+    fn eth_call_mock(&mut self, raw_tx: TypedTransaction, result: String, block: u64) {
         let tx = serde_json::to_value(raw_tx).expect("Failed to serialize raw transaction");
 
         let response = json!({
@@ -470,7 +640,7 @@ impl EvmMock {
             .with_body(&response.to_string())
             .match_body(Matcher::PartialJson(json!({
                 "method": "eth_call",
-                "params": [ tx, "latest" ]
+                "params": [ tx, format!("{block:#x}") ]
             })))
             .create();
     }
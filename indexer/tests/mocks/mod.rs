@@ -3,6 +3,7 @@ use crate::jwt::get_jwt_id;
 use {
     hapi_core::{
         client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
             entities::{
                 address::Address,
                 asset::{Asset, AssetId},
@@ -14,7 +15,7 @@ use {
         },
         HapiCoreNetwork,
     },
-    hapi_indexer::{IndexingCursor, NetworkData, PushData},
+    hapi_indexer::{IndexingCursor, NetworkConfiguration, NetworkData, PushData},
     std::str::FromStr,
     uuid::Uuid,
 };
@@ -44,6 +45,24 @@ pub trait RpcMock {
     // Returns network-specific address
     fn generate_address() -> String;
 
+    // Returns network-specific authority address in the format returned by the client
+    fn generate_authority() -> String {
+        Self::generate_address()
+    }
+
+    // Returns the part of network configuration that is set by the network initialization
+    fn get_initial_configuration(configuration: &NetworkConfiguration) -> NetworkConfiguration {
+        NetworkConfiguration {
+            authority: configuration.authority.clone(),
+            ..Default::default()
+        }
+    }
+
+    // Returns the payload expected for the event, when it differs from the latest entity state
+    fn get_event_data(_name: &EventName, data: PushData) -> PushData {
+        data
+    }
+
     // Returns the URL of the network mock server
     fn get_mock_url(&self) -> String;
 
@@ -72,6 +91,8 @@ pub struct TestData {
     pub name: EventName,
     pub data: Option<PushData>,
     pub block: u64,
+    // Reporter that sends the confirmation
+    pub confirmed_by: Option<Reporter>,
 }
 
 pub fn create_pushdata<T: RpcMock>() -> Vec<PushData> {
@@ -91,13 +112,13 @@ pub fn create_pushdata<T: RpcMock>() -> Vec<PushData> {
         name: String::from("Case 1"),
         url: String::from("https://case1.com"),
         status: CaseStatus::Open,
-        reporter_id: Uuid::new_v4(),
+        reporter_id: reporter.id,
     };
 
     let address = Address {
         address: T::generate_address(),
-        case_id: Uuid::new_v4(),
-        reporter_id: Uuid::new_v4(),
+        case_id: case.id,
+        reporter_id: reporter.id,
         risk: 5,
         category: Category::ATM,
         confirmations: 10,
@@ -106,18 +127,38 @@ pub fn create_pushdata<T: RpcMock>() -> Vec<PushData> {
     let asset = Asset {
         address: T::generate_address(),
         asset_id: AssetId::from_str("12345678").expect("Failed to parse asset id"),
-        case_id: Uuid::new_v4(),
-        reporter_id: Uuid::new_v4(),
+        case_id: case.id,
+        reporter_id: reporter.id,
         risk: 7,
         category: Category::DeFi,
         confirmations: 3,
     };
 
+    let configuration = NetworkConfiguration {
+        authority: Some(T::generate_authority()),
+        stake_configuration: Some(StakeConfiguration {
+            token: T::generate_address(),
+            unlock_duration: 100,
+            validator_stake: 101.into(),
+            tracer_stake: 102.into(),
+            publisher_stake: 103.into(),
+            authority_stake: 104.into(),
+        }),
+        reward_configuration: Some(RewardConfiguration {
+            token: T::generate_address(),
+            address_confirmation_reward: 201.into(),
+            address_tracer_reward: 202.into(),
+            asset_confirmation_reward: 203.into(),
+            asset_tracer_reward: 204.into(),
+        }),
+    };
+
     vec![
         PushData::Reporter(reporter.clone()),
         PushData::Case(case.clone()),
         PushData::Address(address.clone()),
         PushData::Asset(asset.clone()),
+        PushData::Configuration(configuration),
     ]
 }
 
@@ -131,17 +172,43 @@ pub fn create_test_batches<T: RpcMock>(pushdata: &Vec<PushData>) -> Vec<TestBatc
     let address = pushdata[2].clone();
     let asset = pushdata[3].clone();
 
+    // Confirmations are sent by the same reporter, as the mocks don't check the permissions
+    let confirming_reporter = match &reporter {
+        PushData::Reporter(reporter) => reporter.clone(),
+        _ => panic!("Invalid reporter data"),
+    };
+
+    let configuration = match &pushdata[4] {
+        PushData::Configuration(configuration) => configuration.clone(),
+        _ => panic!("Invalid configuration data"),
+    };
+
+    // Each configuration event carries only the changed part of the configuration
+    let initialization = PushData::Configuration(T::get_initial_configuration(&configuration));
+    let authority = PushData::Configuration(NetworkConfiguration {
+        authority: configuration.authority.clone(),
+        ..Default::default()
+    });
+    let stake_config = PushData::Configuration(NetworkConfiguration {
+        stake_configuration: configuration.stake_configuration.clone(),
+        ..Default::default()
+    });
+    let reward_config = PushData::Configuration(NetworkConfiguration {
+        reward_configuration: configuration.reward_configuration,
+        ..Default::default()
+    });
+
     let data = [
         // ==> First Run
         // First batch
-        (EventName::Initialize, None),
-        (EventName::SetAuthority, None),
+        (EventName::Initialize, Some(initialization)),
+        (EventName::SetAuthority, Some(authority)),
         (EventName::CreateReporter, Some(reporter.clone())),
-        (EventName::UpdateStakeConfiguration, None),
+        (EventName::UpdateStakeConfiguration, Some(stake_config)),
         (EventName::ActivateReporter, Some(reporter.clone())),
         (EventName::UpdateReporter, Some(reporter.clone())),
         // Second batch
-        (EventName::UpdateRewardConfiguration, None),
+        (EventName::UpdateRewardConfiguration, Some(reward_config)),
         (EventName::CreateCase, Some(case.clone())),
         (EventName::UpdateCase, Some(case)),
         (EventName::CreateAddress, Some(address.clone())),
@@ -170,8 +237,10 @@ pub fn create_test_batches<T: RpcMock>(pushdata: &Vec<PushData>) -> Vec<TestBatc
             },
             hash: hash.clone(),
             name: name.clone(),
-            data: data.clone(),
+            data: data.clone().map(|data| T::get_event_data(name, data)),
             block: index as u64,
+            confirmed_by: matches!(name, EventName::ConfirmAddress | EventName::ConfirmAsset)
+                .then(|| confirming_reporter.clone()),
         })
         .collect();
 
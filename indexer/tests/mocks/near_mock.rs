@@ -27,7 +27,7 @@ use {
         AddressView as NearAddress, AssetView as NearAsset, Case as NearCase,
         Reporter as NearReporter,
     },
-    hapi_indexer::{IndexingCursor, PushData},
+    hapi_indexer::{IndexingCursor, NetworkConfiguration, PushData},
 };
 
 use super::{RpcMock, TestBatch, TestData};
@@ -99,6 +99,9 @@ impl RpcMock for NearMock {
         self.mock_client_get_requests(&data[1], "get_case");
         self.mock_client_get_requests(&data[2], "get_address");
         self.mock_client_get_requests(&data[3], "get_asset");
+        self.mock_client_get_requests(&data[4], "get_authority");
+        self.mock_client_get_requests(&data[4], "get_stake_configuration");
+        self.mock_client_get_requests(&data[4], "get_reward_configuration");
     }
 
     fn fetching_jobs_mock(&mut self, batches: &[TestBatch], _cursor: &IndexingCursor) {
@@ -237,6 +240,7 @@ impl NearMock {
             PushData::Asset(asset) => {
                 args_from_json(json!({ "address": asset.address, "id": asset.asset_id.to_string()}))
             }
+            PushData::Configuration(_) => args_from_json(Value::Null),
         };
 
         let encoded_entity: Vec<u8> = match data {
@@ -256,6 +260,13 @@ impl NearMock {
                 let asset: NearAsset = a.clone().try_into().expect("Failed to convert");
                 serde_json::to_string(&asset).unwrap().into_bytes()
             }
+            PushData::Configuration(c) => match method {
+                "get_authority" => serde_json::to_string(&c.authority),
+                "get_stake_configuration" => serde_json::to_string(&c.stake_configuration),
+                _ => serde_json::to_string(&c.reward_configuration),
+            }
+            .unwrap()
+            .into_bytes(),
         };
 
         let result = methods::query::RpcQueryResponse {
@@ -282,12 +293,19 @@ impl NearMock {
             },
         };
 
+        // Entities are requested at the receipt block, so the block reference is not matched
+        let mut request = get_value_from_method(payload);
+        request["params"]
+            .as_object_mut()
+            .expect("Invalid request params")
+            .remove("finality");
+
         self.server
             .mock("POST", "/")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(&response.to_string())
-            .match_body(Matcher::PartialJson(get_value_from_method(payload)))
+            .match_body(Matcher::PartialJson(request))
             .create();
     }
 }
@@ -320,6 +338,7 @@ fn make_receipt_response(data: &TestData) -> RpcReceiptResponse {
             PushData::Address(address) => get_address_json(address),
             PushData::Case(case) => get_case_json(case),
             PushData::Asset(asset) => get_asset_json(asset),
+            PushData::Configuration(configuration) => get_configuration_json(configuration),
         };
     };
 
@@ -327,7 +346,7 @@ fn make_receipt_response(data: &TestData) -> RpcReceiptResponse {
 
     RpcReceiptResponse {
         receipt_view: near_primitives::views::ReceiptView {
-            predecessor_id: reporter_id(),
+            predecessor_id: get_predecessor_id(data),
             receiver_id: contract_id(),
             receipt_id: CryptoHash::from_str(data.hash.as_str()).unwrap(),
 
@@ -348,6 +367,29 @@ fn make_receipt_response(data: &TestData) -> RpcReceiptResponse {
     }
 }
 
+// Initializer becomes the contract authority,
+// reporter methods without arguments and confirmations are called by the reporter itself
+fn get_predecessor_id(data: &TestData) -> AccountId {
+    if let Some(reporter) = &data.confirmed_by {
+        return AccountId::try_from(reporter.account.clone()).expect("Invalid account id");
+    }
+
+    let account_id = match (&data.name, &data.data) {
+        (EventName::Initialize, Some(PushData::Configuration(configuration))) => {
+            configuration.authority.clone()
+        }
+        (
+            EventName::DeactivateReporter | EventName::Unstake,
+            Some(PushData::Reporter(reporter)),
+        ) => Some(reporter.account.clone()),
+        _ => None,
+    };
+
+    account_id
+        .map(|id| AccountId::try_from(id).expect("Invalid account id"))
+        .unwrap_or_else(reporter_id)
+}
+
 fn get_reporter_json(data: &Reporter) -> Value {
     json!({
         "id": data.id.as_u128().to_string(),
@@ -385,6 +427,24 @@ fn get_asset_json(data: &Asset) -> Value {
     })
 }
 
+fn get_configuration_json(data: &NetworkConfiguration) -> Value {
+    let mut json = json!({});
+
+    if let Some(authority) = &data.authority {
+        json["authority"] = json!(authority);
+    }
+
+    if let Some(stake_configuration) = &data.stake_configuration {
+        json["stake_configuration"] = json!(stake_configuration);
+    }
+
+    if let Some(reward_configuration) = &data.reward_configuration {
+        json["reward_configuration"] = json!(reward_configuration);
+    }
+
+    json
+}
+
 fn args_from_json(json: Value) -> FunctionArgs {
     FunctionArgs::from(json.to_string().into_bytes())
 }
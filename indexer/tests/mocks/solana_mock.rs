@@ -1,10 +1,23 @@
 use {
     anchor_lang::AccountSerialize,
     hapi_core::{
-        client::solana::{byte_array_from_str, test_helpers::create_test_tx, InstructionData},
+        client::{
+            entities::{
+                address::Address,
+                asset::Asset,
+                reporter::{Reporter, ReporterStatus},
+            },
+            events::EventName,
+            solana::{
+                byte_array_from_str, get_network_address, test_helpers::create_test_tx,
+                CreateAddressData, CreateAssetData, CreateCaseData, CreateNetworkData,
+                CreateReporterData, DecodedInstructionData, InstructionData, UpdateAddressData,
+                UpdateAssetData, UpdateCaseData, UpdateReporterData,
+            },
+        },
         HapiCoreNetwork,
     },
-    hapi_indexer::{IndexingCursor, PushData},
+    hapi_indexer::{IndexingCursor, NetworkConfiguration, PushData},
     mockito::{Matcher, Server, ServerGuard},
     serde_json::{json, Value},
     solana_account_decoder::{UiAccount, UiAccountEncoding},
@@ -61,6 +74,47 @@ impl RpcMock for SolanaMock {
         Keypair::new().pubkey().to_string()
     }
 
+    // Network creation instruction contains the whole configuration
+    fn get_initial_configuration(configuration: &NetworkConfiguration) -> NetworkConfiguration {
+        configuration.clone()
+    }
+
+    // Payloads are built from the instructions, so they carry the state set by the instruction
+    // instead of the latest account state
+    fn get_event_data(name: &EventName, data: PushData) -> PushData {
+        match (name, data) {
+            (EventName::CreateReporter, PushData::Reporter(reporter)) => {
+                PushData::Reporter(Reporter {
+                    status: ReporterStatus::Inactive,
+                    stake: 0_u64.into(),
+                    unlock_timestamp: 0,
+                    ..reporter
+                })
+            }
+            (EventName::DeactivateReporter, PushData::Reporter(reporter)) => {
+                PushData::Reporter(Reporter {
+                    status: ReporterStatus::Unstaking,
+                    ..reporter
+                })
+            }
+            (EventName::Unstake, PushData::Reporter(reporter)) => PushData::Reporter(Reporter {
+                status: ReporterStatus::Inactive,
+                stake: 0_u64.into(),
+                unlock_timestamp: 0,
+                ..reporter
+            }),
+            (EventName::CreateAddress, PushData::Address(address)) => PushData::Address(Address {
+                confirmations: 0,
+                ..address
+            }),
+            (EventName::CreateAsset, PushData::Asset(asset)) => PushData::Asset(Asset {
+                confirmations: 0,
+                ..asset
+            }),
+            (_, data) => data,
+        }
+    }
+
     fn get_delay_multiplier() -> u32 {
         6
     }
@@ -147,33 +201,21 @@ impl RpcMock for SolanaMock {
 
     fn processing_jobs_mock(&mut self, batch: &TestBatch) {
         // Mocking transaction request with instruction
-        batch
-            .iter()
-            .for_each(|event| self.mock_transaction(event.name.to_string(), &event.hash));
+        batch.iter().for_each(|event| self.mock_transaction(event));
     }
 }
 
 impl SolanaMock {
-    fn get_transaction(name: String, hash: &str) -> EncodedConfirmedTransactionWithStatusMeta {
-        let mut account_keys = vec![
-            String::from(PROGRAM_ID),
-            String::default(),
-            String::from(REPORTER),
-            String::from(CASE),
-        ];
-
-        if name.contains("address") {
-            account_keys.push(String::from(ADDRESS))
-        } else if name.contains("asset") {
-            account_keys.push(String::from(ASSET))
-        }
+    fn get_transaction(event: &TestData) -> EncodedConfirmedTransactionWithStatusMeta {
+        let name = get_instruction_name(&event.name);
+        let (data, accounts) = get_instruction(event);
+
+        let mut account_keys = vec![String::from(PROGRAM_ID)];
+        account_keys.extend(accounts);
 
         create_test_tx(
-            &vec![(
-                name.as_str(),
-                InstructionData::Raw(String::from("Some data")),
-            )],
-            hash.to_string(),
+            &vec![(name.as_str(), data)],
+            event.hash.clone(),
             account_keys,
         )
     }
@@ -208,10 +250,11 @@ impl SolanaMock {
             .create();
     }
 
-    fn mock_transaction(&mut self, name: String, hash: &str) {
+    fn mock_transaction(&mut self, event: &TestData) {
+        let hash = &event.hash;
         let response = json!({
            "jsonrpc": "2.0",
-           "result": json!(SolanaMock::get_transaction(name, hash)),
+           "result": json!(SolanaMock::get_transaction(event)),
            "id": 1
         });
 
@@ -309,6 +352,52 @@ impl SolanaMock {
 
                 REPORTER
             }
+            PushData::Configuration(configuration) => {
+                let stake_configuration = configuration.stake_configuration.unwrap_or_default();
+                let reward_configuration = configuration.reward_configuration.unwrap_or_default();
+
+                let mut name = [0u8; 32];
+                byte_array_from_str(&SolanaMock::get_network().to_string(), &mut name)
+                    .expect("Failed to parse network name");
+
+                hapi_core_solana::Network {
+                    version: 1,
+                    bump: 255,
+                    authority: Pubkey::from_str(&configuration.authority.unwrap_or_default())
+                        .unwrap_or_default(),
+                    name,
+                    stake_mint: Pubkey::from_str(&stake_configuration.token).unwrap_or_default(),
+                    stake_configuration: hapi_core_solana::StakeConfiguration {
+                        unlock_duration: stake_configuration.unlock_duration,
+                        validator_stake: stake_configuration.validator_stake.into(),
+                        tracer_stake: stake_configuration.tracer_stake.into(),
+                        publisher_stake: stake_configuration.publisher_stake.into(),
+                        authority_stake: stake_configuration.authority_stake.into(),
+                        appraiser_stake: 0,
+                    },
+                    reward_mint: Pubkey::from_str(&reward_configuration.token).unwrap_or_default(),
+                    reward_configuration: hapi_core_solana::RewardConfiguration {
+                        address_tracer_reward: reward_configuration.address_tracer_reward.into(),
+                        address_confirmation_reward: reward_configuration
+                            .address_confirmation_reward
+                            .into(),
+                        asset_tracer_reward: reward_configuration.asset_tracer_reward.into(),
+                        asset_confirmation_reward: reward_configuration
+                            .asset_confirmation_reward
+                            .into(),
+                    },
+                }
+                .try_serialize(&mut data)
+                .expect("Failed to serialize network");
+
+                let (network, _) = get_network_address(
+                    &SolanaMock::get_network().to_string(),
+                    &Pubkey::from_str(PROGRAM_ID).expect("Invalid program id"),
+                )
+                .expect("Failed to get network address");
+
+                return (network, data);
+            }
         };
 
         (Pubkey::from_str(address).expect("Invalid address"), data)
@@ -357,6 +446,174 @@ impl SolanaMock {
     }
 }
 
+// Network initialization is done by `create_network` instruction in Solana
+fn get_instruction_name(name: &EventName) -> String {
+    match name {
+        EventName::Initialize => String::from("create_network"),
+        _ => name.to_string(),
+    }
+}
+
+// Returns instruction data and instruction accounts in the order expected by the program
+fn get_instruction(event: &TestData) -> (InstructionData, Vec<String>) {
+    let mut accounts = vec![
+        String::default(),
+        String::default(),
+        String::from(REPORTER),
+        String::from(CASE),
+    ];
+
+    let data = match (&event.name, event.data.clone().expect("Empty data")) {
+        (EventName::Initialize, PushData::Configuration(configuration)) => {
+            let stake_configuration = configuration
+                .stake_configuration
+                .expect("Empty stake configuration");
+            let reward_configuration = configuration
+                .reward_configuration
+                .expect("Empty reward configuration");
+
+            let mut name = [0u8; 32];
+            byte_array_from_str(&SolanaMock::get_network().to_string(), &mut name)
+                .expect("Failed to parse network name");
+
+            accounts = vec![
+                configuration.authority.expect("Empty authority"),
+                String::default(),
+                reward_configuration.token.clone(),
+                stake_configuration.token.clone(),
+            ];
+
+            DecodedInstructionData::CreateNetwork(CreateNetworkData {
+                name,
+                stake_info: stake_configuration.into(),
+                reward_info: reward_configuration.into(),
+                bump: 255,
+            })
+        }
+        (EventName::SetAuthority, PushData::Configuration(configuration)) => {
+            accounts = vec![
+                String::default(),
+                String::default(),
+                configuration.authority.expect("Empty authority"),
+            ];
+
+            DecodedInstructionData::SetAuthority
+        }
+        (EventName::UpdateStakeConfiguration, PushData::Configuration(configuration)) => {
+            let stake_configuration = configuration
+                .stake_configuration
+                .expect("Empty stake configuration");
+
+            accounts = vec![
+                String::default(),
+                String::default(),
+                stake_configuration.token.clone(),
+            ];
+
+            DecodedInstructionData::UpdateStakeConfiguration(stake_configuration.into())
+        }
+        (EventName::UpdateRewardConfiguration, PushData::Configuration(configuration)) => {
+            let reward_configuration = configuration
+                .reward_configuration
+                .expect("Empty reward configuration");
+
+            accounts = vec![
+                String::default(),
+                String::default(),
+                reward_configuration.token.clone(),
+            ];
+
+            DecodedInstructionData::UpdateRewardConfiguration(reward_configuration.into())
+        }
+        (EventName::CreateReporter, PushData::Reporter(reporter)) => {
+            DecodedInstructionData::CreateReporter(CreateReporterData {
+                reporter_id: reporter.id.as_u128(),
+                account: Pubkey::from_str(&reporter.account).expect("Invalid reporter address"),
+                name: reporter.name,
+                role: reporter.role.into(),
+                url: reporter.url,
+                bump: 255,
+            })
+        }
+        (EventName::UpdateReporter, PushData::Reporter(reporter)) => {
+            DecodedInstructionData::UpdateReporter(UpdateReporterData {
+                account: Pubkey::from_str(&reporter.account).expect("Invalid reporter address"),
+                name: reporter.name,
+                role: reporter.role.into(),
+                url: reporter.url,
+            })
+        }
+        (EventName::ActivateReporter, _) => DecodedInstructionData::ActivateReporter,
+        (EventName::DeactivateReporter, _) => DecodedInstructionData::DeactivateReporter,
+        (EventName::Unstake, _) => DecodedInstructionData::Unstake,
+        (EventName::CreateCase, PushData::Case(case)) => {
+            DecodedInstructionData::CreateCase(CreateCaseData {
+                case_id: case.id.as_u128(),
+                name: case.name,
+                url: case.url,
+                bump: 255,
+            })
+        }
+        (EventName::UpdateCase, PushData::Case(case)) => {
+            DecodedInstructionData::UpdateCase(UpdateCaseData {
+                name: case.name,
+                url: case.url,
+                status: case.status.into(),
+            })
+        }
+        (EventName::CreateAddress, PushData::Address(address)) => {
+            accounts.push(String::from(ADDRESS));
+
+            DecodedInstructionData::CreateAddress(CreateAddressData {
+                address: encode_address(&address.address),
+                category: address.category.into(),
+                risk: address.risk,
+                bump: 255,
+            })
+        }
+        (EventName::UpdateAddress, PushData::Address(address)) => {
+            accounts.push(String::from(ADDRESS));
+
+            DecodedInstructionData::UpdateAddress(UpdateAddressData {
+                category: address.category.into(),
+                risk: address.risk,
+            })
+        }
+        (EventName::ConfirmAddress, _) => {
+            accounts.push(String::from(ADDRESS));
+
+            DecodedInstructionData::ConfirmAddress(255)
+        }
+        (EventName::CreateAsset, PushData::Asset(asset)) => {
+            accounts.push(String::from(ASSET));
+
+            DecodedInstructionData::CreateAsset(CreateAssetData {
+                addr: encode_address(&asset.address),
+                asset_id: encode_address(&asset.asset_id.to_string()),
+                category: asset.category.into(),
+                risk_score: asset.risk,
+                bump: 255,
+            })
+        }
+        (EventName::UpdateAsset, PushData::Asset(asset)) => {
+            accounts.push(String::from(ASSET));
+
+            DecodedInstructionData::UpdateAsset(UpdateAssetData {
+                category: asset.category.into(),
+                risk_score: asset.risk,
+            })
+        }
+        (EventName::ConfirmAsset, _) => {
+            accounts.push(String::from(ASSET));
+
+            DecodedInstructionData::ConfirmAsset(255)
+        }
+        (name, data) => panic!("Unexpected data for {name}: {data:?}"),
+    };
+
+    (InstructionData::Decoded(data), accounts)
+}
+
 pub fn encode_address(address: &str) -> [u8; 64] {
     let mut res = [0u8; 64];
     let bytes = address.as_bytes();